@@ -0,0 +1,94 @@
+/// Tolerance for comparing floating-point values
+/// # Remarks
+/// Absolute(epsilon): a and b are equal if |a - b| ≤ epsilon
+///
+/// Relative(max_relative): a and b are equal if |a - b| ≤ max_relative * max(|a|, |b|)
+///
+/// Ulps(max_ulps): a and b are equal if at most max_ulps representable numbers lie between them
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Tolerance {
+    Absolute(f64),
+    Relative(f64),
+    Ulps(u32),
+}
+
+/// Trait for checking if two floating-point values are approximately equal
+/// # Remarks
+/// Gets implemented for f32 and f64 and for every struct of this crate that holds floating-point
+/// values. Structs are equal if all of their values are equal with the given tolerance.
+/// NaN is never equal to anything.
+/// # Examples
+/// ```
+/// use mathol::basics::approx_trait::{ApproxEq, Tolerance};
+///
+/// let a = 0.1 + 0.2;
+/// assert!(a != 0.3);
+/// assert!(a.approx_eq(&0.3, Tolerance::Absolute(1e-12)));
+/// assert!(a.relative_eq(&0.3, 1e-12));
+/// assert!(a.ulps_eq(&0.3, 1));
+/// ```
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.approx_eq(other, Tolerance::Absolute(epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        self.approx_eq(other, Tolerance::Relative(max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        self.approx_eq(other, Tolerance::Ulps(max_ulps))
+    }
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &f64, tolerance: Tolerance) -> bool {
+        if self == other {
+            return true;
+        }
+        if self.is_nan() || other.is_nan() || self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+
+        let diff = (self - other).abs();
+        match tolerance {
+            Tolerance::Absolute(epsilon) => diff <= epsilon,
+            Tolerance::Relative(max_relative) => diff <= max_relative * self.abs().max(other.abs()),
+            Tolerance::Ulps(max_ulps) => {
+                if self.is_sign_negative() != other.is_sign_negative() {
+                    return false;
+                }
+                let a = self.to_bits() as i64;
+                let b = other.to_bits() as i64;
+                (a - b).abs() <= max_ulps as i64
+            },
+        }
+    }
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq(&self, other: &f32, tolerance: Tolerance) -> bool {
+        if self == other {
+            return true;
+        }
+        if self.is_nan() || other.is_nan() || self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+
+        let diff = (*self as f64 - *other as f64).abs();
+        match tolerance {
+            Tolerance::Absolute(epsilon) => diff <= epsilon,
+            Tolerance::Relative(max_relative) => diff <= max_relative * (self.abs().max(other.abs()) as f64),
+            Tolerance::Ulps(max_ulps) => {
+                if self.is_sign_negative() != other.is_sign_negative() {
+                    return false;
+                }
+                let a = self.to_bits() as i64;
+                let b = other.to_bits() as i64;
+                (a - b).abs() <= max_ulps as i64
+            },
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::f64::consts::PI;
+use error::*;
+
+const TOLERANCE: f64 = 1e-3;
+
+
+/// Calculates the incomplete elliptic integral of the first kind
+/// # Remarks
+/// F(phi, k) = ∫ 1 / sqrt(1 - k² sin²(t)) dt with t from 0 to phi
+///
+/// Uses the modulus k, not the parameter m = k². The amplitude phi is a radian value and may
+/// lie outside of the range from -π/2 to π/2.
+/// # Return values
+/// Returns the value of the integral in case of success
+///
+/// Returns RangeError if k does not lie between -1 and 1 (exclusive)
+/// # Examples
+/// ```
+/// use mathol::basics::elliptic::get_elliptic_integral_first_kind;
+///
+/// assert!((get_elliptic_integral_first_kind(1.0, 0.0).unwrap() - 1.0).abs() < 1e-15);
+/// assert!((get_elliptic_integral_first_kind(0.5, 0.8).unwrap() - 0.5136182161110).abs() < 1e-12);
+/// ```
+pub fn get_elliptic_integral_first_kind(phi: f64, k: f64) -> Result<f64, MatholError> {
+    check_modulus(k, false)?;
+    Ok(reduce_amplitude(phi, k, incomplete_first_kind))
+}
+
+/// Calculates the incomplete elliptic integral of the second kind
+/// # Remarks
+/// E(phi, k) = ∫ sqrt(1 - k² sin²(t)) dt with t from 0 to phi
+///
+/// Uses the modulus k, not the parameter m = k². The amplitude phi is a radian value and may
+/// lie outside of the range from -π/2 to π/2.
+/// # Return values
+/// Returns the value of the integral in case of success
+///
+/// Returns RangeError if k does not lie between -1 and 1
+/// # Examples
+/// ```
+/// use mathol::basics::elliptic::get_elliptic_integral_second_kind;
+///
+/// assert!((get_elliptic_integral_second_kind(1.0, 0.0).unwrap() - 1.0).abs() < 1e-15);
+/// assert!((get_elliptic_integral_second_kind(0.5, 0.8).unwrap() - 0.4870173987976).abs() < 1e-12);
+/// ```
+pub fn get_elliptic_integral_second_kind(phi: f64, k: f64) -> Result<f64, MatholError> {
+    check_modulus(k, true)?;
+    Ok(reduce_amplitude(phi, k, incomplete_second_kind))
+}
+
+/// Calculates the complete elliptic integral of the first kind K(k) = F(π/2, k)
+/// # Remarks
+/// Uses the arithmetic-geometric mean K(k) = π / (2 * agm(1, sqrt(1 - k²)))
+/// # Return values
+/// Returns the value of the integral in case of success
+///
+/// Returns RangeError if k does not lie between -1 and 1 (exclusive)
+/// # Examples
+/// ```
+/// use mathol::basics::elliptic::get_complete_elliptic_integral_first_kind;
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// assert_eq!(FRAC_PI_2, get_complete_elliptic_integral_first_kind(0.0).unwrap());
+/// assert!((get_complete_elliptic_integral_first_kind(0.5).unwrap() - 1.685750354812596).abs() < 1e-14);
+/// ```
+pub fn get_complete_elliptic_integral_first_kind(k: f64) -> Result<f64, MatholError> {
+    check_modulus(k, false)?;
+    let (mut a, mut g) = (1.0, (1.0 - k * k).sqrt());
+    while (a - g).abs() > 1e-15 * a {
+        let next = (a + g) / 2.0;
+        g = (a * g).sqrt();
+        a = next;
+    }
+    Ok(PI / (2.0 * a))
+}
+
+/// Calculates the complete elliptic integral of the second kind E(k) = E(π/2, k)
+/// # Return values
+/// Returns the value of the integral in case of success
+///
+/// Returns RangeError if k does not lie between -1 and 1
+/// # Examples
+/// ```
+/// use mathol::basics::elliptic::get_complete_elliptic_integral_second_kind;
+///
+/// assert_eq!(1.0, get_complete_elliptic_integral_second_kind(1.0).unwrap());
+/// assert!((get_complete_elliptic_integral_second_kind(0.5).unwrap() - 1.467462209339427).abs() < 1e-14);
+/// ```
+pub fn get_complete_elliptic_integral_second_kind(k: f64) -> Result<f64, MatholError> {
+    check_modulus(k, true)?;
+    Ok(incomplete_second_kind(PI / 2.0, k))
+}
+
+fn check_modulus(k: f64, inclusive: bool) -> Result<(), MatholError> {
+    if k.abs() > 1.0 || (!inclusive && k.abs() == 1.0) || k.is_nan() {
+        return Err(MatholError::RangeCause(RangeError {
+            message: if inclusive {
+                "The modulus k must lie between -1 and 1".to_string()
+            } else {
+                "The modulus k must lie strictly between -1 and 1".to_string()
+            },
+        }));
+    }
+
+    Ok(())
+}
+
+/// Splits the amplitude into multiples of π and a rest between -π/2 and π/2
+/// # Remarks
+/// The integral over a period of π is twice the complete integral
+fn reduce_amplitude<F>(phi: f64, k: f64, integral: F) -> f64
+    where F: Fn(f64, f64) -> f64
+{
+    let periods = (phi / PI).round();
+    let rest = phi - periods * PI;
+    let complete = if periods == 0.0 { 0.0 } else { integral(PI / 2.0, k) };
+    2.0 * periods * complete + integral(rest, k)
+}
+
+/// F(phi, k) = sin(phi) * RF(cos²(phi), 1 - k² sin²(phi), 1) for phi between -π/2 and π/2
+fn incomplete_first_kind(phi: f64, k: f64) -> f64 {
+    let (s, c) = (phi.sin(), phi.cos());
+    s * carlson_rf(c * c, 1.0 - k * k * s * s, 1.0)
+}
+
+/// E(phi, k) = F(phi, k) - k²/3 * sin³(phi) * RD(cos²(phi), 1 - k² sin²(phi), 1) for phi between -π/2 and π/2
+fn incomplete_second_kind(phi: f64, k: f64) -> f64 {
+    let (s, c) = (phi.sin(), phi.cos());
+    let (x, y) = (c * c, 1.0 - k * k * s * s);
+    s * carlson_rf(x, y, 1.0) - k * k * s * s * s * carlson_rd(x, y, 1.0) / 3.0
+}
+
+/// Carlson's symmetric elliptic integral RF(x, y, z), calculated by the duplication theorem
+fn carlson_rf(x: f64, y: f64, z: f64) -> f64 {
+    let (mut x, mut y, mut z) = (x.max(0.0), y.max(0.0), z.max(0.0));
+    loop {
+        let mean = (x + y + z) / 3.0;
+        let (dx, dy, dz) = ((mean - x) / mean, (mean - y) / mean, (mean - z) / mean);
+        if dx.abs().max(dy.abs()).max(dz.abs()) < TOLERANCE {
+            let e2 = dx * dy - dz * dz;
+            let e3 = dx * dy * dz;
+            return (1.0 + (e2 / 24.0 - 0.1 - 3.0 * e3 / 44.0) * e2 + e3 / 14.0) / mean.sqrt();
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        z = (z + lambda) / 4.0;
+    }
+}
+
+/// Carlson's symmetric elliptic integral RD(x, y, z), calculated by the duplication theorem
+fn carlson_rd(x: f64, y: f64, z: f64) -> f64 {
+    let (mut x, mut y, mut z) = (x.max(0.0), y.max(0.0), z);
+    let (mut sum, mut factor) = (0.0, 1.0);
+    loop {
+        let mean = (x + y + 3.0 * z) / 5.0;
+        let (dx, dy, dz) = ((mean - x) / mean, (mean - y) / mean, (mean - z) / mean);
+        if dx.abs().max(dy.abs()).max(dz.abs()) < TOLERANCE {
+            let ea = dx * dy;
+            let eb = dz * dz;
+            let ec = ea - eb;
+            let ed = ea - 6.0 * eb;
+            let ee = ed + ec + ec;
+            let (c1, c2, c3, c4) = (3.0 / 14.0, 1.0 / 6.0, 9.0 / 22.0, 3.0 / 26.0);
+            let series = 1.0 + ed * (-c1 + 0.25 * c1 * ed - 1.5 * c4 * dz * ee) + dz * (c2 * ee + dz * (-c3 * ec + dz * c4 * ea));
+            return 3.0 * sum + factor * series / (mean * mean.sqrt());
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        sum += factor / (sz * (z + lambda));
+        factor /= 4.0;
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        z = (z + lambda) / 4.0;
+    }
+}
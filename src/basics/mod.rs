@@ -1,6 +1,8 @@
 pub mod convert_trait;
 pub mod amount_trait;
 pub mod cotangent;
+pub mod approx_trait;
+pub mod elliptic;
 
 use num::Num;
 use basics::convert_trait::Convert;
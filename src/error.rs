@@ -14,6 +14,8 @@ pub enum MatholError {
     MatriceCause(MatriceError),
     OutOfBoundsCause(OutOfBoundsError),
     LengthCause(LengthError),
+    InfeasibleCause(InfeasibleError),
+    UnboundedCause(UnboundedError),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -209,3 +211,38 @@ impl error::Error for LengthError {
     }
 }
 
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfeasibleError {
+    pub message: String,
+}
+
+impl Display for InfeasibleError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for InfeasibleError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnboundedError {
+    pub message: String,
+}
+
+impl Display for UnboundedError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for UnboundedError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
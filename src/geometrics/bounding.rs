@@ -0,0 +1,643 @@
+use num::{Num, FromPrimitive};
+use std::fmt::Debug;
+use std::cmp::PartialOrd;
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use vectoroperations::vector2d::Vector2D;
+use vectoroperations::vector3d::Vector3D;
+use matrices::matrice::Matrice;
+use geometrics::planimetry::Rectangle;
+use geometrics::stereometry::Cuboid;
+use geometrics::traits::*;
+use error::*;
+
+
+/// Struct representing an axis-aligned bounding box in two-dimensional space
+/// # Usage
+/// ```
+/// use mathol::geometrics::bounding::Aabb2D;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb2D {
+    /// The corner with the smallest coordinates
+    pub min: Vector2D<f64>,
+    /// The corner with the largest coordinates
+    pub max: Vector2D<f64>,
+}
+
+impl Aabb2D {
+    /// Creates a box from its two corners
+    /// # Return values
+    /// Returns the box in case of success
+    ///
+    /// Returns RangeError if a coordinate of min is larger than the same coordinate of max
+    pub fn build_aabb<T>(min: Vector2D<T>, max: Vector2D<T>) -> Result<Aabb2D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let (min, max) = (min.convert_to_f64(), max.convert_to_f64());
+        if min.x > max.x || min.y > max.y {
+            return Err(MatholError::RangeCause(RangeError {
+                message: "The minimum corner must not be larger than the maximum corner".to_string(),
+            }));
+        }
+
+        Ok(Aabb2D {min, max})
+    }
+
+    /// Creates the smallest box that contains all of the given points
+    /// # Return values
+    /// Returns the box in case of success
+    ///
+    /// Returns EmptyVectorError if the slice of points is empty
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::bounding::Aabb2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let points = [Vector2D::build_vector(1, 5), Vector2D::build_vector(-2, 3), Vector2D::build_vector(4, 4)];
+    /// let aabb = Aabb2D::build_from_points(&points).unwrap();
+    /// assert_eq!(Vector2D::build_vector(-2.0, 3.0), aabb.min);
+    /// assert_eq!(Vector2D::build_vector(4.0, 5.0), aabb.max);
+    /// ```
+    pub fn build_from_points<T>(points: &[Vector2D<T>]) -> Result<Aabb2D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        if points.is_empty() {
+            return Err(MatholError::EmptyVecCause(EmptyVectorError {
+                message: "Vector or Array is empty".to_string(),
+            }));
+        }
+
+        let first = points[0].convert_to_f64();
+        Ok(points.iter().skip(1).fold(Aabb2D {min: first, max: first}, |aabb, p| aabb.expand_to_point(*p)))
+    }
+
+    /// Returns the centre of the box
+    pub fn get_centre(self) -> Vector2D<f64> {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    /// Returns the vector from the minimum to the maximum corner, so its coordinates are width and height
+    pub fn get_size(self) -> Vector2D<f64> {
+        self.max - self.min
+    }
+
+    /// Returns the smallest box that contains both boxes
+    pub fn union(self, other: Aabb2D) -> Aabb2D {
+        Aabb2D {
+            min: Vector2D::build_vector(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Vector2D::build_vector(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    /// Returns the box that is shared by both boxes
+    /// # Return values
+    /// Returns None if the boxes do not intersect
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::bounding::Aabb2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let a = Aabb2D::build_aabb(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 4)).unwrap();
+    /// let b = Aabb2D::build_aabb(Vector2D::build_vector(2, -1), Vector2D::build_vector(6, 3)).unwrap();
+    /// let c = Aabb2D::build_aabb(Vector2D::build_vector(2, 0), Vector2D::build_vector(4, 3)).unwrap();
+    /// assert_eq!(Some(c), a.intersection(b));
+    /// ```
+    pub fn intersection(self, other: Aabb2D) -> Option<Aabb2D> {
+        let min = Vector2D::build_vector(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Vector2D::build_vector(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        if min.x > max.x || min.y > max.y {
+            None
+        } else {
+            Some(Aabb2D {min, max})
+        }
+    }
+
+    /// Checks if two boxes have at least one common point
+    pub fn intersects(self, other: Aabb2D) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Checks if a point lies inside of the box or on its border
+    pub fn contains_point<T>(self, p: Vector2D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let p = p.convert_to_f64();
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    /// Checks if another box lies completely inside of the box
+    pub fn contains_aabb(self, other: Aabb2D) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// Calculates the distance between a point and the box
+    /// # Remarks
+    /// Points inside of the box have the distance 0
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::bounding::Aabb2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let aabb = Aabb2D::build_aabb(Vector2D::build_vector(0, 0), Vector2D::build_vector(2, 2)).unwrap();
+    /// assert_eq!(5.0, aabb.distance_from_point(Vector2D::build_vector(5, 6)));
+    /// assert_eq!(0.0, aabb.distance_from_point(Vector2D::build_vector(1, 1)));
+    /// ```
+    pub fn distance_from_point<T>(self, p: Vector2D<T>) -> f64
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let p = p.convert_to_f64();
+        let dx = (self.min.x - p.x).max(0.0).max(p.x - self.max.x);
+        let dy = (self.min.y - p.y).max(0.0).max(p.y - self.max.y);
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    /// Returns the box grown by margin in every direction
+    /// # Remarks
+    /// A negative margin shrinks the box, but never below its centre
+    pub fn expand(self, margin: f64) -> Aabb2D {
+        let centre = self.get_centre();
+        Aabb2D {
+            min: Vector2D::build_vector((self.min.x - margin).min(centre.x), (self.min.y - margin).min(centre.y)),
+            max: Vector2D::build_vector((self.max.x + margin).max(centre.x), (self.max.y + margin).max(centre.y)),
+        }
+    }
+
+    /// Returns the smallest box that contains the box and the given point
+    pub fn expand_to_point<T>(self, p: Vector2D<T>) -> Aabb2D
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let p = p.convert_to_f64();
+        self.union(Aabb2D {min: p, max: p})
+    }
+
+    /// Converts the box to a rectangle with the width as edge a and the height as edge b
+    /// # Return values
+    /// Returns the rectangle in case of success
+    ///
+    /// Returns NegativeValueError if the box has no area
+    pub fn to_rectangle(self) -> Result<Rectangle, MatholError> {
+        let size = self.get_size();
+        Rectangle::build_rectangle(size.x, size.y)
+    }
+}
+
+impl Area for Aabb2D {
+    /// Calculates the area of the box
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::bounding::Aabb2D;
+    /// use mathol::geometrics::traits::Area;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let aabb = Aabb2D::build_aabb(Vector2D::build_vector(1, 1), Vector2D::build_vector(4, 3)).unwrap();
+    /// assert_eq!(6.0, aabb.get_area());
+    /// ```
+    fn get_area(self) -> f64 {
+        let size = self.get_size();
+        size.x * size.y
+    }
+}
+
+impl Perimeter for Aabb2D {
+    /// Calculates the perimeter of the box
+    fn get_perimeter(self) -> f64 {
+        let size = self.get_size();
+        2.0 * size.x + 2.0 * size.y
+    }
+}
+
+impl ApproxEq for Aabb2D {
+    fn approx_eq(&self, other: &Aabb2D, tolerance: Tolerance) -> bool {
+        self.min.approx_eq(&other.min, tolerance) && self.max.approx_eq(&other.max, tolerance)
+    }
+}
+
+
+/// Struct representing an axis-aligned bounding box in three-dimensional space
+/// # Usage
+/// ```
+/// use mathol::geometrics::bounding::Aabb3D;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb3D {
+    /// The corner with the smallest coordinates
+    pub min: Vector3D<f64>,
+    /// The corner with the largest coordinates
+    pub max: Vector3D<f64>,
+}
+
+impl Aabb3D {
+    /// Creates a box from its two corners
+    /// # Return values
+    /// Returns the box in case of success
+    ///
+    /// Returns RangeError if a coordinate of min is larger than the same coordinate of max
+    pub fn build_aabb<T>(min: Vector3D<T>, max: Vector3D<T>) -> Result<Aabb3D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let (min, max) = (min.convert_to_f64(), max.convert_to_f64());
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            return Err(MatholError::RangeCause(RangeError {
+                message: "The minimum corner must not be larger than the maximum corner".to_string(),
+            }));
+        }
+
+        Ok(Aabb3D {min, max})
+    }
+
+    /// Creates the smallest box that contains all of the given points
+    /// # Return values
+    /// Returns the box in case of success
+    ///
+    /// Returns EmptyVectorError if the slice of points is empty
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::bounding::Aabb3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let points = [Vector3D::build_vector(1, 5, 0), Vector3D::build_vector(-2, 3, 7)];
+    /// let aabb = Aabb3D::build_from_points(&points).unwrap();
+    /// assert_eq!(Vector3D::build_vector(-2.0, 3.0, 0.0), aabb.min);
+    /// assert_eq!(Vector3D::build_vector(1.0, 5.0, 7.0), aabb.max);
+    /// ```
+    pub fn build_from_points<T>(points: &[Vector3D<T>]) -> Result<Aabb3D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        if points.is_empty() {
+            return Err(MatholError::EmptyVecCause(EmptyVectorError {
+                message: "Vector or Array is empty".to_string(),
+            }));
+        }
+
+        let first = points[0].convert_to_f64();
+        Ok(points.iter().skip(1).fold(Aabb3D {min: first, max: first}, |aabb, p| aabb.expand_to_point(*p)))
+    }
+
+    /// Returns the centre of the box
+    pub fn get_centre(self) -> Vector3D<f64> {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    /// Returns the vector from the minimum to the maximum corner, so its coordinates are length, width and height
+    pub fn get_size(self) -> Vector3D<f64> {
+        self.max - self.min
+    }
+
+    /// Returns the smallest box that contains both boxes
+    pub fn union(self, other: Aabb3D) -> Aabb3D {
+        Aabb3D {
+            min: Vector3D::build_vector(self.min.x.min(other.min.x), self.min.y.min(other.min.y), self.min.z.min(other.min.z)),
+            max: Vector3D::build_vector(self.max.x.max(other.max.x), self.max.y.max(other.max.y), self.max.z.max(other.max.z)),
+        }
+    }
+
+    /// Returns the box that is shared by both boxes
+    /// # Return values
+    /// Returns None if the boxes do not intersect
+    pub fn intersection(self, other: Aabb3D) -> Option<Aabb3D> {
+        let min = Vector3D::build_vector(self.min.x.max(other.min.x), self.min.y.max(other.min.y), self.min.z.max(other.min.z));
+        let max = Vector3D::build_vector(self.max.x.min(other.max.x), self.max.y.min(other.max.y), self.max.z.min(other.max.z));
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            None
+        } else {
+            Some(Aabb3D {min, max})
+        }
+    }
+
+    /// Checks if two boxes have at least one common point
+    pub fn intersects(self, other: Aabb3D) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Checks if a point lies inside of the box or on its border
+    pub fn contains_point<T>(self, p: Vector3D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let p = p.convert_to_f64();
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y && p.z >= self.min.z && p.z <= self.max.z
+    }
+
+    /// Checks if another box lies completely inside of the box
+    pub fn contains_aabb(self, other: Aabb3D) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// Calculates the distance between a point and the box
+    /// # Remarks
+    /// Points inside of the box have the distance 0
+    pub fn distance_from_point<T>(self, p: Vector3D<T>) -> f64
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let p = p.convert_to_f64();
+        let dx = (self.min.x - p.x).max(0.0).max(p.x - self.max.x);
+        let dy = (self.min.y - p.y).max(0.0).max(p.y - self.max.y);
+        let dz = (self.min.z - p.z).max(0.0).max(p.z - self.max.z);
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    /// Returns the box grown by margin in every direction
+    /// # Remarks
+    /// A negative margin shrinks the box, but never below its centre
+    pub fn expand(self, margin: f64) -> Aabb3D {
+        let c = self.get_centre();
+        Aabb3D {
+            min: Vector3D::build_vector((self.min.x - margin).min(c.x), (self.min.y - margin).min(c.y), (self.min.z - margin).min(c.z)),
+            max: Vector3D::build_vector((self.max.x + margin).max(c.x), (self.max.y + margin).max(c.y), (self.max.z + margin).max(c.z)),
+        }
+    }
+
+    /// Returns the smallest box that contains the box and the given point
+    pub fn expand_to_point<T>(self, p: Vector3D<T>) -> Aabb3D
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let p = p.convert_to_f64();
+        self.union(Aabb3D {min: p, max: p})
+    }
+
+    /// Converts the box to a cuboid with the extents along x, y and z as length, width and height
+    /// # Return values
+    /// Returns the cuboid in case of success
+    ///
+    /// Returns NegativeValueError if the box has no volume
+    pub fn to_cuboid(self) -> Result<Cuboid, MatholError> {
+        let size = self.get_size();
+        Cuboid::build_cuboid(size.x, size.y, size.z)
+    }
+}
+
+impl Volume for Aabb3D {
+    /// Calculates the volume of the box
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::bounding::Aabb3D;
+    /// use mathol::geometrics::traits::Volume;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let aabb = Aabb3D::build_aabb(Vector3D::build_vector(1, 1, 1), Vector3D::build_vector(4, 3, 2)).unwrap();
+    /// assert_eq!(6.0, aabb.get_volume());
+    /// ```
+    fn get_volume(self) -> f64 {
+        let size = self.get_size();
+        size.x * size.y * size.z
+    }
+}
+
+impl Surface for Aabb3D {
+    /// Calculates the surface of the box
+    fn get_surface(self) -> f64 {
+        let size = self.get_size();
+        2.0 * (size.x * size.y + size.x * size.z + size.y * size.z)
+    }
+}
+
+impl ApproxEq for Aabb3D {
+    fn approx_eq(&self, other: &Aabb3D, tolerance: Tolerance) -> bool {
+        self.min.approx_eq(&other.min, tolerance) && self.max.approx_eq(&other.max, tolerance)
+    }
+}
+
+
+/// Struct representing an oriented bounding box in two-dimensional space
+/// # Remarks
+/// The points of the box are centre + s * axes[0] + t * axes[1] with |s| ≤ half_extents[0]
+/// and |t| ≤ half_extents[1]. The axes are orthonormal.
+/// # Usage
+/// ```
+/// use mathol::geometrics::bounding::Obb2D;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Obb2D {
+    pub centre: Vector2D<f64>,
+    pub axes: [Vector2D<f64>; 2],
+    pub half_extents: [f64; 2],
+}
+
+impl Obb2D {
+    /// Creates an oriented box that contains all of the given points
+    /// # Remarks
+    /// The axes of the box are the principal axes of the points, which are the eigenvectors of
+    /// their covariance matrice. The first axis is the direction of the largest variance.
+    /// The box is not necessarily the smallest oriented box.
+    /// # Return values
+    /// Returns the box in case of success
+    ///
+    /// Returns EmptyVectorError if the slice of points is empty
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::bounding::Obb2D;
+    /// use mathol::geometrics::traits::Area;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let points = [Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 1), Vector2D::build_vector(2, 2), Vector2D::build_vector(3, 3)];
+    /// let obb = Obb2D::build_from_points(&points).unwrap();
+    /// assert!(obb.get_area() < 1e-12);
+    /// assert!((obb.half_extents[0] - 4.5_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn build_from_points<T>(points: &[Vector2D<T>]) -> Result<Obb2D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        if points.is_empty() {
+            return Err(MatholError::EmptyVecCause(EmptyVectorError {
+                message: "Vector or Array is empty".to_string(),
+            }));
+        }
+
+        let points: Vec<Vector2D<f64>> = points.iter().map(|p| p.convert_to_f64()).collect();
+        let mean = points.iter().sum::<Vector2D<f64>>() / points.len() as f64;
+        let mut covariance = [0.0; 4];
+        for p in points.iter() {
+            let d = [p.x - mean.x, p.y - mean.y];
+            for i in 0..2 {
+                for k in 0..2 {
+                    covariance[i * 2 + k] += d[i] * d[k];
+                }
+            }
+        }
+
+        let (_, vectors) = Matrice::build_matrice(2, 2, covariance.to_vec())?.get_symmetric_eigen()?;
+        let axes = [
+            Vector2D::build_vector(vectors.data[0], vectors.data[2]),
+            Vector2D::build_vector(vectors.data[1], vectors.data[3]),
+        ];
+
+        let mut centre = mean;
+        let mut half_extents = [0.0; 2];
+        for (i, axis) in axes.iter().enumerate() {
+            let (low, high) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), p| {
+                let s = (*p - mean).get_scalar_product(*axis);
+                (low.min(s), high.max(s))
+            });
+            centre += *axis * ((low + high) / 2.0);
+            half_extents[i] = (high - low) / 2.0;
+        }
+
+        Ok(Obb2D {centre, axes, half_extents})
+    }
+
+    /// Checks if a point lies inside of the box or on its border
+    pub fn contains_point<T>(self, p: Vector2D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let d = p.convert_to_f64() - self.centre;
+        self.axes.iter().zip(self.half_extents.iter()).all(|(axis, h)| d.get_scalar_product(*axis).abs() <= h + 1e-12 * (1.0 + h))
+    }
+
+    /// Returns the four corners of the box in counterclockwise or clockwise order
+    pub fn get_corners(self) -> [Vector2D<f64>; 4] {
+        let u = self.axes[0] * self.half_extents[0];
+        let v = self.axes[1] * self.half_extents[1];
+        [self.centre - u - v, self.centre + u - v, self.centre + u + v, self.centre - u + v]
+    }
+
+    /// Converts the box to a rectangle with the extent along the first axis as edge a
+    /// # Return values
+    /// Returns the rectangle in case of success
+    ///
+    /// Returns NegativeValueError if the box has no area
+    pub fn to_rectangle(self) -> Result<Rectangle, MatholError> {
+        Rectangle::build_rectangle(2.0 * self.half_extents[0], 2.0 * self.half_extents[1])
+    }
+}
+
+impl Area for Obb2D {
+    /// Calculates the area of the box
+    fn get_area(self) -> f64 {
+        4.0 * self.half_extents[0] * self.half_extents[1]
+    }
+}
+
+impl Perimeter for Obb2D {
+    /// Calculates the perimeter of the box
+    fn get_perimeter(self) -> f64 {
+        4.0 * (self.half_extents[0] + self.half_extents[1])
+    }
+}
+
+impl ApproxEq for Obb2D {
+    fn approx_eq(&self, other: &Obb2D, tolerance: Tolerance) -> bool {
+        self.centre.approx_eq(&other.centre, tolerance)
+            && self.axes.iter().zip(other.axes.iter()).all(|(a, b)| a.approx_eq(b, tolerance))
+            && self.half_extents.iter().zip(other.half_extents.iter()).all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+
+/// Struct representing an oriented bounding box in three-dimensional space
+/// # Remarks
+/// The points of the box are centre + s * axes[0] + t * axes[1] + u * axes[2] with
+/// |s| ≤ half_extents[0], |t| ≤ half_extents[1] and |u| ≤ half_extents[2]. The axes are orthonormal.
+/// # Usage
+/// ```
+/// use mathol::geometrics::bounding::Obb3D;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Obb3D {
+    pub centre: Vector3D<f64>,
+    pub axes: [Vector3D<f64>; 3],
+    pub half_extents: [f64; 3],
+}
+
+impl Obb3D {
+    /// Creates an oriented box that contains all of the given points
+    /// # Remarks
+    /// The axes of the box are the principal axes of the points, which are the eigenvectors of
+    /// their covariance matrice, sorted from the largest to the smallest variance.
+    /// The box is not necessarily the smallest oriented box.
+    /// # Return values
+    /// Returns the box in case of success
+    ///
+    /// Returns EmptyVectorError if the slice of points is empty
+    pub fn build_from_points<T>(points: &[Vector3D<T>]) -> Result<Obb3D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        if points.is_empty() {
+            return Err(MatholError::EmptyVecCause(EmptyVectorError {
+                message: "Vector or Array is empty".to_string(),
+            }));
+        }
+
+        let points: Vec<Vector3D<f64>> = points.iter().map(|p| p.convert_to_f64()).collect();
+        let mean = points.iter().sum::<Vector3D<f64>>() / points.len() as f64;
+        let mut covariance = [0.0; 9];
+        for p in points.iter() {
+            let d = [p.x - mean.x, p.y - mean.y, p.z - mean.z];
+            for i in 0..3 {
+                for k in 0..3 {
+                    covariance[i * 3 + k] += d[i] * d[k];
+                }
+            }
+        }
+
+        let (_, vectors) = Matrice::build_matrice(3, 3, covariance.to_vec())?.get_symmetric_eigen()?;
+        let v = &vectors.data;
+        let axes = [
+            Vector3D::build_vector(v[0], v[3], v[6]),
+            Vector3D::build_vector(v[1], v[4], v[7]),
+            Vector3D::build_vector(v[2], v[5], v[8]),
+        ];
+
+        let mut centre = mean;
+        let mut half_extents = [0.0; 3];
+        for (i, axis) in axes.iter().enumerate() {
+            let (low, high) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), p| {
+                let s = (*p - mean).get_scalar_product(*axis);
+                (low.min(s), high.max(s))
+            });
+            centre += *axis * ((low + high) / 2.0);
+            half_extents[i] = (high - low) / 2.0;
+        }
+
+        Ok(Obb3D {centre, axes, half_extents})
+    }
+
+    /// Checks if a point lies inside of the box or on its border
+    pub fn contains_point<T>(self, p: Vector3D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let d = p.convert_to_f64() - self.centre;
+        self.axes.iter().zip(self.half_extents.iter()).all(|(axis, h)| d.get_scalar_product(*axis).abs() <= h + 1e-12 * (1.0 + h))
+    }
+
+    /// Returns the eight corners of the box
+    pub fn get_corners(self) -> [Vector3D<f64>; 8] {
+        let u = self.axes[0] * self.half_extents[0];
+        let v = self.axes[1] * self.half_extents[1];
+        let w = self.axes[2] * self.half_extents[2];
+        let c = self.centre;
+        [c - u - v - w, c + u - v - w, c + u + v - w, c - u + v - w, c - u - v + w, c + u - v + w, c + u + v + w, c - u + v + w]
+    }
+
+    /// Converts the box to a cuboid with the extents along the three axes as length, width and height
+    /// # Return values
+    /// Returns the cuboid in case of success
+    ///
+    /// Returns NegativeValueError if the box has no volume
+    pub fn to_cuboid(self) -> Result<Cuboid, MatholError> {
+        Cuboid::build_cuboid(2.0 * self.half_extents[0], 2.0 * self.half_extents[1], 2.0 * self.half_extents[2])
+    }
+}
+
+impl Volume for Obb3D {
+    /// Calculates the volume of the box
+    fn get_volume(self) -> f64 {
+        8.0 * self.half_extents[0] * self.half_extents[1] * self.half_extents[2]
+    }
+}
+
+impl Surface for Obb3D {
+    /// Calculates the surface of the box
+    fn get_surface(self) -> f64 {
+        let h = self.half_extents;
+        8.0 * (h[0] * h[1] + h[0] * h[2] + h[1] * h[2])
+    }
+}
+
+impl ApproxEq for Obb3D {
+    fn approx_eq(&self, other: &Obb3D, tolerance: Tolerance) -> bool {
+        self.centre.approx_eq(&other.centre, tolerance)
+            && self.axes.iter().zip(other.axes.iter()).all(|(a, b)| a.approx_eq(b, tolerance))
+            && self.half_extents.iter().zip(other.half_extents.iter()).all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
@@ -0,0 +1,440 @@
+use vectoroperations::vector2d::Vector2D;
+use geometrics::planimetry::{SimplePolygon, Orientation};
+use error::*;
+
+const EPSILON: f64 = 1e-10;
+/// The number of times the border is moved before a degenerate case is given up
+const MAX_ATTEMPTS: usize = 8;
+
+
+/// The boolean operations on polygons
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Operation {
+    Intersection,
+    Union,
+    Difference,
+}
+
+/// A vertex of a polygon during the Greiner-Hormann algorithm
+#[derive(Debug, Copy, Clone)]
+struct Node {
+    point: Vector2D<f64>,
+    next: usize,
+    prev: usize,
+    /// Index of the same intersection point in the other polygon
+    neighbour: Option<usize>,
+    entry: bool,
+    visited: bool,
+}
+
+impl SimplePolygon {
+    /// Clips the polygon with a convex polygon
+    /// # Remarks
+    /// Uses the Sutherland-Hodgman algorithm. If the polygon is not convex and the clip region cuts
+    /// it into several parts, the parts are connected with edges of zero width along the border of the
+    /// clip region.
+    /// # Return values
+    /// Returns the clipped polygon or an empty vector if the polygons do not overlap
+    ///
+    /// Returns VectorError if the clip polygon is not convex
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::geometrics::traits::Area;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let square = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0),
+    ///     Vector2D::build_vector(4, 4), Vector2D::build_vector(0, 4)]).expect("error");
+    /// let triangle = SimplePolygon::build_polygon(vec![Vector2D::build_vector(2, 2), Vector2D::build_vector(6, 2),
+    ///     Vector2D::build_vector(2, 6)]).expect("error");
+    /// let clipped = square.clip_with_convex_polygon(&triangle).expect("error");
+    /// assert_eq!(4.0, clipped[0].get_area());
+    /// ```
+    pub fn clip_with_convex_polygon(&self, clip: &SimplePolygon) -> Result<Vec<SimplePolygon>, MatholError> {
+        if !clip.is_convex() {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The clip polygon must be convex".to_string(),
+            }));
+        }
+
+        let clip = clip.to_counterclockwise();
+        let mut output = self.vertices.clone();
+        for (a, b) in clip.get_edges() {
+            let input = output;
+            output = Vec::with_capacity(input.len() + 1);
+            let side = |p: Vector2D<f64>| (b - a).get_vector_product(p - a);
+            for (i, &q) in input.iter().enumerate() {
+                let p = input[(i + input.len() - 1) % input.len()];
+                let (side_p, side_q) = (side(p), side(q));
+                if side_q >= 0.0 {
+                    if side_p < 0.0 {
+                        output.push(p + (q - p) * (side_p / (side_p - side_q)));
+                    }
+                    output.push(q);
+                } else if side_p >= 0.0 {
+                    output.push(p + (q - p) * (side_p / (side_p - side_q)));
+                }
+            }
+            if output.is_empty() {
+                return Ok(Vec::new());
+            }
+        }
+
+        output.dedup();
+        if output.len() > 1 && output[0] == output[output.len() - 1] {
+            output.pop();
+        }
+        let polygon = SimplePolygon {vertices: output};
+        if polygon.vertices.len() < 3 || polygon.get_orientation() == Orientation::Degenerate {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![polygon])
+    }
+
+    /// Calculates the area that is covered by both polygons
+    /// # Remarks
+    /// Uses the Greiner-Hormann algorithm, see get_union for the details
+    /// # Return values
+    /// Returns the resulting polygons in case of success
+    ///
+    /// Returns VectorError if a coordinate is not finite or if a degenerate case remains after
+    /// moving the border several times and neither polygon is convex
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::geometrics::traits::Area;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let p = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0),
+    ///     Vector2D::build_vector(4, 4), Vector2D::build_vector(0, 4)]).expect("error");
+    /// let q = SimplePolygon::build_polygon(vec![Vector2D::build_vector(2, 1), Vector2D::build_vector(6, 1),
+    ///     Vector2D::build_vector(6, 3), Vector2D::build_vector(2, 3)]).expect("error");
+    /// let intersection = p.get_intersection(&q).expect("error");
+    /// assert_eq!(1, intersection.len());
+    /// assert!((intersection[0].get_area() - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn get_intersection(&self, other: &SimplePolygon) -> Result<Vec<SimplePolygon>, MatholError> {
+        self.combine(other, Operation::Intersection)
+    }
+
+    /// Calculates the area that is covered by at least one of the polygons
+    /// # Remarks
+    /// Uses the Greiner-Hormann algorithm. The results of the boolean operations are in
+    /// counterclockwise order, except for holes, which are in clockwise order. So the covered area
+    /// is the sum of the signed areas of all resulting polygons.
+    ///
+    /// The algorithm cannot handle vertices that lie on an edge of the other polygon or edges that
+    /// overlap. In this case the border of the polygon is moved by a distance of about 1e-8 times its
+    /// size until these cases disappear, outwards for the union and inwards for the intersection and
+    /// the difference. So touching polygons are merged by the union, but do not intersect, and the
+    /// vertices of the result may differ from the exact ones by this amount. After eight unsuccessful
+    /// attempts the intersection falls back to clip_with_convex_polygon if one polygon is convex.
+    ///
+    /// Repeated vertices and spikes of zero width are removed before, a polygon without area
+    /// covers nothing.
+    /// # Return values
+    /// Returns the resulting polygons in case of success
+    ///
+    /// Returns VectorError if a coordinate is not finite or if a degenerate case remains after
+    /// moving the border several times
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::geometrics::traits::Area;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let p = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0),
+    ///     Vector2D::build_vector(4, 4), Vector2D::build_vector(0, 4)]).expect("error");
+    /// let q = SimplePolygon::build_polygon(vec![Vector2D::build_vector(2, 1), Vector2D::build_vector(6, 1),
+    ///     Vector2D::build_vector(6, 3), Vector2D::build_vector(2, 3)]).expect("error");
+    /// let union = p.get_union(&q).expect("error");
+    /// assert_eq!(1, union.len());
+    /// assert!((union[0].get_area() - 20.0).abs() < 1e-12);
+    /// ```
+    pub fn get_union(&self, other: &SimplePolygon) -> Result<Vec<SimplePolygon>, MatholError> {
+        self.combine(other, Operation::Union)
+    }
+
+    /// Calculates the area that is covered by the polygon, but not by the other one
+    /// # Remarks
+    /// Uses the Greiner-Hormann algorithm, see get_union for the details. If the other polygon
+    /// lies inside of the polygon, the result is the polygon and the other one as clockwise hole.
+    /// # Return values
+    /// Returns the resulting polygons in case of success
+    ///
+    /// Returns VectorError if a coordinate is not finite or if a degenerate case remains after
+    /// moving the border several times
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::geometrics::traits::Area;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let p = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0),
+    ///     Vector2D::build_vector(4, 4), Vector2D::build_vector(0, 4)]).expect("error");
+    /// let q = SimplePolygon::build_polygon(vec![Vector2D::build_vector(2, 1), Vector2D::build_vector(6, 1),
+    ///     Vector2D::build_vector(6, 3), Vector2D::build_vector(2, 3)]).expect("error");
+    /// let difference = p.get_difference(&q).expect("error");
+    /// assert_eq!(1, difference.len());
+    /// assert!((difference[0].get_area() - 12.0).abs() < 1e-12);
+    /// ```
+    pub fn get_difference(&self, other: &SimplePolygon) -> Result<Vec<SimplePolygon>, MatholError> {
+        self.combine(other, Operation::Difference)
+    }
+
+    /// Returns the polygon with the vertices in counterclockwise order
+    pub fn to_counterclockwise(&self) -> SimplePolygon {
+        match self.get_orientation() {
+            Orientation::Clockwise => self.reverse(),
+            _ => self.clone(),
+        }
+    }
+
+    fn combine(&self, other: &SimplePolygon, operation: Operation) -> Result<Vec<SimplePolygon>, MatholError> {
+        if self.vertices.iter().chain(other.vertices.iter()).any(|p| !p.x.is_finite() || !p.y.is_finite()) {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The polygons must have finite coordinates".to_string(),
+            }));
+        }
+        let scale = self.vertices.iter().chain(other.vertices.iter())
+            .fold(1.0_f64, |m, p| m.max(p.x.abs()).max(p.y.abs()));
+        let subject = self.remove_degenerate_vertices(scale).to_counterclockwise();
+        let clip = other.remove_degenerate_vertices(scale).to_counterclockwise();
+
+        // A polygon without area covers nothing
+        let empty = |p: &SimplePolygon| p.vertices.len() < 3 || p.get_orientation() == Orientation::Degenerate;
+        if empty(&subject) || empty(&clip) {
+            return Ok(match operation {
+                Operation::Union if empty(&subject) && empty(&clip) => Vec::new(),
+                Operation::Union if empty(&subject) => vec![clip],
+                Operation::Union | Operation::Difference if !empty(&subject) => vec![subject],
+                _ => Vec::new(),
+            });
+        }
+
+        let direction = if operation == Operation::Union { 1.0 } else { -1.0 };
+        let mut moved = subject.clone();
+        for attempt in 1..=MAX_ATTEMPTS {
+            if let Some(result) = moved.combine_without_degeneracy(&subject, &clip, operation, scale) {
+                return Ok(result);
+            }
+            moved = subject.offset_border(direction * 1e-8 * scale * attempt as f64);
+        }
+
+        // Sutherland-Hodgman has no problem with degenerate cases, but needs a convex clip polygon
+        if operation == Operation::Intersection {
+            if clip.is_convex() {
+                return subject.clip_with_convex_polygon(&clip);
+            }
+            if subject.is_convex() {
+                return clip.clip_with_convex_polygon(&subject);
+            }
+        }
+        Err(MatholError::VectorCause(VectorError {
+            message: "The degenerate case could not be resolved by moving the border".to_string(),
+        }))
+    }
+
+    /// Removes repeated vertices and the tips of spikes, whose edges run back along each other
+    /// # Remarks
+    /// Both have edges of zero length or zero width, which the Greiner-Hormann algorithm cannot handle
+    fn remove_degenerate_vertices(&self, scale: f64) -> SimplePolygon {
+        let mut vertices = self.vertices.clone();
+        let is_degenerate = |vertices: &[Vector2D<f64>], i: usize| {
+            let n = vertices.len();
+            let (u, v) = (vertices[i] - vertices[(i + n - 1) % n], vertices[(i + 1) % n] - vertices[i]);
+            u.get_length() <= EPSILON * scale
+                || (u.get_vector_product(v).abs() <= EPSILON * u.get_length() * v.get_length() && u.get_scalar_product(v) < 0.0)
+        };
+        // A removal can turn a neighbour into a spike, so search again after every removal
+        while vertices.len() > 2 {
+            match (0..vertices.len()).find(|&i| is_degenerate(&vertices, i)) {
+                Some(i) => { vertices.remove(i); },
+                None => break,
+            }
+        }
+
+        SimplePolygon {vertices}
+    }
+
+    /// Moves every edge of a counterclockwise polygon outwards by about the given distance
+    /// # Remarks
+    /// A negative distance moves the edges inwards
+    fn offset_border(&self, distance: f64) -> SimplePolygon {
+        let n = self.vertices.len();
+        let normal = |i: usize| {
+            let edge = self.vertices[(i + 1) % n] - self.vertices[i];
+            Vector2D::build_vector(edge.y, -edge.x) / edge.get_length()
+        };
+
+        SimplePolygon {
+            vertices: (0..n).map(|i| {
+                let direction = normal((i + n - 1) % n) + normal(i);
+                let direction = if direction.get_length() < 1e-6 { normal(i) } else { direction / direction.get_length() };
+                self.vertices[i] + direction * distance
+            }).collect(),
+        }
+    }
+
+    /// Runs the Greiner-Hormann algorithm
+    /// # Remarks
+    /// The polygon is the subject with the moved border, the original subject is returned if
+    /// the borders do not cut each other
+    /// # Return values
+    /// Returns None if a vertex lies on an edge of the other polygon or if two edges overlap
+    fn combine_without_degeneracy(&self, original: &SimplePolygon, clip: &SimplePolygon, operation: Operation, scale: f64) -> Option<Vec<SimplePolygon>> {
+        let mut subject_nodes = build_nodes(&self.vertices);
+        let mut clip_nodes = build_nodes(&clip.vertices);
+        let (n, m) = (self.vertices.len(), clip.vertices.len());
+
+        let mut subject_cuts: Vec<Vec<(f64, usize)>> = vec![Vec::new(); n];
+        let mut clip_cuts: Vec<Vec<(f64, usize)>> = vec![Vec::new(); m];
+        for (i, subject_edge_cuts) in subject_cuts.iter_mut().enumerate() {
+            let (p, q) = (self.vertices[i], self.vertices[(i + 1) % n]);
+            for (k, clip_edge_cuts) in clip_cuts.iter_mut().enumerate() {
+                let (a, b) = (clip.vertices[k], clip.vertices[(k + 1) % m]);
+                let (r, s, w) = (q - p, b - a, a - p);
+                let denominator = r.get_vector_product(s);
+                if denominator.abs() <= EPSILON * r.get_length() * s.get_length() {
+                    if w.get_vector_product(r).abs() <= EPSILON * scale * r.get_length() {
+                        let (t0, t1) = (w.get_scalar_product(r), (b - p).get_scalar_product(r));
+                        let rr = r.get_scalar_product(r);
+                        if t0.max(t1) >= -EPSILON * rr && t0.min(t1) <= rr * (1.0 + EPSILON) {
+                            return None;
+                        }
+                    }
+                    continue;
+                }
+
+                let t = w.get_vector_product(s) / denominator;
+                let u = w.get_vector_product(r) / denominator;
+                if !(-EPSILON..=1.0 + EPSILON).contains(&t) || !(-EPSILON..=1.0 + EPSILON).contains(&u) {
+                    continue;
+                }
+                if t <= EPSILON || t >= 1.0 - EPSILON || u <= EPSILON || u >= 1.0 - EPSILON {
+                    return None;
+                }
+
+                let point = p + r * t;
+                let (s_index, c_index) = (subject_nodes.len(), clip_nodes.len());
+                subject_nodes.push(Node {point, next: 0, prev: 0, neighbour: Some(c_index), entry: false, visited: false});
+                clip_nodes.push(Node {point, next: 0, prev: 0, neighbour: Some(s_index), entry: false, visited: false});
+                subject_edge_cuts.push((t, s_index));
+                clip_edge_cuts.push((u, c_index));
+            }
+        }
+
+        if subject_nodes.len() == n {
+            return Some(combine_disjoint(self, original, clip, operation));
+        }
+
+        insert_cuts(&mut subject_nodes, subject_cuts);
+        insert_cuts(&mut clip_nodes, clip_cuts);
+        mark_entries(&mut subject_nodes, clip, operation != Operation::Intersection);
+        mark_entries(&mut clip_nodes, self, operation == Operation::Union);
+
+        let mut result = Vec::new();
+        while let Some(start) = (n..subject_nodes.len()).find(|&i| subject_nodes[i].entry && !subject_nodes[i].visited) {
+            let mut vertices = vec![subject_nodes[start].point];
+            let mut on_subject = true;
+            let mut current = start;
+            loop {
+                let nodes = if on_subject { &mut subject_nodes } else { &mut clip_nodes };
+                nodes[current].visited = true;
+                let forward = nodes[current].entry;
+                loop {
+                    current = if forward { nodes[current].next } else { nodes[current].prev };
+                    vertices.push(nodes[current].point);
+                    if nodes[current].neighbour.is_some() {
+                        break;
+                    }
+                }
+                nodes[current].visited = true;
+                current = nodes[current].neighbour.unwrap();
+                on_subject = !on_subject;
+                let nodes = if on_subject { &mut subject_nodes } else { &mut clip_nodes };
+                nodes[current].visited = true;
+                if on_subject && current == start {
+                    break;
+                }
+                if !on_subject && nodes[current].neighbour == Some(start) {
+                    break;
+                }
+            }
+
+            vertices.pop();
+            if vertices.len() >= 3 {
+                result.push(SimplePolygon {vertices});
+            }
+        }
+
+        Some(result)
+    }
+}
+
+/// Builds a circular list of the vertices of a polygon
+fn build_nodes(vertices: &[Vector2D<f64>]) -> Vec<Node> {
+    let n = vertices.len();
+    vertices.iter().enumerate().map(|(i, &point)| Node {
+        point,
+        next: (i + 1) % n,
+        prev: (i + n - 1) % n,
+        neighbour: None,
+        entry: false,
+        visited: false,
+    }).collect()
+}
+
+/// Inserts the intersection points into the list between the vertices of their edges
+fn insert_cuts(nodes: &mut [Node], cuts: Vec<Vec<(f64, usize)>>) {
+    for (i, mut edge_cuts) in cuts.into_iter().enumerate() {
+        edge_cuts.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let end = nodes[i].next;
+        let mut previous = i;
+        for (_, index) in edge_cuts {
+            nodes[previous].next = index;
+            nodes[index].prev = previous;
+            previous = index;
+        }
+        nodes[previous].next = end;
+        nodes[end].prev = previous;
+    }
+}
+
+/// Marks every intersection point where the border enters the other polygon
+/// # Remarks
+/// If invert is true, the points where the border leaves the other polygon are marked instead
+fn mark_entries(nodes: &mut [Node], other: &SimplePolygon, invert: bool) {
+    let mut entry = other.contains_point(nodes[0].point) == invert;
+    let mut current = 0;
+    loop {
+        if nodes[current].neighbour.is_some() {
+            nodes[current].entry = entry;
+            entry = !entry;
+        }
+        current = nodes[current].next;
+        if current == 0 {
+            break;
+        }
+    }
+}
+
+/// Combines two polygons whose borders do not cut each other
+/// # Remarks
+/// The containment is checked with the moved subject, but the original one is returned
+fn combine_disjoint(moved: &SimplePolygon, subject: &SimplePolygon, clip: &SimplePolygon, operation: Operation) -> Vec<SimplePolygon> {
+    let subject_inside = clip.contains_point(moved.vertices[0]);
+    let clip_inside = moved.contains_point(clip.vertices[0]);
+    match operation {
+        Operation::Intersection if subject_inside => vec![subject.clone()],
+        Operation::Intersection if clip_inside => vec![clip.clone()],
+        Operation::Intersection => Vec::new(),
+        Operation::Union if subject_inside => vec![clip.clone()],
+        Operation::Union if clip_inside => vec![subject.clone()],
+        Operation::Union => vec![subject.clone(), clip.clone()],
+        Operation::Difference if subject_inside => Vec::new(),
+        Operation::Difference if clip_inside => vec![subject.clone(), clip.reverse()],
+        Operation::Difference => vec![subject.clone()],
+    }
+}
@@ -0,0 +1,412 @@
+use num::{Num, FromPrimitive};
+use std::fmt::Debug;
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use vectoroperations::vector2d::Vector2D;
+use vectoroperations::line2d::Line2D;
+use vectoroperations::transform::Transform2D;
+use matrices::matrice::Matrice;
+use geometrics::planimetry::{Ellipsis, Parabola, Hyperbola};
+use error::*;
+
+const EPSILON: f64 = 1e-10;
+
+
+/// The shape of the point set of a conic section
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ConicType {
+    Ellipsis,
+    Circle,
+    Parabola,
+    Hyperbola,
+    /// A degenerate ellipsis that consists of a single point
+    Point,
+    IntersectingLines,
+    ParallelLines,
+    /// Two parallel lines that coincide
+    CoincidentLines,
+    /// The equation has no real solution
+    Empty,
+}
+
+/// Struct representing a general conic section ax² + bxy + cy² + dx + ey + f = 0
+/// # Usage
+/// ```
+/// use mathol::geometrics::conic::Conic;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Conic {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+/// The canonical form of a conic section
+/// # Remarks
+/// In canonical coordinates the equation has no mixed term and the curve is centred at the
+/// origin or, for a parabola, has its vertex there:
+///
+/// Ellipsis and hyperbola: x²/a² ± y²/b² = 1 with the major or the real axis along the x axis
+///
+/// Parabola: y² = 2px with p > 0
+///
+/// Degenerate conics: ax² + cy² = 0 or y² + f = 0
+#[derive(Debug, Clone)]
+pub struct CanonicalConic {
+    pub kind: ConicType,
+    /// The equation in canonical coordinates
+    pub conic: Conic,
+    /// The rotation and translation that maps canonical coordinates to the original coordinates
+    pub transform: Transform2D,
+}
+
+impl Conic {
+    /// Creates the conic section ax² + bxy + cy² + dx + ey + f = 0
+    /// # Return values
+    /// Returns the conic section in case of success
+    ///
+    /// Returns VectorError if a, b and c are all zero
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::conic::Conic;
+    ///
+    /// let conic = Conic::build_conic(1, 0, 1, 0, 0, -4).expect("error");
+    /// assert_eq!(-4.0, conic.f);
+    /// ```
+    pub fn build_conic<T>(a: T, b: T, c: T, d: T, e: T, f: T) -> Result<Conic, MatholError>
+        where T: Num + Convert
+    {
+        if a.is_zero() && b.is_zero() && c.is_zero() {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The coefficients a, b and c must not all be zero".to_string(),
+            }));
+        }
+
+        Ok(Conic {
+            a: a.to_f64(),
+            b: b.to_f64(),
+            c: c.to_f64(),
+            d: d.to_f64(),
+            e: e.to_f64(),
+            f: f.to_f64(),
+        })
+    }
+
+    /// Returns the value of the left side of the equation at a point
+    pub fn get_value<T>(&self, p: Vector2D<T>) -> f64
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let (x, y) = (p.x.to_f64(), p.y.to_f64());
+        self.a * x * x + self.b * x * y + self.c * y * y + self.d * x + self.e * y + self.f
+    }
+
+    /// Returns the gradient of the left side of the equation at a point, which is normal to the curve
+    pub fn get_gradient<T>(&self, p: Vector2D<T>) -> Vector2D<f64>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let (x, y) = (p.x.to_f64(), p.y.to_f64());
+        Vector2D::build_vector(2.0 * self.a * x + self.b * y + self.d, self.b * x + 2.0 * self.c * y + self.e)
+    }
+
+    /// Checks if a point lies on the conic section
+    /// # Remarks
+    /// The value of the equation is compared relative to the magnitude of its terms
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::conic::Conic;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let conic = Conic::build_conic(0, 1, 0, 0, 0, -1).expect("error");
+    /// assert!(conic.is_point_on_conic(Vector2D::build_vector(4.0, 0.25)));
+    /// assert!(!conic.is_point_on_conic(Vector2D::build_vector(4.0, 0.5)));
+    /// ```
+    pub fn is_point_on_conic<T>(&self, p: Vector2D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let (x, y) = (p.x.to_f64(), p.y.to_f64());
+        let magnitude = (self.a * x * x).abs() + (self.b * x * y).abs() + (self.c * y * y).abs()
+            + (self.d * x).abs() + (self.e * y).abs() + self.f.abs();
+        self.get_value(p).abs() <= EPSILON * magnitude
+    }
+
+    /// Calculates the shape of the conic section
+    /// # Return values
+    /// Returns the type of the conic section in case of success
+    ///
+    /// Returns VectorError if the coefficients a, b and c are all zero
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::conic::{Conic, ConicType};
+    ///
+    /// assert_eq!(Ok(ConicType::Hyperbola), Conic::build_conic(0, 1, 0, 0, 0, -1).expect("error").get_type());
+    /// assert_eq!(Ok(ConicType::Parabola), Conic::build_conic(1, 0, 0, 0, -1, 0).expect("error").get_type());
+    /// assert_eq!(Ok(ConicType::IntersectingLines), Conic::build_conic(1, 0, -1, 0, 0, 0).expect("error").get_type());
+    /// ```
+    pub fn get_type(&self) -> Result<ConicType, MatholError> {
+        Ok(self.get_canonical_form()?.kind)
+    }
+
+    /// Calculates the canonical form of the conic section
+    /// # Remarks
+    /// The mixed term is removed by a rotation onto the eigenvectors of the matrice
+    /// ((a, b/2), (b/2, c)), the linear terms are removed by a translation onto the centre or the
+    /// vertex. The canonical equation is scaled as described for CanonicalConic.
+    /// # Return values
+    /// Returns the canonical form in case of success
+    ///
+    /// Returns VectorError if the coefficients a, b and c are all zero
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::conic::{Conic, ConicType};
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// // The ellipsis x²/4 + y² = 1, rotated by 45 degrees and moved by (1 | 2)
+    /// let conic = Conic::build_conic(5, -6, 5, 2, -14, 5).expect("error");
+    /// let canonical = conic.get_canonical_form().expect("error");
+    /// assert_eq!(ConicType::Ellipsis, canonical.kind);
+    /// assert!((canonical.conic.a - 0.25).abs() < 1e-12 && (canonical.conic.c - 1.0).abs() < 1e-12);
+    /// let centre = canonical.transform.apply_to_point(Vector2D::build_vector(0, 0));
+    /// assert!((centre.x - 1.0).abs() < 1e-12 && (centre.y - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn get_canonical_form(&self) -> Result<CanonicalConic, MatholError> {
+        let scale = [self.a, self.b, self.c, self.d, self.e, self.f].iter().fold(0.0f64, |m, x| m.max(x.abs()));
+        let (a, b, c, f) = (self.a / scale, self.b / scale, self.c / scale, self.f / scale);
+        if scale == 0.0 || (a == 0.0 && b == 0.0 && c == 0.0) {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The coefficients a, b and c must not all be zero".to_string(),
+            }));
+        }
+        let linear = Vector2D::build_vector(self.d / scale, self.e / scale);
+
+        let (values, vectors) = Matrice::build_matrice(2, 2, vec![a, b / 2.0, b / 2.0, c]).unwrap()
+            .get_symmetric_eigen().unwrap();
+        let axes = [Vector2D::build_vector(vectors.data[0], vectors.data[2]), Vector2D::build_vector(vectors.data[1], vectors.data[3])];
+        let largest = values[0].abs().max(values[1].abs());
+        let singular: Vec<bool> = values.iter().map(|v| v.abs() <= EPSILON * largest).collect();
+
+        let (kind, conic, u, origin) = if !singular[0] && !singular[1] {
+            let centre = axes[0] * (-linear.get_scalar_product(axes[0]) / (2.0 * values[0]))
+                + axes[1] * (-linear.get_scalar_product(axes[1]) / (2.0 * values[1]));
+            let shift = linear.get_scalar_product(centre) / 2.0;
+            let rest = f + shift;
+
+            if rest.abs() <= EPSILON * f.abs().max(shift.abs()) {
+                let kind = if values[0] * values[1] > 0.0 { ConicType::Point } else { ConicType::IntersectingLines };
+                (kind, [1.0, values[1] / values[0], 0.0, 0.0], axes[0], centre)
+            } else {
+                // Scale to x²/a² ± y²/b² = 1 and put the major or the real axis first
+                let k = [-values[0] / rest, -values[1] / rest];
+                let first = if (k[0] > 0.0 && k[1] > 0.0 && k[1] < k[0]) || (k[0] <= 0.0 && k[1] > 0.0) { 1 } else { 0 };
+                let (ku, kv) = (k[first], k[1 - first]);
+                let kind = if ku > 0.0 && kv > 0.0 {
+                    if (ku - kv).abs() <= EPSILON * kv { ConicType::Circle } else { ConicType::Ellipsis }
+                } else if ku < 0.0 && kv < 0.0 {
+                    ConicType::Empty
+                } else {
+                    ConicType::Hyperbola
+                };
+                (kind, [ku, kv, 0.0, -1.0], axes[first], centre)
+            }
+        } else {
+            // The axis of the vanishing eigenvalue becomes the x axis
+            let (w, v) = if singular[0] { (axes[0], axes[1]) } else { (axes[1], axes[0]) };
+            let lambda = if singular[0] { values[1] } else { values[0] };
+            let (along, across) = (linear.get_scalar_product(w), linear.get_scalar_product(v));
+            let k = -across / (2.0 * lambda);
+            let shift = lambda * k * k;
+            let rest = f - shift;
+
+            if along.abs() <= EPSILON * (linear.x.abs() + linear.y.abs()) {
+                let g = if rest.abs() <= EPSILON * f.abs().max(shift.abs()) { 0.0 } else { rest / lambda };
+                let kind = if g < 0.0 {
+                    ConicType::ParallelLines
+                } else if g == 0.0 {
+                    ConicType::CoincidentLines
+                } else {
+                    ConicType::Empty
+                };
+                (kind, [0.0, 1.0, 0.0, g], w, v * k)
+            } else {
+                // λy² + along * x = 0 becomes y² = 2px with the parabola opening towards positive x
+                let h = -rest / along;
+                let u = if along / lambda > 0.0 { w * -1.0 } else { w };
+                (ConicType::Parabola, [0.0, 1.0, -(along / lambda).abs(), 0.0], u, w * h + v * k)
+            }
+        };
+
+        let transform = Transform2D::build_rotation(u.y.atan2(u.x))
+            .compose_with(&Transform2D::build_translation(origin.x, origin.y));
+
+        Ok(CanonicalConic {
+            kind,
+            conic: Conic {a: conic[0], b: 0.0, c: conic[1], d: conic[2], e: 0.0, f: conic[3]},
+            transform,
+        })
+    }
+
+    /// Calculates the intersection points of a conic section and a line
+    /// # Return values
+    /// Returns no, one or two intersection points in the order of the direction vector of the line.
+    /// If the line touches the conic section, the touching point is returned once. If the line is
+    /// part of a degenerate conic section, no point is returned.
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::conic::Conic;
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let conic = Conic::build_conic(1, 0, 0, 0, -1, 0).expect("error");
+    /// let line = Line2D::build_line_from_slope_intercept(0, 4);
+    /// assert_eq!(vec![Vector2D::build_vector(-2.0, 4.0), Vector2D::build_vector(2.0, 4.0)], conic.intersection_with_line(line));
+    /// ```
+    pub fn intersection_with_line<T>(&self, line: Line2D<T>) -> Vec<Vector2D<f64>>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let (r, a) = (line.r.convert_to_f64(), line.a.convert_to_f64());
+        let quadratic = self.a * a.x * a.x + self.b * a.x * a.y + self.c * a.y * a.y;
+        let linear = self.get_gradient(r).get_scalar_product(a);
+        let constant = self.get_value(r);
+        let magnitude = (self.a.abs() + self.b.abs() + self.c.abs()) * a.get_scalar_product(a);
+
+        let mut parameters = if quadratic.abs() <= EPSILON * magnitude {
+            if linear == 0.0 { Vec::new() } else { vec![-constant / linear] }
+        } else {
+            let discriminant = linear * linear - 4.0 * quadratic * constant;
+            if discriminant.abs() <= EPSILON * (linear * linear + (4.0 * quadratic * constant).abs()) {
+                vec![-linear / (2.0 * quadratic)]
+            } else if discriminant < 0.0 {
+                Vec::new()
+            } else {
+                let root = discriminant.sqrt();
+                vec![(-linear - root) / (2.0 * quadratic), (-linear + root) / (2.0 * quadratic)]
+            }
+        };
+        parameters.sort_by(|s, t| s.partial_cmp(t).unwrap());
+        parameters.into_iter().map(|t| r + a * t).collect()
+    }
+
+    /// Calculates the tangent line in a point of the conic section
+    /// # Remarks
+    /// The tangent line has the point as support vector
+    /// # Return values
+    /// Returns the tangent line in case of success
+    ///
+    /// Returns VectorError if the point does not lie on the conic section or if the conic section
+    /// has no tangent in this point, like the crossing point of two lines
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::conic::Conic;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let conic = Conic::build_conic(1, 0, 0, 0, -1, 0).expect("error");
+    /// let tangent = conic.get_tangent(Vector2D::build_vector(1, 1)).expect("error");
+    /// assert_eq!((2.0, -1.0), tangent.get_slope_intercept().expect("error"));
+    /// ```
+    pub fn get_tangent<T>(&self, p: Vector2D<T>) -> Result<Line2D<f64>, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        if !self.is_point_on_conic(p) {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The point does not lie on the conic".to_string(),
+            }));
+        }
+        let normal = self.get_gradient(p);
+        if normal.get_length() <= EPSILON * (self.a.abs() + self.b.abs() + self.c.abs()) * (1.0 + p.convert_to_f64().get_length()) {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The conic has no tangent in a singular point".to_string(),
+            }));
+        }
+
+        Ok(Line2D {r: p.convert_to_f64(), a: Vector2D::build_vector(-normal.y, normal.x)})
+    }
+
+    /// Calculates the tangent lines from a point to the conic section
+    /// # Remarks
+    /// The touching points are the intersections with the polar line of the point. Every tangent
+    /// line has its touching point as support vector.
+    /// # Return values
+    /// Returns one tangent for points on the conic section and up to two tangents for other points
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::conic::Conic;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let conic = Conic::build_conic(1, 0, 0, 0, -1, 0).expect("error");
+    /// let tangents = conic.get_tangents_from_point(Vector2D::build_vector(0, -1));
+    /// assert_eq!(2, tangents.len());
+    /// assert!(tangents.iter().any(|t| t.r == Vector2D::build_vector(-1.0, 1.0)));
+    /// assert!(tangents.iter().any(|t| t.r == Vector2D::build_vector(1.0, 1.0)));
+    /// ```
+    pub fn get_tangents_from_point<T>(&self, p: Vector2D<T>) -> Vec<Line2D<f64>>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        if self.is_point_on_conic(p) {
+            return self.get_tangent(p).into_iter().collect();
+        }
+
+        let p = p.convert_to_f64();
+        let normal = self.get_gradient(p) / 2.0;
+        let constant = (self.d * p.x + self.e * p.y) / 2.0 + self.f;
+        match Line2D::build_line_from_general_form(normal.x, normal.y, constant) {
+            Ok(polar) => self.intersection_with_line(polar).into_iter()
+                .map(|touch| Line2D {r: touch, a: p - touch})
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+impl CanonicalConic {
+    /// Converts an elliptic canonical form to an ellipsis
+    /// # Return values
+    /// Returns the ellipsis in case of success
+    ///
+    /// Returns VectorError if the conic section is neither an ellipsis nor a circle
+    pub fn to_ellipsis(&self) -> Result<Ellipsis, MatholError> {
+        if self.kind != ConicType::Ellipsis && self.kind != ConicType::Circle {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The conic is not an ellipsis".to_string(),
+            }));
+        }
+        Ellipsis::build_ellipsis(1.0 / self.conic.a.sqrt(), 1.0 / self.conic.c.sqrt())
+    }
+
+    /// Converts a hyperbolic canonical form to a hyperbola
+    /// # Return values
+    /// Returns the hyperbola in case of success
+    ///
+    /// Returns VectorError if the conic section is no hyperbola
+    pub fn to_hyperbola(&self) -> Result<Hyperbola, MatholError> {
+        if self.kind != ConicType::Hyperbola {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The conic is not a hyperbola".to_string(),
+            }));
+        }
+        Hyperbola::build_hyperbola(1.0 / self.conic.a.sqrt(), 1.0 / (-self.conic.c).sqrt())
+    }
+
+    /// Converts a parabolic canonical form to a parabola
+    /// # Return values
+    /// Returns the parabola in case of success
+    ///
+    /// Returns VectorError if the conic section is no parabola
+    pub fn to_parabola(&self) -> Result<Parabola, MatholError> {
+        if self.kind != ConicType::Parabola {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The conic is not a parabola".to_string(),
+            }));
+        }
+        Parabola::build_parabola(-self.conic.d / 2.0)
+    }
+}
+
+impl ApproxEq for Conic {
+    fn approx_eq(&self, other: &Conic, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) && self.b.approx_eq(&other.b, tolerance) && self.c.approx_eq(&other.c, tolerance)
+            && self.d.approx_eq(&other.d, tolerance) && self.e.approx_eq(&other.e, tolerance) && self.f.approx_eq(&other.f, tolerance)
+    }
+}
@@ -0,0 +1,370 @@
+use num::{Num, FromPrimitive};
+use std::fmt::Debug;
+use std::cmp::{Ordering, PartialOrd};
+use std::collections::HashMap;
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use vectoroperations::vector2d::Vector2D;
+use vectoroperations::vector3d::Vector3D;
+use geometrics::planimetry::SimplePolygon;
+use geometrics::traits::{Volume, Surface};
+use error::*;
+
+
+/// Calculates the convex hull of a set of points in two-dimensional space
+/// # Remarks
+/// Uses Andrew's monotone chain algorithm. The vertices of the hull are returned in
+/// counterclockwise order, starting with the point with the smallest x coordinate
+/// (and the smallest y coordinate, if there are several). Duplicate points and points that lie
+/// on an edge of the hull are not part of the result.
+///
+/// If all points are equal, the result contains a single point. If all points lie on a line,
+/// the result contains the two end points of the line segment.
+/// # Examples
+/// ```
+/// use mathol::geometrics::hull::get_convex_hull_2d;
+/// use mathol::vectoroperations::vector2d::Vector2D;
+///
+/// let points = [Vector2D::build_vector(0, 0), Vector2D::build_vector(2, 2), Vector2D::build_vector(1, 1),
+///     Vector2D::build_vector(2, 0), Vector2D::build_vector(0, 2), Vector2D::build_vector(1, 0)];
+/// let hull = get_convex_hull_2d(&points);
+/// assert_eq!(vec![Vector2D::build_vector(0.0, 0.0), Vector2D::build_vector(2.0, 0.0),
+///     Vector2D::build_vector(2.0, 2.0), Vector2D::build_vector(0.0, 2.0)], hull);
+/// ```
+pub fn get_convex_hull_2d<T>(points: &[Vector2D<T>]) -> Vec<Vector2D<f64>>
+    where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+{
+    let mut points: Vec<Vector2D<f64>> = points.iter().map(|p| p.convert_to_f64()).collect();
+    points.sort_by(|p, q| p.x.partial_cmp(&q.x).unwrap_or(Ordering::Equal).then(p.y.partial_cmp(&q.y).unwrap_or(Ordering::Equal)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull: Vec<Vector2D<f64>> = Vec::with_capacity(2 * points.len());
+    for p in points.iter() {
+        push_hull_point(&mut hull, *p, 2);
+    }
+    let lower_length = hull.len() + 1;
+    for p in points.iter().rev().skip(1) {
+        push_hull_point(&mut hull, *p, lower_length);
+    }
+    hull.pop();
+
+    hull
+}
+
+/// Adds a point to a monotone chain and removes all points that no longer make a left turn
+fn push_hull_point(hull: &mut Vec<Vector2D<f64>>, p: Vector2D<f64>, min_length: usize) {
+    while hull.len() >= min_length {
+        let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+        if (b - a).get_vector_product(p - a) > 0.0 {
+            break;
+        }
+        hull.pop();
+    }
+    hull.push(p);
+}
+
+impl SimplePolygon {
+    /// Creates the convex hull of a set of points as polygon
+    /// # Remarks
+    /// The vertices are the result of get_convex_hull_2d, so they are in counterclockwise order
+    /// # Return values
+    /// Returns the polygon in case of success
+    ///
+    /// Returns LengthError if the hull has less than three vertices, which means that all points lie on a line
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::geometrics::traits::{Area, Perimeter};
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let points = [Vector2D::build_vector(0, 0), Vector2D::build_vector(3, 0), Vector2D::build_vector(1, 1), Vector2D::build_vector(0, 4)];
+    /// let hull = SimplePolygon::build_convex_hull(&points).expect("error");
+    /// assert_eq!(6.0, hull.get_area());
+    /// assert_eq!(12.0, hull.get_perimeter());
+    /// ```
+    pub fn build_convex_hull<T>(points: &[Vector2D<T>]) -> Result<SimplePolygon, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        SimplePolygon::build_polygon(get_convex_hull_2d(points))
+    }
+}
+
+
+/// Struct representing the convex hull of a set of points in three-dimensional space
+/// # Remarks
+/// The hull is a closed triangle mesh. Every face contains the indices of three vertices, which
+/// are in counterclockwise order when the face is seen from outside of the hull.
+/// # Usage
+/// ```
+/// use mathol::geometrics::hull::ConvexHull3D;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull3D {
+    /// The vertices of the hull
+    pub vertices: Vec<Vector3D<f64>>,
+    /// The triangular faces of the hull as indices into vertices
+    pub faces: Vec<[usize; 3]>,
+}
+
+/// A face of the hull while it is being built
+struct HullFace {
+    vertices: [usize; 3],
+    normal: Vector3D<f64>,
+    offset: f64,
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl HullFace {
+    fn build_face(points: &[Vector3D<f64>], vertices: [usize; 3]) -> HullFace {
+        let (a, b, c) = (points[vertices[0]], points[vertices[1]], points[vertices[2]]);
+        let normal = (b - a).get_vector_product(c - a);
+        let normal = normal / normal.get_length();
+        HullFace {vertices, normal, offset: normal.get_scalar_product(a), outside: Vec::new(), alive: true}
+    }
+
+    fn distance_from_point(&self, p: Vector3D<f64>) -> f64 {
+        self.normal.get_scalar_product(p) - self.offset
+    }
+
+    fn get_edges(&self) -> [(usize, usize); 3] {
+        let v = self.vertices;
+        [(v[0], v[1]), (v[1], v[2]), (v[2], v[0])]
+    }
+}
+
+impl ConvexHull3D {
+    /// Calculates the convex hull of a set of points
+    /// # Remarks
+    /// Uses the quickhull algorithm. Duplicate points and points that lie inside of the hull or on
+    /// one of its faces are not part of the result. Coplanar faces are not merged, so a flat side of
+    /// the hull is split into several triangles.
+    /// # Return values
+    /// Returns the hull in case of success
+    ///
+    /// Returns EmptyVectorError if the slice of points is empty
+    ///
+    /// Returns VectorError if all points lie in one plane
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::hull::ConvexHull3D;
+    /// use mathol::geometrics::traits::{Volume, Surface};
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let points = [Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(3, 0, 0), Vector3D::build_vector(0, 3, 0),
+    ///     Vector3D::build_vector(0, 0, 3), Vector3D::build_vector(1, 1, 1)];
+    /// let hull = ConvexHull3D::build_convex_hull(&points).expect("error");
+    /// assert_eq!(4, hull.vertices.len());
+    /// assert_eq!(4, hull.faces.len());
+    /// assert!((hull.get_volume() - 4.5).abs() < 1e-12);
+    /// ```
+    pub fn build_convex_hull<T>(points: &[Vector3D<T>]) -> Result<ConvexHull3D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        if points.is_empty() {
+            return Err(MatholError::EmptyVecCause(EmptyVectorError {
+                message: "Vector or Array is empty".to_string(),
+            }));
+        }
+
+        let points: Vec<Vector3D<f64>> = points.iter().map(|p| p.convert_to_f64()).collect();
+        let scale = points.iter().fold(0.0_f64, |m, p| m.max(p.x.abs()).max(p.y.abs()).max(p.z.abs()));
+        let epsilon = 1e-10 * scale.max(1.0);
+
+        let mut faces = ConvexHull3D::build_initial_simplex(&points, epsilon)?;
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+        for (i, face) in faces.iter().enumerate() {
+            for edge in face.get_edges().iter() {
+                edges.insert(*edge, i);
+            }
+        }
+        let used: Vec<usize> = faces.iter().flat_map(|f| f.vertices.to_vec()).collect();
+        let candidates: Vec<usize> = (0..points.len()).filter(|i| !used.contains(i)).collect();
+        let first: Vec<usize> = (0..faces.len()).collect();
+        ConvexHull3D::assign_points(&points, &mut faces, &first, candidates, epsilon);
+
+        while let Some(current) = faces.iter().position(|f| f.alive && !f.outside.is_empty()) {
+            let apex = *faces[current].outside.iter().max_by(|&&i, &&k| {
+                let (di, dk) = (faces[current].distance_from_point(points[i]), faces[current].distance_from_point(points[k]));
+                di.partial_cmp(&dk).unwrap_or(Ordering::Equal)
+            }).unwrap();
+
+            let mut visible = vec![current];
+            let mut stack = vec![current];
+            while let Some(f) = stack.pop() {
+                for &(a, b) in faces[f].get_edges().iter() {
+                    let neighbour = edges[&(b, a)];
+                    if !visible.contains(&neighbour) && faces[neighbour].distance_from_point(points[apex]) > epsilon {
+                        visible.push(neighbour);
+                        stack.push(neighbour);
+                    }
+                }
+            }
+
+            let mut horizon = Vec::new();
+            for &f in visible.iter() {
+                for &(a, b) in faces[f].get_edges().iter() {
+                    if !visible.contains(&edges[&(b, a)]) {
+                        horizon.push((a, b));
+                    }
+                }
+            }
+
+            let mut orphans = Vec::new();
+            for &f in visible.iter() {
+                for edge in faces[f].get_edges().iter() {
+                    edges.remove(edge);
+                }
+                faces[f].alive = false;
+                orphans.append(&mut faces[f].outside);
+            }
+
+            let mut created = Vec::with_capacity(horizon.len());
+            for &(a, b) in horizon.iter() {
+                let face = HullFace::build_face(&points, [a, b, apex]);
+                for edge in face.get_edges().iter() {
+                    edges.insert(*edge, faces.len());
+                }
+                created.push(faces.len());
+                faces.push(face);
+            }
+            orphans.retain(|&i| i != apex);
+            ConvexHull3D::assign_points(&points, &mut faces, &created, orphans, epsilon);
+        }
+
+        let mut index: HashMap<usize, usize> = HashMap::new();
+        let mut vertices = Vec::new();
+        let mut result = Vec::new();
+        for face in faces.iter().filter(|f| f.alive) {
+            let mut triangle = [0; 3];
+            for (k, v) in face.vertices.iter().enumerate() {
+                triangle[k] = *index.entry(*v).or_insert_with(|| {
+                    vertices.push(points[*v]);
+                    vertices.len() - 1
+                });
+            }
+            result.push(triangle);
+        }
+
+        Ok(ConvexHull3D {vertices, faces: result})
+    }
+
+    /// Builds a tetrahedron from four points of the set that are far apart
+    fn build_initial_simplex(points: &[Vector3D<f64>], epsilon: f64) -> Result<Vec<HullFace>, MatholError> {
+        let flat = || Err(MatholError::VectorCause(VectorError {
+            message: "The points must not all lie in one plane".to_string(),
+        }));
+
+        let mut extremes = Vec::with_capacity(6);
+        for axis in 0..3 {
+            let compare = |&i: &usize, &k: &usize| points[i][axis].partial_cmp(&points[k][axis]).unwrap_or(Ordering::Equal);
+            extremes.push((0..points.len()).min_by(compare).unwrap());
+            extremes.push((0..points.len()).max_by(compare).unwrap());
+        }
+        let mut pair = (extremes[0], extremes[0]);
+        let mut largest = 0.0;
+        for &i in extremes.iter() {
+            for &k in extremes.iter() {
+                let distance = (points[k] - points[i]).get_length();
+                if distance > largest {
+                    largest = distance;
+                    pair = (i, k);
+                }
+            }
+        }
+        if largest <= epsilon {
+            return flat();
+        }
+        let (a, b) = pair;
+
+        let direction = points[b] - points[a];
+        let c = ConvexHull3D::farthest(points, |p| direction.get_vector_product(p - points[a]).get_length() / direction.get_length());
+        let normal = direction.get_vector_product(points[c] - points[a]);
+        if normal.get_length() / direction.get_length() <= epsilon {
+            return flat();
+        }
+        let normal = normal / normal.get_length();
+        let d = ConvexHull3D::farthest(points, |p| normal.get_scalar_product(p - points[a]).abs());
+        let height = normal.get_scalar_product(points[d] - points[a]);
+        if height.abs() <= epsilon {
+            return flat();
+        }
+
+        let (b, c) = if height > 0.0 { (c, b) } else { (b, c) };
+        Ok(vec![
+            HullFace::build_face(points, [a, b, c]),
+            HullFace::build_face(points, [a, d, b]),
+            HullFace::build_face(points, [b, d, c]),
+            HullFace::build_face(points, [c, d, a]),
+        ])
+    }
+
+    /// Returns the index of the point with the largest value of measure
+    fn farthest<F>(points: &[Vector3D<f64>], measure: F) -> usize
+        where F: Fn(Vector3D<f64>) -> f64
+    {
+        (0..points.len()).max_by(|&i, &k| measure(points[i]).partial_cmp(&measure(points[k])).unwrap_or(Ordering::Equal)).unwrap()
+    }
+
+    /// Adds every point to the outside set of the first face it lies in front of
+    fn assign_points(points: &[Vector3D<f64>], faces: &mut [HullFace], targets: &[usize], candidates: Vec<usize>, epsilon: f64) {
+        for i in candidates {
+            if let Some(&f) = targets.iter().find(|&&f| faces[f].distance_from_point(points[i]) > epsilon) {
+                faces[f].outside.push(i);
+            }
+        }
+    }
+
+    /// Returns the three corners of a face
+    pub fn get_face_vertices(&self, face: usize) -> [Vector3D<f64>; 3] {
+        let f = self.faces[face];
+        [self.vertices[f[0]], self.vertices[f[1]], self.vertices[f[2]]]
+    }
+
+    /// Returns the centroid of the vertices, which lies inside of the hull
+    fn get_vertex_centre(&self) -> Vector3D<f64> {
+        self.vertices.iter().sum::<Vector3D<f64>>() / self.vertices.len() as f64
+    }
+}
+
+impl Volume for &ConvexHull3D {
+    /// Calculates the volume of the convex hull
+    /// # Remarks
+    /// The hull is split into tetrahedrons with one corner in the centre of the vertices
+    /// # Return values
+    /// Returns the calculated volume as f64 value
+    fn get_volume(self) -> f64 {
+        let centre = self.get_vertex_centre();
+        (0..self.faces.len()).map(|f| {
+            let [a, b, c] = self.get_face_vertices(f);
+            (a - centre).get_scalar_product((b - centre).get_vector_product(c - centre))
+        }).sum::<f64>() / 6.0
+    }
+}
+
+impl Surface for &ConvexHull3D {
+    /// Calculates the surface of the convex hull
+    /// # Remarks
+    /// The surface is the sum of the areas of all triangular faces
+    /// # Return values
+    /// Returns the calculated surface as f64 value
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::hull::ConvexHull3D;
+    /// use mathol::geometrics::traits::Surface;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let points = [Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(1, 0, 0), Vector3D::build_vector(0, 1, 0), Vector3D::build_vector(0, 0, 1)];
+    /// let hull = ConvexHull3D::build_convex_hull(&points).expect("error");
+    /// assert!((hull.get_surface() - (1.5 + 0.75_f64.sqrt())).abs() < 1e-12);
+    /// ```
+    fn get_surface(self) -> f64 {
+        (0..self.faces.len()).map(|f| {
+            let [a, b, c] = self.get_face_vertices(f);
+            (b - a).get_vector_product(c - a).get_length() / 2.0
+        }).sum()
+    }
+}
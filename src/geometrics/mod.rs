@@ -1,3 +1,8 @@
 pub mod planimetry;
 pub mod stereometry;
+pub mod bounding;
+pub mod hull;
+pub mod clipping;
+pub mod triangulation;
+pub mod conic;
 pub mod traits;
\ No newline at end of file
@@ -3,14 +3,21 @@ use basics::{pow, pythagoras2d};
 use basics::convert_trait::Convert;
 use basics::amount_trait::Amount;
 use basics::cotangent::Cotangent;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use basics::elliptic::{get_elliptic_integral_second_kind, get_complete_elliptic_integral_second_kind};
 use std::ops::Add;
 use std::cmp::PartialOrd;
 use std::fmt::Debug;
 use vectoroperations::vector2d::Vector2D;
+use vectoroperations::line2d::Line2D;
+use vectoroperations::segment::{Segment2D, SegmentIntersection};
 use num::{Num, FromPrimitive};
 use geometrics::traits::{Area, Perimeter, Height, Diagonal};
+use geometrics::conic::Conic;
 use error::*;
 
+const EPSILON: f64 = 1e-12;
+
 
 /// Struct representing a triangle in euclidean space
 /// # Usage
@@ -26,6 +33,10 @@ pub struct Triangle {
     pub b: f64,
     /// Edge c
     pub c: f64,
+    /// Vertices A, B and C, if the triangle was built from points
+    /// # Remarks
+    /// Vertex A lies opposite of edge a, B opposite of b and C opposite of c
+    pub vertices: Option<[Vector2D<f64>; 3]>,
 }
 
 impl Triangle
@@ -65,12 +76,15 @@ impl Triangle
             a: a.to_f64(),
             b: b.to_f64(),
             c: c.to_f64(),
+            vertices: None,
         };
 
         Ok(triangle)
     }
 
     /// Creates a triangle instance with the given vertices
+    /// # Remarks
+    /// The vertices are kept, so the centres of the triangle can be calculated as points
     /// # Parameters
     /// a: Vertice a
     ///
@@ -93,7 +107,157 @@ impl Triangle
         let b = A.get_distance(C);
         let c = A.get_distance(B);
 
-        Triangle::build_triangle_with_edges(a, b, c).unwrap()
+        let mut triangle = Triangle::build_triangle_with_edges(a, b, c).unwrap();
+        triangle.vertices = Some([A.convert_to_f64(), B.convert_to_f64(), C.convert_to_f64()]);
+        triangle
+    }
+
+    /// Creates a triangle instance from two edges and the enclosed angle (SAS)
+    /// # Parameters
+    /// b: Edge b
+    ///
+    /// c: Edge c
+    ///
+    /// alpha: Angle alpha between b and c in degrees
+    /// # Remarks
+    /// The law of cosines is used in the form a² = (b - c)² + 4bc * sin²(alpha / 2), which stays
+    /// accurate for small angles alpha
+    /// # Return values
+    /// Returns the triangle instance in case of success
+    ///
+    /// Returns LengthError if at least one of the given edges is not positive
+    ///
+    /// Returns RangeError if the angle does not lie between 0 and 180 degrees
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    ///
+    /// let triangle = Triangle::build_triangle_with_sas(4, 3, 90).expect("error");
+    /// assert!((triangle.a - 5.0).abs() < 1e-12);
+    /// ```
+    pub fn build_triangle_with_sas<T>(b: T, c: T, alpha: T) -> Result<Triangle, MatholError>
+        where T: Num + Convert + PartialOrd + Copy
+    {
+        let (b, c, alpha) = (b.to_f64(), c.to_f64(), alpha.to_f64());
+        check_edges(&[b, c])?;
+        check_angles(&[alpha])?;
+
+        let a = (pow(b - c, 2) + 4.0 * b * c * pow((alpha.to_radians() / 2.0).sin(), 2)).sqrt();
+        Triangle::build_triangle_with_edges(a, b, c)
+    }
+
+    /// Creates a triangle instance from one edge and the two angles at its ends (ASA)
+    /// # Parameters
+    /// beta: Angle beta at the start of a in degrees
+    ///
+    /// a: Edge a
+    ///
+    /// gamma: Angle gamma at the end of a in degrees
+    /// # Return values
+    /// Returns the triangle instance in case of success
+    ///
+    /// Returns LengthError if the given edge is not positive
+    ///
+    /// Returns RangeError if an angle does not lie between 0 and 180 degrees or if the sum of the
+    /// angles is not smaller than 180 degrees
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    ///
+    /// let triangle = Triangle::build_triangle_with_asa(60, 2, 60).expect("error");
+    /// assert!((triangle.b - 2.0).abs() < 1e-12 && (triangle.c - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn build_triangle_with_asa<T>(beta: T, a: T, gamma: T) -> Result<Triangle, MatholError>
+        where T: Num + Convert + PartialOrd + Copy
+    {
+        let (beta, a, gamma) = (beta.to_f64(), a.to_f64(), gamma.to_f64());
+        check_edges(&[a])?;
+        check_angles(&[beta, gamma])?;
+
+        let alpha = 180.0 - beta - gamma;
+        let ratio = a / alpha.to_radians().sin();
+        Triangle::build_triangle_with_edges(a, ratio * beta.to_radians().sin(), ratio * gamma.to_radians().sin())
+    }
+
+    /// Creates a triangle instance from two angles and the edge opposite of the first angle (AAS)
+    /// # Parameters
+    /// alpha: Angle alpha in degrees
+    ///
+    /// beta: Angle beta in degrees
+    ///
+    /// a: Edge a opposite of alpha
+    /// # Return values
+    /// Returns the triangle instance in case of success
+    ///
+    /// Returns LengthError if the given edge is not positive
+    ///
+    /// Returns RangeError if an angle does not lie between 0 and 180 degrees or if the sum of the
+    /// angles is not smaller than 180 degrees
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    ///
+    /// let triangle = Triangle::build_triangle_with_aas(30, 90, 1).expect("error");
+    /// assert!((triangle.b - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn build_triangle_with_aas<T>(alpha: T, beta: T, a: T) -> Result<Triangle, MatholError>
+        where T: Num + Convert + PartialOrd + Copy
+    {
+        let (alpha, beta, a) = (alpha.to_f64(), beta.to_f64(), a.to_f64());
+        check_edges(&[a])?;
+        check_angles(&[alpha, beta])?;
+
+        let gamma = 180.0 - alpha - beta;
+        let ratio = a / alpha.to_radians().sin();
+        Triangle::build_triangle_with_edges(a, ratio * beta.to_radians().sin(), ratio * gamma.to_radians().sin())
+    }
+
+    /// Creates all triangles with two given edges and the angle opposite of the first edge (SSA)
+    /// # Parameters
+    /// a: Edge a
+    ///
+    /// b: Edge b
+    ///
+    /// alpha: Angle alpha opposite of a in degrees
+    /// # Remarks
+    /// This case is ambiguous. Depending on the values, there are no, one or two triangles.
+    /// A second triangle with an obtuse angle beta only exists if a is shorter than b, it comes
+    /// after the one with the acute angle beta. Triangles whose angle gamma vanishes are omitted.
+    /// # Return values
+    /// Returns the triangle instances in case of success
+    ///
+    /// Returns LengthError if at least one of the given edges is not positive
+    ///
+    /// Returns RangeError if the angle does not lie between 0 and 180 degrees
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    ///
+    /// assert_eq!(2, Triangle::build_triangles_with_ssa(3, 4, 30).expect("error").len());
+    /// assert_eq!(1, Triangle::build_triangles_with_ssa(2, 4, 30).expect("error").len());
+    /// assert_eq!(0, Triangle::build_triangles_with_ssa(1, 4, 30).expect("error").len());
+    /// ```
+    pub fn build_triangles_with_ssa<T>(a: T, b: T, alpha: T) -> Result<Vec<Triangle>, MatholError>
+        where T: Num + Convert + PartialOrd + Copy
+    {
+        let (a, b, alpha) = (a.to_f64(), b.to_f64(), alpha.to_f64());
+        check_edges(&[a, b])?;
+        check_angles(&[alpha])?;
+
+        let sin_beta = b * alpha.to_radians().sin() / a;
+        if sin_beta > 1.0 + 1e-12 {
+            return Ok(Vec::new());
+        }
+
+        let beta = if (sin_beta - 1.0).abs() <= 1e-12 { 90.0 } else { sin_beta.asin().to_degrees() };
+        let mut candidates = vec![beta];
+        if a < b && beta < 90.0 {
+            candidates.push(180.0 - beta);
+        }
+        candidates.into_iter()
+            .filter(|beta| 180.0 - alpha - beta > 180.0 * 1e-12)
+            .map(|beta| Triangle::build_triangle_with_aas(alpha, beta, a))
+            .collect()
     }
 
     /// Calculates the value of each of the three angles in a triangle
@@ -148,6 +312,169 @@ impl Triangle
         let r = (self.a * self.b * self.c) / (4.0 * (s * (s - self.a) * (s - self.b) * (s - self.c)).sqrt());
         Circle::build_circle(r).unwrap()
     }
+
+    /// Returns the vertices of the triangle
+    /// # Return values
+    /// Returns the vertices A, B and C in case of success
+    ///
+    /// Returns VectorError if the triangle was not built from points
+    pub fn get_vertices(self) -> Result<[Vector2D<f64>; 3], MatholError> {
+        self.vertices.ok_or_else(|| MatholError::VectorCause(VectorError {
+            message: "The triangle has no vertices".to_string(),
+        }))
+    }
+
+    /// Calculates the centroid of a triangle, where the three medians meet
+    /// # Return values
+    /// Returns the centroid in case of success
+    ///
+    /// Returns VectorError if the triangle was not built from points
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let triangle = Triangle::build_triangle_with_points(Vector2D::build_vector(0, 0), Vector2D::build_vector(6, 0), Vector2D::build_vector(0, 3));
+    /// assert_eq!(Ok(Vector2D::build_vector(2.0, 1.0)), triangle.get_centroid());
+    /// ```
+    pub fn get_centroid(self) -> Result<Vector2D<f64>, MatholError> {
+        let [a, b, c] = self.get_vertices()?;
+        Ok((a + b + c) / 3.0)
+    }
+
+    /// Calculates the incenter of a triangle, which is the centre of the inner circle
+    /// # Return values
+    /// Returns the incenter in case of success
+    ///
+    /// Returns VectorError if the triangle was not built from points
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let triangle = Triangle::build_triangle_with_points(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(0, 3));
+    /// assert_eq!(Ok(Vector2D::build_vector(1.0, 1.0)), triangle.get_incenter());
+    /// ```
+    pub fn get_incenter(self) -> Result<Vector2D<f64>, MatholError> {
+        let [a, b, c] = self.get_vertices()?;
+        Ok((a * self.a + b * self.b + c * self.c) / self.get_perimeter())
+    }
+
+    /// Calculates the circumcenter of a triangle, which is the centre of the outer circle
+    /// # Return values
+    /// Returns the circumcenter in case of success
+    ///
+    /// Returns VectorError if the triangle was not built from points
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let triangle = Triangle::build_triangle_with_points(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(0, 3));
+    /// assert_eq!(Ok(Vector2D::build_vector(2.0, 1.5)), triangle.get_circumcenter());
+    /// ```
+    pub fn get_circumcenter(self) -> Result<Vector2D<f64>, MatholError> {
+        let [a, b, c] = self.get_vertices()?;
+        Ok(get_circumcenter(a, b, c))
+    }
+
+    /// Calculates the orthocenter of a triangle, where the three altitudes meet
+    /// # Return values
+    /// Returns the orthocenter in case of success
+    ///
+    /// Returns VectorError if the triangle was not built from points
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let triangle = Triangle::build_triangle_with_points(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(1, 3));
+    /// assert_eq!(Ok(Vector2D::build_vector(1.0, 1.0)), triangle.get_orthocenter());
+    /// ```
+    pub fn get_orthocenter(self) -> Result<Vector2D<f64>, MatholError> {
+        let [a, b, c] = self.get_vertices()?;
+        Ok(a + b + c - self.get_circumcenter()? * 2.0)
+    }
+
+    /// Calculates the Euler line of a triangle
+    /// # Remarks
+    /// The Euler line goes through centroid, circumcenter, orthocenter and the centre of the
+    /// nine-point circle. The resulting line has the circumcenter as support vector and points
+    /// towards the orthocenter.
+    /// # Return values
+    /// Returns the Euler line in case of success
+    ///
+    /// Returns VectorError if the triangle was not built from points or if it is equilateral,
+    /// because then all centres are the same point
+    pub fn get_euler_line(self) -> Result<Line2D<f64>, MatholError> {
+        let circumcenter = self.get_circumcenter()?;
+        let orthocenter = self.get_orthocenter()?;
+        if circumcenter.get_distance(orthocenter) <= 1e-12 * self.get_perimeter() {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "An equilateral triangle has no Euler line".to_string(),
+            }));
+        }
+
+        Ok(Line2D::build_line_from_two_points(circumcenter, orthocenter))
+    }
+
+    /// Calculates the nine-point circle of a triangle
+    /// # Remarks
+    /// The nine-point circle goes through the midpoints of the edges, the feet of the altitudes and
+    /// the midpoints between the orthocenter and the vertices. Its radius is half of the radius of
+    /// the outer circle.
+    /// # Return values
+    /// Returns the circle in case of success
+    ///
+    /// Returns VectorError if the triangle was not built from points
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Triangle;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let triangle = Triangle::build_triangle_with_points(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(0, 3));
+    /// let circle = triangle.get_nine_point_circle().unwrap();
+    /// assert_eq!(Vector2D::build_vector(1.0, 0.75), circle.centre);
+    /// assert_eq!(1.25, circle.r);
+    /// ```
+    pub fn get_nine_point_circle(self) -> Result<Circle2D, MatholError> {
+        let centre = self.get_circumcenter()?.lerp(self.get_orthocenter()?, 0.5);
+        Ok(Circle2D {centre, r: self.get_outer_circle().r / 2.0})
+    }
+}
+
+/// Calculates the centre of the circle through the points a, b and c
+fn get_circumcenter(a: Vector2D<f64>, b: Vector2D<f64>, c: Vector2D<f64>) -> Vector2D<f64> {
+    let (u, v) = (b - a, c - a);
+    let (uu, vv) = (u.get_scalar_product(u), v.get_scalar_product(v));
+    a + Vector2D::build_vector(v.y * uu - u.y * vv, u.x * vv - v.x * uu) / (2.0 * u.get_vector_product(v))
+}
+
+/// Checks that all edges of a triangle are positive
+fn check_edges(edges: &[f64]) -> Result<(), MatholError> {
+    if edges.iter().any(|&e| e <= 0.0) {
+        return Err(MatholError::LengthCause(LengthError {
+            message: "Length of triangle edges must be positive".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Checks that all angles of a triangle and their sum lie between 0 and 180 degrees
+fn check_angles(angles: &[f64]) -> Result<(), MatholError> {
+    if angles.iter().any(|&angle| angle <= 0.0 || angle >= 180.0) {
+        return Err(MatholError::RangeCause(RangeError {
+            message: "Triangle angles must lie between 0 and 180 degrees".to_string(),
+        }));
+    }
+    if angles.iter().sum::<f64>() >= 180.0 {
+        return Err(MatholError::RangeCause(RangeError {
+            message: "The sum of the triangle angles must be less than 180 degrees".to_string(),
+        }));
+    }
+
+    Ok(())
 }
 
 impl Area for Triangle {
@@ -201,6 +528,14 @@ impl Height for Triangle {
     }
 }
 
+impl ApproxEq for Triangle {
+    fn approx_eq(&self, other: &Triangle, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) &&
+            self.b.approx_eq(&other.b, tolerance) &&
+            self.c.approx_eq(&other.c, tolerance)
+    }
+}
+
 
 /// Struct representing a rectangle (and square) in euclidean space
 /// # Usage
@@ -295,6 +630,12 @@ impl Diagonal for Rectangle {
     }
 }
 
+impl ApproxEq for Rectangle {
+    fn approx_eq(&self, other: &Rectangle, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) && self.b.approx_eq(&other.b, tolerance)
+    }
+}
+
 
 /// Struct representing a parallelogram in euclidean space
 /// # Usage
@@ -376,6 +717,14 @@ impl Perimeter for Parallelogram {
     }
 }
 
+impl ApproxEq for Parallelogram {
+    fn approx_eq(&self, other: &Parallelogram, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) &&
+            self.b.approx_eq(&other.b, tolerance) &&
+            self.h.approx_eq(&other.h, tolerance)
+    }
+}
+
 
 /// Struct representing a trapeze in euclidean space
 /// # Usage
@@ -478,6 +827,15 @@ impl Height for Trapeze {
     }
 }
 
+impl ApproxEq for Trapeze {
+    fn approx_eq(&self, other: &Trapeze, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) &&
+            self.b.approx_eq(&other.b, tolerance) &&
+            self.c.approx_eq(&other.c, tolerance) &&
+            self.d.approx_eq(&other.d, tolerance)
+    }
+}
+
 
 /// Struct representing a polygon in euclidean space
 /// # Usage
@@ -573,129 +931,833 @@ impl Perimeter for Polygon {
     }
 }
 
+impl ApproxEq for Polygon {
+    fn approx_eq(&self, other: &Polygon, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) && self.n.approx_eq(&other.n, tolerance)
+    }
+}
 
-/// Struct representing a circle in euclidean space
+
+/// Struct representing a simple polygon given by its vertices
+/// # Remarks
+/// The vertices are stored in their order along the border. The last vertex is connected with
+/// the first one, so it must not be repeated at the end.
 /// # Usage
 /// ```
-/// pub use mathol::geometrics::planimetry::Circle;
+/// pub use mathol::geometrics::planimetry::SimplePolygon;
 /// ```
-#[derive(Debug, Copy, Clone)]
-pub struct Circle {
-    /// Radius r
-    pub r: f64,
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimplePolygon {
+    /// The vertices of the polygon
+    pub vertices: Vec<Vector2D<f64>>,
 }
 
-impl Circle {
-    /// Creates a circle instance with the given radius
+impl SimplePolygon {
+    /// Creates a polygon instance with the given vertices
     /// # Parameters
-    /// r: Radius r
+    /// vertices: The vertices in their order along the border
     /// # Return values
-    /// Returns the circle instance in case of success
+    /// Returns the polygon instance in case of success
     ///
-    /// Returns NegativeValueError if the radius is a negative value
+    /// Returns LengthError if less than three vertices are given
     /// # Examples
     /// ```
-    /// let circle = Circle::build_circle(2).expect("error");
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let polygon = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(0, 3)]).expect("error");
+    /// assert_eq!(3, polygon.vertices.len());
     /// ```
-    pub fn build_circle<T>(r: T) -> Result<Circle, MatholError>
-        where T: Num + Convert + PartialOrd
+    pub fn build_polygon<T>(vertices: Vec<Vector2D<T>>) -> Result<SimplePolygon, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
     {
-        if r <= T::zero() {
-            return Err(MatholError::NegativeValueCause(NegativeValueError {
-                message: "Circle radius must be positive".to_string(),
-            }))
+        if vertices.len() < 3 {
+            return Err(MatholError::LengthCause(LengthError {
+                message: "A polygon needs at least three vertices".to_string(),
+            }));
         }
 
-        Ok(Circle {
-            r: r.to_f64(),
+        Ok(SimplePolygon {
+            vertices: vertices.iter().map(|v| v.convert_to_f64()).collect(),
         })
     }
-}
 
-impl Area for Circle {
-    /// Calculates the area of a circle
-    /// # Remarks
-    /// Formula for a circle is A = PI * r²
-    /// # Return values
-    /// Returns the calculated area as f64 value
-    /// # Examples
-    /// ```
-    /// let circle = Circle::build_circle(2).expect("error");
-    /// assert_eq!(12.566370614359172, circle.get_area());
-    /// ```
-    fn get_area(self) -> f64 {
-        PI * self.r * self.r
+    /// Returns the edges of the polygon as pairs of start and end point
+    pub fn get_edges(&self) -> Vec<(Vector2D<f64>, Vector2D<f64>)> {
+        let n = self.vertices.len();
+        (0..n).map(|i| (self.vertices[i], self.vertices[(i + 1) % n])).collect()
     }
-}
 
-impl Perimeter for Circle {
-    /// Calculates the perimeter of a circle
+    /// Calculates the signed area of the polygon
     /// # Remarks
-    /// The formula for a circle's perimeter is P = 2 * PI * r
-    /// # Return values
-    /// Returns the perimeter as f64 value
+    /// The area is positive if the vertices are in counterclockwise order and negative if
+    /// they are in clockwise order
     /// # Examples
     /// ```
-    /// let circle = Circle::build_circle(2).expect("error");
-    /// assert_eq!(12.566370614359172, circle.get_perimeter());
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let polygon = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(0, 3), Vector2D::build_vector(4, 0)]).expect("error");
+    /// assert_eq!(-6.0, polygon.get_signed_area());
     /// ```
-    fn get_perimeter(self) -> f64 {
-        2.0 * PI * self.r
+    pub fn get_signed_area(&self) -> f64 {
+        0.5 * self.get_edges().iter().map(|&(p, q)| p.get_vector_product(q)).sum::<f64>()
     }
-}
 
+    /// Determines the order of the vertices
+    /// # Return values
+    /// Returns Orientation::Degenerate if the polygon has no area
+    pub fn get_orientation(&self) -> Orientation {
+        let area = self.get_signed_area();
+        if area > 0.0 {
+            Orientation::CounterClockwise
+        } else if area < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::Degenerate
+        }
+    }
 
-/// Struct representing an ellipsis in euclidean space
-/// # Usage
-/// ```
-/// pub use mathol::geometrics::planimetry::Ellipsis;
-/// ```
-#[derive(Debug, Copy, Clone)]
-pub struct Ellipsis {
-    /// Length a
-    pub a: f64,
-    /// Width b
-    pub b: f64,
-}
+    /// Returns the polygon with the vertices in reversed order
+    pub fn reverse(&self) -> SimplePolygon {
+        SimplePolygon {
+            vertices: self.vertices.iter().rev().cloned().collect(),
+        }
+    }
 
-impl Ellipsis {
-    /// Creates an ellipsis instance with the given length and width
-    /// # Parameters
-    /// a: Length a
-    ///
-    /// b: Width b
+    /// Calculates the centroid (centre of mass) of the area of the polygon
     /// # Return values
-    /// Returns the ellipsis instance in case of success
+    /// Returns the centroid in case of success
     ///
-    /// Returns NegativeValueError if the length or width is a negative value
+    /// Returns VectorError if the polygon has no area
     /// # Examples
     /// ```
-    /// let ellipsis = Ellipsis::build_ellipsis(2, 3).expect("error");
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let polygon = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(6, 0), Vector2D::build_vector(0, 3)]).expect("error");
+    /// assert_eq!(Ok(Vector2D::build_vector(2.0, 1.0)), polygon.get_centroid());
     /// ```
-    pub fn build_ellipsis<T>(a: T, b: T) -> Result<Ellipsis, MatholError>
-        where T: Num + Convert + PartialOrd
-    {
-        if a <= T::zero() || b <= T::zero() {
-            return Err(MatholError::NegativeValueCause(NegativeValueError {
-                message: "Ellipsis must have a positive length or width".to_string(),
-            }))
+    pub fn get_centroid(&self) -> Result<Vector2D<f64>, MatholError> {
+        let area = self.get_signed_area();
+        if area == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The polygon has no area".to_string(),
+            }));
         }
 
-        Ok(Ellipsis {
-            a: a.to_f64(),
-            b: b.to_f64(),
-        })
+        let origin = self.vertices[0];
+        let sum = self.get_edges().iter().fold(Vector2D::build_vector(0.0, 0.0), |sum, &(p, q)| {
+            let (p, q) = (p - origin, q - origin);
+            sum + (p + q) * p.get_vector_product(q)
+        });
+        Ok(origin + sum / (6.0 * area))
     }
-}
 
-impl Area for Ellipsis {
-    /// Calculates the area of an ellipsis
+    /// Checks if the polygon is convex
     /// # Remarks
-    /// Formula for an ellipsis is A = PI * a * b
-    /// # Return values
-    /// Returns the calculated area as f64 value
-    /// # Examples
-    /// ```
+    /// Collinear neighbouring edges are allowed. A self-intersecting polygon is never convex.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let mut sign = 0.0;
+        for i in 0..n {
+            let (p, q, r) = (self.vertices[i], self.vertices[(i + 1) % n], self.vertices[(i + 2) % n]);
+            let turn = (q - p).get_vector_product(r - q);
+            if turn != 0.0 {
+                if turn * sign < 0.0 {
+                    return false;
+                }
+                sign = turn;
+            }
+        }
+
+        sign != 0.0 && !self.is_self_intersecting()
+    }
+
+    /// Checks if two edges of the polygon cross or touch each other
+    /// # Remarks
+    /// Neighbouring edges may only share their common vertex. A polygon that touches itself in a
+    /// vertex counts as self-intersecting.
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let bowtie = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(2, 2),
+    ///     Vector2D::build_vector(2, 0), Vector2D::build_vector(0, 2)]).expect("error");
+    /// assert!(bowtie.is_self_intersecting());
+    /// ```
+    pub fn is_self_intersecting(&self) -> bool {
+        let edges: Vec<Segment2D<f64>> = self.get_edges().iter().map(|&(p, q)| Segment2D::build_segment(p, q)).collect();
+        let n = edges.len();
+        for i in 0..n {
+            for k in (i + 1)..n {
+                let neighbours = k == i + 1 || (i == 0 && k == n - 1);
+                match edges[i].intersection(edges[k]) {
+                    SegmentIntersection::Disjoint => {},
+                    SegmentIntersection::Point {..} if neighbours => {},
+                    _ => return true,
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Calculates how often the border of the polygon winds around a point
+    /// # Remarks
+    /// The winding number is positive for counterclockwise and negative for clockwise windings.
+    /// Points outside of the polygon have the winding number 0.
+    pub fn get_winding_number<T>(&self, p: Vector2D<T>) -> i32
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let p = p.convert_to_f64();
+        self.get_edges().iter().fold(0, |winding, &(a, b)| {
+            let side = (b - a).get_vector_product(p - a);
+            if a.y <= p.y && b.y > p.y && side > 0.0 {
+                winding + 1
+            } else if a.y > p.y && b.y <= p.y && side < 0.0 {
+                winding - 1
+            } else {
+                winding
+            }
+        })
+    }
+
+    /// Checks if a point lies inside of the polygon or on its border
+    /// # Remarks
+    /// Uses the winding number, so for self-intersecting polygons every area that is wound
+    /// around counts as inside. A point counts as lying on the border if its distance from an
+    /// edge is negligible compared to the length of the edge
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let polygon = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0),
+    ///     Vector2D::build_vector(4, 4), Vector2D::build_vector(2, 1), Vector2D::build_vector(0, 4)]).expect("error");
+    /// assert!(polygon.contains_point(Vector2D::build_vector(1, 1)));
+    /// assert!(!polygon.contains_point(Vector2D::build_vector(2, 3)));
+    /// assert!(polygon.contains_point(Vector2D::build_vector(4, 2)));
+    /// ```
+    pub fn contains_point<T>(&self, p: Vector2D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let p = p.convert_to_f64();
+        let on_border = self.get_edges().iter().any(|&(a, b)| {
+            let edge = Segment2D::build_segment(a, b);
+            edge.distance_from_point(p) <= EPSILON * edge.get_length()
+        });
+        on_border || self.get_winding_number(p) != 0
+    }
+}
+
+/// The order of the vertices of a polygon
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+    /// The polygon has no area
+    Degenerate,
+}
+
+impl Area for &SimplePolygon {
+    /// Calculates the area of a simple polygon
+    /// # Remarks
+    /// Uses the shoelace formula A = 0.5 * |Σ (x_i * y_(i+1) - x_(i+1) * y_i)|
+    /// # Return values
+    /// Returns the calculated area as f64 value
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::geometrics::traits::Area;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let polygon = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(0, 3)]).expect("error");
+    /// assert_eq!(6.0, polygon.get_area());
+    /// ```
+    fn get_area(self) -> f64 {
+        0.5 * self.get_edges().iter().map(|&(p, q)| p.get_vector_product(q)).sum::<f64>().abs()
+    }
+}
+
+impl Perimeter for &SimplePolygon {
+    /// Calculates the perimeter of a simple polygon
+    /// # Remarks
+    /// The perimeter is the sum of the lengths of all edges
+    /// # Return values
+    /// Returns the perimeter as f64 value
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::SimplePolygon;
+    /// use mathol::geometrics::traits::Perimeter;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let polygon = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(0, 3)]).expect("error");
+    /// assert_eq!(12.0, polygon.get_perimeter());
+    /// ```
+    fn get_perimeter(self) -> f64 {
+        self.get_edges().iter().map(|&(p, q)| (q - p).get_length()).sum()
+    }
+}
+
+impl ApproxEq for SimplePolygon {
+    fn approx_eq(&self, other: &SimplePolygon, tolerance: Tolerance) -> bool {
+        self.vertices.len() == other.vertices.len()
+            && self.vertices.iter().zip(other.vertices.iter()).all(|(v, w)| v.approx_eq(w, tolerance))
+    }
+}
+
+
+/// Struct representing a circle in euclidean space
+/// # Usage
+/// ```
+/// pub use mathol::geometrics::planimetry::Circle;
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Circle {
+    /// Radius r
+    pub r: f64,
+}
+
+impl Circle {
+    /// Creates a circle instance with the given radius
+    /// # Parameters
+    /// r: Radius r
+    /// # Return values
+    /// Returns the circle instance in case of success
+    ///
+    /// Returns NegativeValueError if the radius is a negative value
+    /// # Examples
+    /// ```
+    /// let circle = Circle::build_circle(2).expect("error");
+    /// ```
+    pub fn build_circle<T>(r: T) -> Result<Circle, MatholError>
+        where T: Num + Convert + PartialOrd
+    {
+        if r <= T::zero() {
+            return Err(MatholError::NegativeValueCause(NegativeValueError {
+                message: "Circle radius must be positive".to_string(),
+            }))
+        }
+
+        Ok(Circle {
+            r: r.to_f64(),
+        })
+    }
+}
+
+impl Area for Circle {
+    /// Calculates the area of a circle
+    /// # Remarks
+    /// Formula for a circle is A = PI * r²
+    /// # Return values
+    /// Returns the calculated area as f64 value
+    /// # Examples
+    /// ```
+    /// let circle = Circle::build_circle(2).expect("error");
+    /// assert_eq!(12.566370614359172, circle.get_area());
+    /// ```
+    fn get_area(self) -> f64 {
+        PI * self.r * self.r
+    }
+}
+
+impl Perimeter for Circle {
+    /// Calculates the perimeter of a circle
+    /// # Remarks
+    /// The formula for a circle's perimeter is P = 2 * PI * r
+    /// # Return values
+    /// Returns the perimeter as f64 value
+    /// # Examples
+    /// ```
+    /// let circle = Circle::build_circle(2).expect("error");
+    /// assert_eq!(12.566370614359172, circle.get_perimeter());
+    /// ```
+    fn get_perimeter(self) -> f64 {
+        2.0 * PI * self.r
+    }
+}
+
+impl ApproxEq for Circle {
+    fn approx_eq(&self, other: &Circle, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance)
+    }
+}
+
+
+/// Struct representing a circle with a centre in euclidean space
+/// # Usage
+/// ```
+/// pub use mathol::geometrics::planimetry::Circle2D;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Circle2D {
+    /// Centre of the circle
+    pub centre: Vector2D<f64>,
+    /// Radius r
+    pub r: f64,
+}
+
+impl Circle2D {
+    /// Creates a circle instance with the given centre and radius
+    /// # Parameters
+    /// centre: Centre of the circle
+    ///
+    /// r: Radius r
+    /// # Return values
+    /// Returns the circle instance in case of success
+    ///
+    /// Returns NegativeValueError if the radius is a negative value
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Circle2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let circle = Circle2D::build_circle(Vector2D::build_vector(1, 2), 3).expect("error");
+    /// ```
+    pub fn build_circle<T>(centre: Vector2D<T>, r: T) -> Result<Circle2D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive + PartialOrd
+    {
+        let circle = Circle::build_circle(r)?;
+        Ok(Circle2D {
+            centre: centre.convert_to_f64(),
+            r: circle.r,
+        })
+    }
+
+    /// Creates the circle that goes through three points
+    /// # Return values
+    /// Returns the circle instance in case of success
+    ///
+    /// Returns VectorError if the points lie on a line
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Circle2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let circle = Circle2D::build_circle_through_points(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(0, 3)).expect("error");
+    /// assert_eq!(Vector2D::build_vector(2.0, 1.5), circle.centre);
+    /// assert_eq!(2.5, circle.r);
+    /// ```
+    pub fn build_circle_through_points<T>(p: Vector2D<T>, q: Vector2D<T>, s: Vector2D<T>) -> Result<Circle2D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let (p, q, s) = (p.convert_to_f64(), q.convert_to_f64(), s.convert_to_f64());
+        let (u, v) = (q - p, s - p);
+        if u.get_vector_product(v).abs() <= EPSILON * u.get_length() * v.get_length() {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The points lie on a line".to_string(),
+            }));
+        }
+
+        let centre = get_circumcenter(p, q, s);
+        Ok(Circle2D {centre, r: centre.get_distance(p)})
+    }
+
+    /// Returns the circle without its centre
+    pub fn get_circle(self) -> Circle {
+        Circle {r: self.r}
+    }
+
+    /// Calculates the intersection points of two circles
+    /// # Return values
+    /// Returns no, one or two intersection points in case of success.
+    /// If the circles touch each other, the touching point is returned once.
+    ///
+    /// Returns VectorError if the circles are identical
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Circle2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let c1 = Circle2D::build_circle(Vector2D::build_vector(0, 0), 5).expect("error");
+    /// let c2 = Circle2D::build_circle(Vector2D::build_vector(8, 0), 5).expect("error");
+    /// let points = c1.intersection_with_circle(c2).expect("error");
+    /// assert_eq!(vec![Vector2D::build_vector(4.0, 3.0), Vector2D::build_vector(4.0, -3.0)], points);
+    /// ```
+    pub fn intersection_with_circle(self, other: Circle2D) -> Result<Vec<Vector2D<f64>>, MatholError> {
+        let d = other.centre - self.centre;
+        let distance = d.get_length();
+        let tolerance = EPSILON * (self.r + other.r);
+        if distance <= tolerance {
+            if (self.r - other.r).abs() <= tolerance {
+                return Err(MatholError::VectorCause(VectorError {
+                    message: "The circles are identical".to_string(),
+                }));
+            }
+            return Ok(Vec::new());
+        }
+        if distance > self.r + other.r + tolerance || distance < (self.r - other.r).abs() - tolerance {
+            return Ok(Vec::new());
+        }
+
+        let along = (self.r * self.r - other.r * other.r + distance * distance) / (2.0 * distance);
+        let direction = d / distance;
+        Ok(get_chord_points(self.centre + direction * along, direction, self.r * self.r - along * along, self.r))
+    }
+
+    /// Calculates the intersection points of a circle and a line
+    /// # Return values
+    /// Returns no, one or two intersection points in the order of the direction vector of the line.
+    /// If the line touches the circle, the touching point is returned once.
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Circle2D;
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let circle = Circle2D::build_circle(Vector2D::build_vector(0, 0), 5).expect("error");
+    /// let line = Line2D::build_line_from_slope_intercept(0, 3);
+    /// assert_eq!(vec![Vector2D::build_vector(-4.0, 3.0), Vector2D::build_vector(4.0, 3.0)], circle.intersection_with_line(line));
+    /// ```
+    pub fn intersection_with_line<T>(self, line: Line2D<T>) -> Vec<Vector2D<f64>>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let r = line.r.convert_to_f64();
+        let direction = line.a.convert_to_f64() / line.a.get_length();
+        let foot = r + direction * direction.get_scalar_product(self.centre - r);
+        let distance = foot.get_distance(self.centre);
+        let normal = Vector2D::build_vector(-direction.y, direction.x);
+        get_chord_points(foot, normal, self.r * self.r - distance * distance, self.r)
+    }
+
+    /// Calculates the tangent lines from a point to the circle
+    /// # Remarks
+    /// Every tangent line has its touching point on the circle as support vector
+    /// # Return values
+    /// Returns two tangents for points outside of the circle, one tangent for points on the circle
+    /// and no tangent for points inside of the circle
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Circle2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let circle = Circle2D::build_circle(Vector2D::build_vector(0, 0), 3).expect("error");
+    /// let tangents = circle.get_tangents_from_point(Vector2D::build_vector(5, 0));
+    /// assert_eq!(Vector2D::build_vector(1.8, 2.4), tangents[0].r);
+    /// assert_eq!(Vector2D::build_vector(1.8, -2.4), tangents[1].r);
+    /// ```
+    pub fn get_tangents_from_point<T>(self, p: Vector2D<T>) -> Vec<Line2D<f64>>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let p = p.convert_to_f64();
+        let d = p - self.centre;
+        let distance = d.get_length();
+        if distance < self.r * (1.0 - EPSILON) {
+            return Vec::new();
+        }
+        if distance <= self.r * (1.0 + EPSILON) {
+            return vec![Line2D {r: p, a: Vector2D::build_vector(-d.y, d.x)}];
+        }
+
+        let direction = d / distance;
+        let along = self.r * self.r / distance;
+        get_chord_points(self.centre + direction * along, direction, self.r * self.r - along * along, self.r)
+            .into_iter()
+            .map(|touch| Line2D {r: touch, a: p - touch})
+            .collect()
+    }
+
+    /// Calculates the common tangent lines of two circles
+    /// # Remarks
+    /// Every tangent line has its touching point on the first circle as support vector.
+    /// The outer tangents, which do not pass between the circles, come first.
+    /// # Return values
+    /// Returns up to four tangents in case of success
+    ///
+    /// Returns VectorError if the circles are identical
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Circle2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let c1 = Circle2D::build_circle(Vector2D::build_vector(0, 0), 1).expect("error");
+    /// let c2 = Circle2D::build_circle(Vector2D::build_vector(5, 0), 1).expect("error");
+    /// assert_eq!(4, c1.get_common_tangents(c2).expect("error").len());
+    /// ```
+    pub fn get_common_tangents(self, other: Circle2D) -> Result<Vec<Line2D<f64>>, MatholError> {
+        let d = other.centre - self.centre;
+        let distance = d.get_length();
+        let tolerance = EPSILON * (self.r + other.r);
+        if distance <= tolerance {
+            if (self.r - other.r).abs() <= tolerance {
+                return Err(MatholError::VectorCause(VectorError {
+                    message: "The circles are identical".to_string(),
+                }));
+            }
+            return Ok(Vec::new());
+        }
+
+        let direction = d / distance;
+        let normal = Vector2D::build_vector(-direction.y, direction.x);
+        let mut tangents = Vec::new();
+        for &sign in [1.0, -1.0].iter() {
+            // The unit normal n of a tangent satisfies n·(c2 - c1) = sign * r2 - r1
+            let cosine = (sign * other.r - self.r) / distance;
+            if cosine.abs() > 1.0 + EPSILON {
+                continue;
+            }
+            let sine = (1.0 - cosine * cosine).max(0.0).sqrt();
+            let sines = if sine <= EPSILON { vec![0.0] } else { vec![sine, -sine] };
+            for sine in sines {
+                let n = direction * cosine + normal * sine;
+                tangents.push(Line2D {r: self.centre - n * self.r, a: Vector2D::build_vector(-n.y, n.x)});
+            }
+        }
+
+        Ok(tangents)
+    }
+}
+
+/// Returns the points at the distance sqrt(squared) from the foot perpendicular to the direction
+/// # Remarks
+/// Values of squared that are negative by a rounding error of the radius r count as zero
+fn get_chord_points(foot: Vector2D<f64>, direction: Vector2D<f64>, squared: f64, r: f64) -> Vec<Vector2D<f64>> {
+    if squared < -EPSILON * r * r {
+        return Vec::new();
+    }
+    if squared <= EPSILON * r * r {
+        return vec![foot];
+    }
+
+    let offset = Vector2D::build_vector(-direction.y, direction.x) * squared.sqrt();
+    vec![foot + offset, foot - offset]
+}
+
+impl Area for Circle2D {
+    /// Calculates the area of a circle
+    /// # Remarks
+    /// Formula for a circle is A = PI * r²
+    fn get_area(self) -> f64 {
+        self.get_circle().get_area()
+    }
+}
+
+impl Perimeter for Circle2D {
+    /// Calculates the perimeter of a circle
+    /// # Remarks
+    /// The formula for a circle's perimeter is P = 2 * PI * r
+    fn get_perimeter(self) -> f64 {
+        self.get_circle().get_perimeter()
+    }
+}
+
+impl ApproxEq for Circle2D {
+    fn approx_eq(&self, other: &Circle2D, tolerance: Tolerance) -> bool {
+        self.centre.approx_eq(&other.centre, tolerance) && self.r.approx_eq(&other.r, tolerance)
+    }
+}
+
+
+/// Struct representing an ellipsis in euclidean space
+/// # Usage
+/// ```
+/// pub use mathol::geometrics::planimetry::Ellipsis;
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Ellipsis {
+    /// Length a
+    pub a: f64,
+    /// Width b
+    pub b: f64,
+}
+
+impl Ellipsis {
+    /// Creates an ellipsis instance with the given length and width
+    /// # Parameters
+    /// a: Length a
+    ///
+    /// b: Width b
+    /// # Return values
+    /// Returns the ellipsis instance in case of success
+    ///
+    /// Returns NegativeValueError if the length or width is a negative value
+    /// # Examples
+    /// ```
+    /// let ellipsis = Ellipsis::build_ellipsis(2, 3).expect("error");
+    /// ```
+    pub fn build_ellipsis<T>(a: T, b: T) -> Result<Ellipsis, MatholError>
+        where T: Num + Convert + PartialOrd
+    {
+        if a <= T::zero() || b <= T::zero() {
+            return Err(MatholError::NegativeValueCause(NegativeValueError {
+                message: "Ellipsis must have a positive length or width".to_string(),
+            }))
+        }
+
+        Ok(Ellipsis {
+            a: a.to_f64(),
+            b: b.to_f64(),
+        })
+    }
+
+    /// Returns the semi-major and the semi-minor axis
+    fn get_semi_axes(&self) -> (f64, f64) {
+        (self.a.max(self.b), self.a.min(self.b))
+    }
+
+    /// Calculates the linear eccentricity of an ellipsis
+    /// # Remarks
+    /// The linear eccentricity is the distance between the centre and a focus, c = sqrt(|a² - b²|)
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Ellipsis;
+    ///
+    /// let ellipsis = Ellipsis::build_ellipsis(5, 3).expect("error");
+    /// assert_eq!(4.0, ellipsis.get_linear_eccentricity());
+    /// ```
+    pub fn get_linear_eccentricity(&self) -> f64 {
+        (self.a * self.a - self.b * self.b).abs().sqrt()
+    }
+
+    /// Calculates the numerical eccentricity of an ellipsis
+    /// # Remarks
+    /// The eccentricity is the linear eccentricity divided by the semi-major axis. It is 0 for a
+    /// circle and approaches 1 for a very flat ellipsis.
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Ellipsis;
+    ///
+    /// let ellipsis = Ellipsis::build_ellipsis(5, 3).expect("error");
+    /// assert_eq!(0.8, ellipsis.get_eccentricity());
+    /// ```
+    pub fn get_eccentricity(&self) -> f64 {
+        self.get_linear_eccentricity() / self.get_semi_axes().0
+    }
+
+    /// Calculates the two foci of an ellipsis
+    /// # Remarks
+    /// The ellipsis is centred at the origin with a along the x axis and b along the y axis. The
+    /// foci lie on the major axis, the one with the negative coordinate comes first.
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Ellipsis;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let ellipsis = Ellipsis::build_ellipsis(3, 5).expect("error");
+    /// assert_eq!([Vector2D::build_vector(0.0, -4.0), Vector2D::build_vector(0.0, 4.0)], ellipsis.get_foci());
+    /// ```
+    pub fn get_foci(&self) -> [Vector2D<f64>; 2] {
+        let c = self.get_linear_eccentricity();
+        if self.a >= self.b {
+            [Vector2D::build_vector(-c, 0.0), Vector2D::build_vector(c, 0.0)]
+        } else {
+            [Vector2D::build_vector(0.0, -c), Vector2D::build_vector(0.0, c)]
+        }
+    }
+
+    /// Calculates the length of the latus rectum of an ellipsis
+    /// # Remarks
+    /// The latus rectum is the chord through a focus perpendicular to the major axis, its length
+    /// is 2 * minor² / major
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Ellipsis;
+    ///
+    /// let ellipsis = Ellipsis::build_ellipsis(5, 3).expect("error");
+    /// assert_eq!(3.6, ellipsis.get_latus_rectum());
+    /// ```
+    pub fn get_latus_rectum(&self) -> f64 {
+        let (major, minor) = self.get_semi_axes();
+        2.0 * minor * minor / major
+    }
+
+    /// Checks if a point lies on the border of an ellipsis centred at the origin
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Ellipsis;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let ellipsis = Ellipsis::build_ellipsis(5, 3).expect("error");
+    /// assert!(ellipsis.is_point_on_ellipsis(Vector2D::build_vector(4.0, 1.8)));
+    /// assert!(!ellipsis.is_point_on_ellipsis(Vector2D::build_vector(4.0, 1.0)));
+    /// ```
+    pub fn is_point_on_ellipsis<T>(&self, p: Vector2D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        (self.get_implicit_value(p.convert_to_f64()) - 1.0).abs() <= EPSILON
+    }
+
+    /// Checks if a point lies inside of an ellipsis centred at the origin
+    /// # Remarks
+    /// Points on the border count as inside
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Ellipsis;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let ellipsis = Ellipsis::build_ellipsis(5, 3).expect("error");
+    /// assert!(ellipsis.contains_point(Vector2D::build_vector(4.0, 1.0)));
+    /// assert!(!ellipsis.contains_point(Vector2D::build_vector(4.0, 2.0)));
+    /// ```
+    pub fn contains_point<T>(&self, p: Vector2D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        self.get_implicit_value(p.convert_to_f64()) <= 1.0 + EPSILON
+    }
+
+    /// Returns (x/a)² + (y/b)², which is 1 on the border of the ellipsis
+    fn get_implicit_value(&self, p: Vector2D<f64>) -> f64 {
+        (p.x / self.a).powi(2) + (p.y / self.b).powi(2)
+    }
+
+    /// Calculates the exact perimeter of an ellipsis
+    /// # Remarks
+    /// The perimeter is P = 4 * major * E(e) with the complete elliptic integral of the second kind
+    /// E and the eccentricity e. The estimation of get_perimeter stays available.
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Ellipsis;
+    ///
+    /// let ellipsis = Ellipsis::build_ellipsis(2, 3).expect("error");
+    /// assert!((ellipsis.get_exact_perimeter() - 15.865439589290595).abs() < 1e-12);
+    /// ```
+    pub fn get_exact_perimeter(&self) -> f64 {
+        let (major, _) = self.get_semi_axes();
+        4.0 * major * get_complete_elliptic_integral_second_kind(self.get_eccentricity()).unwrap()
+    }
+
+    /// Calculates the length of the arc between two parametric angles
+    /// # Parameters
+    /// t1: Start angle in radians of the point (a * cos(t), b * sin(t))
+    ///
+    /// t2: End angle in radians
+    /// # Remarks
+    /// The arc runs counterclockwise from t1 to t2, the length is negative if t2 is less than t1.
+    /// Angles that differ by more than 2π wind around the ellipsis several times.
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Ellipsis;
+    /// use std::f64::consts::PI;
+    ///
+    /// let ellipsis = Ellipsis::build_ellipsis(2, 3).expect("error");
+    /// let quarter = ellipsis.get_arc_length(0.0, PI / 2.0);
+    /// assert!((4.0 * quarter - ellipsis.get_exact_perimeter()).abs() < 1e-12);
+    /// ```
+    pub fn get_arc_length(&self, t1: f64, t2: f64) -> f64 {
+        let (major, _) = self.get_semi_axes();
+        let e = self.get_eccentricity();
+        // With a as the major axis the integrand is a * sqrt(1 - e² cos²(t)), so the angle is shifted
+        let shift = if self.a >= self.b { PI / 2.0 } else { 0.0 };
+        let integral = |t: f64| get_elliptic_integral_second_kind(t - shift, e).unwrap();
+        major * (integral(t2) - integral(t1))
+    }
+
+    /// Converts the ellipsis to the general conic section x²/a² + y²/b² - 1 = 0
+    pub fn to_conic(&self) -> Conic {
+        Conic {a: 1.0 / (self.a * self.a), b: 0.0, c: 1.0 / (self.b * self.b), d: 0.0, e: 0.0, f: -1.0}
+    }
+}
+
+impl Area for Ellipsis {
+    /// Calculates the area of an ellipsis
+    /// # Remarks
+    /// Formula for an ellipsis is A = PI * a * b
+    /// # Return values
+    /// Returns the calculated area as f64 value
+    /// # Examples
+    /// ```
     /// let ellipsis = Ellipsis::build_ellipsis(2, 3).expect("error");
     /// assert_eq!(18.84955592153876, ellipsis.get_area());
     /// ```
@@ -718,4 +1780,275 @@ impl Perimeter for Ellipsis {
     fn get_perimeter(self) -> f64 {
         PI * (1.5 * (self.a + self.b) - (self.a * self.b).sqrt())
     }
-}
\ No newline at end of file
+}
+
+impl ApproxEq for Ellipsis {
+    fn approx_eq(&self, other: &Ellipsis, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) && self.b.approx_eq(&other.b, tolerance)
+    }
+}
+
+
+/// Struct representing the parabola y² = 2px with its vertex at the origin
+/// # Remarks
+/// The parabola opens towards the positive x axis
+/// # Usage
+/// ```
+/// pub use mathol::geometrics::planimetry::Parabola;
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Parabola {
+    /// Parameter p, the distance between focus and directrix
+    pub p: f64,
+}
+
+impl Parabola {
+    /// Creates a parabola instance with the given parameter
+    /// # Return values
+    /// Returns the parabola instance in case of success
+    ///
+    /// Returns NegativeValueError if the parameter is not positive
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Parabola;
+    ///
+    /// let parabola = Parabola::build_parabola(2).expect("error");
+    /// assert_eq!(2.0, parabola.p);
+    /// ```
+    pub fn build_parabola<T>(p: T) -> Result<Parabola, MatholError>
+        where T: Num + Convert + PartialOrd
+    {
+        if p <= T::zero() {
+            return Err(MatholError::NegativeValueCause(NegativeValueError {
+                message: "Parabola must have a positive parameter".to_string(),
+            }))
+        }
+
+        Ok(Parabola {p: p.to_f64()})
+    }
+
+    /// Returns the focus (p/2 | 0) of the parabola
+    pub fn get_focus(&self) -> Vector2D<f64> {
+        Vector2D::build_vector(self.p / 2.0, 0.0)
+    }
+
+    /// Returns the directrix x = -p/2 of the parabola
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Parabola;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let parabola = Parabola::build_parabola(2).expect("error");
+    /// let p = Vector2D::build_vector(4.0, 4.0);
+    /// assert_eq!(p.get_distance(parabola.get_focus()), parabola.get_directrix().distance_from_point(p));
+    /// ```
+    pub fn get_directrix(&self) -> Line2D<f64> {
+        Line2D {r: Vector2D::build_vector(-self.p / 2.0, 0.0), a: Vector2D::build_vector(0.0, 1.0)}
+    }
+
+    /// Returns the eccentricity of the parabola, which is always 1
+    pub fn get_eccentricity(&self) -> f64 {
+        1.0
+    }
+
+    /// Calculates the length of the latus rectum 2p, the chord through the focus perpendicular to the axis
+    pub fn get_latus_rectum(&self) -> f64 {
+        2.0 * self.p
+    }
+
+    /// Checks if a point lies on the parabola
+    pub fn is_point_on_parabola<T>(&self, p: Vector2D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        self.to_conic().is_point_on_conic(p)
+    }
+
+    /// Calculates the tangent line in a point of the parabola
+    /// # Remarks
+    /// The tangent line has the point as support vector
+    /// # Return values
+    /// Returns the tangent line in case of success
+    ///
+    /// Returns VectorError if the point does not lie on the parabola
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Parabola;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let parabola = Parabola::build_parabola(2).expect("error");
+    /// let tangent = parabola.get_tangent(Vector2D::build_vector(1, 2)).expect("error");
+    /// assert_eq!((1.0, 1.0), tangent.get_slope_intercept().expect("error"));
+    /// ```
+    pub fn get_tangent<T>(&self, p: Vector2D<T>) -> Result<Line2D<f64>, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        self.to_conic().get_tangent(p)
+    }
+
+    /// Calculates the tangent lines from a point to the parabola
+    /// # Remarks
+    /// Every tangent line has its touching point on the parabola as support vector
+    /// # Return values
+    /// Returns two tangents for points outside of the parabola, one tangent for points on the
+    /// parabola and no tangent for points inside of the parabola
+    pub fn get_tangents_from_point<T>(&self, p: Vector2D<T>) -> Vec<Line2D<f64>>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        self.to_conic().get_tangents_from_point(p)
+    }
+
+    /// Converts the parabola to the general conic section y² - 2px = 0
+    pub fn to_conic(&self) -> Conic {
+        Conic {a: 0.0, b: 0.0, c: 1.0, d: -2.0 * self.p, e: 0.0, f: 0.0}
+    }
+}
+
+impl ApproxEq for Parabola {
+    fn approx_eq(&self, other: &Parabola, tolerance: Tolerance) -> bool {
+        self.p.approx_eq(&other.p, tolerance)
+    }
+}
+
+
+/// Struct representing the hyperbola x²/a² - y²/b² = 1 with its centre at the origin
+/// # Remarks
+/// The real axis is the x axis, so the branches open towards the negative and positive x axis
+/// # Usage
+/// ```
+/// pub use mathol::geometrics::planimetry::Hyperbola;
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Hyperbola {
+    /// Real semi-axis a
+    pub a: f64,
+    /// Imaginary semi-axis b
+    pub b: f64,
+}
+
+impl Hyperbola {
+    /// Creates a hyperbola instance with the given semi-axes
+    /// # Return values
+    /// Returns the hyperbola instance in case of success
+    ///
+    /// Returns NegativeValueError if a semi-axis is not positive
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Hyperbola;
+    ///
+    /// let hyperbola = Hyperbola::build_hyperbola(3, 4).expect("error");
+    /// assert_eq!(5.0, hyperbola.get_linear_eccentricity());
+    /// ```
+    pub fn build_hyperbola<T>(a: T, b: T) -> Result<Hyperbola, MatholError>
+        where T: Num + Convert + PartialOrd
+    {
+        if a <= T::zero() || b <= T::zero() {
+            return Err(MatholError::NegativeValueCause(NegativeValueError {
+                message: "Hyperbola must have positive semi-axes".to_string(),
+            }))
+        }
+
+        Ok(Hyperbola {
+            a: a.to_f64(),
+            b: b.to_f64(),
+        })
+    }
+
+    /// Calculates the linear eccentricity c = sqrt(a² + b²), the distance between centre and focus
+    pub fn get_linear_eccentricity(&self) -> f64 {
+        pythagoras2d(self.a, self.b)
+    }
+
+    /// Calculates the numerical eccentricity c / a, which is always larger than 1
+    pub fn get_eccentricity(&self) -> f64 {
+        self.get_linear_eccentricity() / self.a
+    }
+
+    /// Returns the foci (-c | 0) and (c | 0)
+    pub fn get_foci(&self) -> [Vector2D<f64>; 2] {
+        let c = self.get_linear_eccentricity();
+        [Vector2D::build_vector(-c, 0.0), Vector2D::build_vector(c, 0.0)]
+    }
+
+    /// Returns the directrices x = -a²/c and x = a²/c
+    /// # Remarks
+    /// For every point on the hyperbola, the distance to a focus divided by the distance to the
+    /// directrix on the same side is the eccentricity
+    pub fn get_directrices(&self) -> [Line2D<f64>; 2] {
+        let x = self.a * self.a / self.get_linear_eccentricity();
+        let up = Vector2D::build_vector(0.0, 1.0);
+        [Line2D {r: Vector2D::build_vector(-x, 0.0), a: up}, Line2D {r: Vector2D::build_vector(x, 0.0), a: up}]
+    }
+
+    /// Returns the asymptotes y = b/a * x and y = -b/a * x
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Hyperbola;
+    ///
+    /// let hyperbola = Hyperbola::build_hyperbola(2, 1).expect("error");
+    /// let asymptotes = hyperbola.get_asymptotes();
+    /// assert_eq!((0.5, 0.0), asymptotes[0].get_slope_intercept().expect("error"));
+    /// assert_eq!((-0.5, 0.0), asymptotes[1].get_slope_intercept().expect("error"));
+    /// ```
+    pub fn get_asymptotes(&self) -> [Line2D<f64>; 2] {
+        let origin = Vector2D::build_vector(0.0, 0.0);
+        [Line2D {r: origin, a: Vector2D::build_vector(self.a, self.b)}, Line2D {r: origin, a: Vector2D::build_vector(self.a, -self.b)}]
+    }
+
+    /// Calculates the length of the latus rectum 2b²/a, the chord through a focus perpendicular to the real axis
+    pub fn get_latus_rectum(&self) -> f64 {
+        2.0 * self.b * self.b / self.a
+    }
+
+    /// Checks if a point lies on the hyperbola
+    pub fn is_point_on_hyperbola<T>(&self, p: Vector2D<T>) -> bool
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        self.to_conic().is_point_on_conic(p)
+    }
+
+    /// Calculates the tangent line in a point of the hyperbola
+    /// # Remarks
+    /// The tangent line has the point as support vector
+    /// # Return values
+    /// Returns the tangent line in case of success
+    ///
+    /// Returns VectorError if the point does not lie on the hyperbola
+    pub fn get_tangent<T>(&self, p: Vector2D<T>) -> Result<Line2D<f64>, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        self.to_conic().get_tangent(p)
+    }
+
+    /// Calculates the tangent lines from a point to the hyperbola
+    /// # Remarks
+    /// Every tangent line has its touching point on the hyperbola as support vector. A tangent
+    /// that touches the hyperbola only at infinity, like an asymptote, is not returned.
+    /// # Return values
+    /// Returns up to two tangents, one tangent for points on the hyperbola
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::planimetry::Hyperbola;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let hyperbola = Hyperbola::build_hyperbola(1, 1).expect("error");
+    /// let tangents = hyperbola.get_tangents_from_point(Vector2D::build_vector(0.5, 0.0));
+    /// assert_eq!(2, tangents.len());
+    /// assert!(tangents.iter().all(|t| hyperbola.is_point_on_hyperbola(t.r)));
+    /// ```
+    pub fn get_tangents_from_point<T>(&self, p: Vector2D<T>) -> Vec<Line2D<f64>>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        self.to_conic().get_tangents_from_point(p)
+    }
+
+    /// Converts the hyperbola to the general conic section x²/a² - y²/b² - 1 = 0
+    pub fn to_conic(&self) -> Conic {
+        Conic {a: 1.0 / (self.a * self.a), b: 0.0, c: -1.0 / (self.b * self.b), d: 0.0, e: 0.0, f: -1.0}
+    }
+}
+
+impl ApproxEq for Hyperbola {
+    fn approx_eq(&self, other: &Hyperbola, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) && self.b.approx_eq(&other.b, tolerance)
+    }
+}
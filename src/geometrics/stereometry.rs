@@ -1,6 +1,7 @@
 use std::f64::consts::PI;
 use basics::{pow, pythagoras3d};
 use basics::convert_trait::Convert;
+use basics::approx_trait::{ApproxEq, Tolerance};
 use num::Num;
 use geometrics::traits::*;
 use std::cmp::PartialOrd;
@@ -107,6 +108,14 @@ impl Diagonal for Cuboid {
     }
 }
 
+impl ApproxEq for Cuboid {
+    fn approx_eq(&self, other: &Cuboid, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) &&
+            self.b.approx_eq(&other.b, tolerance) &&
+            self.c.approx_eq(&other.c, tolerance)
+    }
+}
+
 
 /// Struct representing a pyramid in euclidean space
 /// # Usage
@@ -167,6 +176,12 @@ impl Volume for Pyramid {
     }
 }
 
+impl ApproxEq for Pyramid {
+    fn approx_eq(&self, other: &Pyramid, tolerance: Tolerance) -> bool {
+        self.area.approx_eq(&other.area, tolerance) && self.h.approx_eq(&other.h, tolerance)
+    }
+}
+
 
 /// Struct representing a wedge in euclidean space
 /// # Usage
@@ -238,6 +253,15 @@ impl Volume for Wedge {
     }
 }
 
+impl ApproxEq for Wedge {
+    fn approx_eq(&self, other: &Wedge, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) &&
+            self.b.approx_eq(&other.b, tolerance) &&
+            self.c.approx_eq(&other.c, tolerance) &&
+            self.h.approx_eq(&other.h, tolerance)
+    }
+}
+
 
 /// Struct representing a cylinder in euclidean space
 /// # Usage
@@ -330,6 +354,12 @@ impl Lateral for Cylinder {
     }
 }
 
+impl ApproxEq for Cylinder {
+    fn approx_eq(&self, other: &Cylinder, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance) && self.h.approx_eq(&other.h, tolerance)
+    }
+}
+
 
 /// Struct representing a cone in euclidean space
 /// # Usage
@@ -424,6 +454,12 @@ impl Lateral for Cone {
     }
 }
 
+impl ApproxEq for Cone {
+    fn approx_eq(&self, other: &Cone, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance) && self.h.approx_eq(&other.h, tolerance)
+    }
+}
+
 
 /// Struct representing a sphere in euclidean space
 /// # Usage
@@ -495,6 +531,12 @@ impl Surface for Sphere {
     }
 }
 
+impl ApproxEq for Sphere {
+    fn approx_eq(&self, other: &Sphere, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance)
+    }
+}
+
 
 /// Struct representing an ellipsoid in euclidean space
 /// # Usage
@@ -560,6 +602,14 @@ impl Volume for Ellipsoid {
     }
 }
 
+impl ApproxEq for Ellipsoid {
+    fn approx_eq(&self, other: &Ellipsoid, tolerance: Tolerance) -> bool {
+        self.a.approx_eq(&other.a, tolerance) &&
+            self.b.approx_eq(&other.b, tolerance) &&
+            self.c.approx_eq(&other.c, tolerance)
+    }
+}
+
 
 /// Struct representing a barrel with spheric curvature
 /// # Usage
@@ -627,6 +677,14 @@ impl Volume for SphericBarrel {
     }
 }
 
+impl ApproxEq for SphericBarrel {
+    fn approx_eq(&self, other: &SphericBarrel, tolerance: Tolerance) -> bool {
+        self.R.approx_eq(&other.R, tolerance) &&
+            self.r.approx_eq(&other.r, tolerance) &&
+            self.h.approx_eq(&other.h, tolerance)
+    }
+}
+
 
 /// Struct representing a barrel with parabolic curvature
 /// # Usage
@@ -694,6 +752,14 @@ impl Volume for ParabolicBarrel {
     }
 }
 
+impl ApproxEq for ParabolicBarrel {
+    fn approx_eq(&self, other: &ParabolicBarrel, tolerance: Tolerance) -> bool {
+        self.R.approx_eq(&other.R, tolerance) &&
+            self.r.approx_eq(&other.r, tolerance) &&
+            self.h.approx_eq(&other.h, tolerance)
+    }
+}
+
 
 /// Struct representing a torus in euclidean space
 /// # Usage
@@ -777,4 +843,10 @@ impl Surface for Torus {
     fn get_surface(self) -> f64 {
         4.0 * pow(PI, 2) * self.r / 2.0 * self.R
     }
-}
\ No newline at end of file
+}
+
+impl ApproxEq for Torus {
+    fn approx_eq(&self, other: &Torus, tolerance: Tolerance) -> bool {
+        self.R.approx_eq(&other.R, tolerance) && self.r.approx_eq(&other.r, tolerance)
+    }
+}
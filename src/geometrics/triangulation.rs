@@ -0,0 +1,317 @@
+use num::{Num, FromPrimitive};
+use std::fmt::Debug;
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use vectoroperations::vector2d::Vector2D;
+use geometrics::planimetry::{Triangle, SimplePolygon};
+use geometrics::bounding::Aabb2D;
+use error::*;
+
+
+/// Struct representing a Delaunay triangulation of a set of points
+/// # Remarks
+/// No point lies inside of the circumcircle of a triangle. Every triangle contains the indices of
+/// its three vertices in counterclockwise order.
+/// # Usage
+/// ```
+/// use mathol::geometrics::triangulation::DelaunayTriangulation;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelaunayTriangulation {
+    /// The points without duplicates in the order of their first appearance
+    pub points: Vec<Vector2D<f64>>,
+    /// The triangles as indices into points
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl DelaunayTriangulation {
+    /// Calculates the Delaunay triangulation of a set of points
+    /// # Remarks
+    /// Uses the Bowyer-Watson algorithm. Instead of a large enclosing triangle, every edge of the
+    /// convex hull is closed by a triangle with a symbolic vertex at infinity, so no triangle at the
+    /// border gets lost. If four or more points lie on a common circle, one of the possible
+    /// triangulations is returned.
+    /// # Return values
+    /// Returns the triangulation in case of success
+    ///
+    /// Returns EmptyVectorError if the slice of points is empty
+    ///
+    /// Returns VectorError if all points lie on a line
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::triangulation::DelaunayTriangulation;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let points = [Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(4, 3),
+    ///     Vector2D::build_vector(0, 3), Vector2D::build_vector(1, 1)];
+    /// let triangulation = DelaunayTriangulation::build_delaunay_triangulation(&points).expect("error");
+    /// assert_eq!(4, triangulation.triangles.len());
+    /// ```
+    pub fn build_delaunay_triangulation<T>(points: &[Vector2D<T>]) -> Result<DelaunayTriangulation, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        if points.is_empty() {
+            return Err(MatholError::EmptyVecCause(EmptyVectorError {
+                message: "Vector or Array is empty".to_string(),
+            }));
+        }
+
+        let mut unique: Vec<Vector2D<f64>> = Vec::with_capacity(points.len());
+        for p in points.iter().map(|p| p.convert_to_f64()) {
+            if !unique.contains(&p) {
+                unique.push(p);
+            }
+        }
+        let n = unique.len();
+        let third = match (2..n).find(|&i| get_orientation(&unique, 0, 1, i) != 0.0) {
+            Some(third) => third,
+            None => return Err(MatholError::VectorCause(VectorError {
+                message: "The points must not all lie on a line".to_string(),
+            })),
+        };
+
+        // The index n stands for the vertex at infinity
+        let first = if get_orientation(&unique, 0, 1, third) > 0.0 { [0, 1, third] } else { [1, 0, third] };
+        let mut triangles = vec![first, [first[1], first[0], n], [first[2], first[1], n], [first[0], first[2], n]];
+        for i in (2..n).filter(|&i| i != third) {
+            let cavity = get_cavity(&unique, &triangles, i);
+            let edges: Vec<(usize, usize)> = cavity.iter()
+                .flat_map(|&k| {
+                    let t = triangles[k];
+                    vec![(t[0], t[1]), (t[1], t[2]), (t[2], t[0])]
+                })
+                .collect();
+
+            triangles = triangles.iter().enumerate().filter(|&(k, _)| !cavity.contains(&k)).map(|(_, &t)| t).collect();
+            for &(a, b) in edges.iter() {
+                if !edges.contains(&(b, a)) {
+                    triangles.push([a, b, i]);
+                }
+            }
+        }
+        triangles.retain(|t| !t.contains(&n));
+
+        Ok(DelaunayTriangulation {points: unique, triangles})
+    }
+
+    /// Returns the three corners of a triangle
+    pub fn get_triangle_vertices(&self, triangle: usize) -> [Vector2D<f64>; 3] {
+        let t = self.triangles[triangle];
+        [self.points[t[0]], self.points[t[1]], self.points[t[2]]]
+    }
+
+    /// Converts all triangles of the triangulation to triangle instances
+    /// # Remarks
+    /// The triangles keep their vertices, so their centres can be calculated
+    /// # Return values
+    /// Returns the triangles in case of success
+    ///
+    /// Returns LengthError if a triangle is too flat to be constructed from its edges
+    pub fn to_triangles(&self) -> Result<Vec<Triangle>, MatholError> {
+        (0..self.triangles.len()).map(|i| {
+            let [a, b, c] = self.get_triangle_vertices(i);
+            let mut triangle = Triangle::build_triangle_with_edges(b.get_distance(c), a.get_distance(c), a.get_distance(b))?;
+            triangle.vertices = Some([a, b, c]);
+            Ok(triangle)
+        }).collect()
+    }
+
+    /// Returns the indices of all points that share an edge with the given point
+    pub fn get_neighbours(&self, point: usize) -> Vec<usize> {
+        let mut neighbours: Vec<usize> = self.triangles.iter()
+            .filter(|t| t.contains(&point))
+            .flat_map(|t| t.iter().cloned().filter(|&v| v != point).collect::<Vec<usize>>())
+            .collect();
+        neighbours.sort();
+        neighbours.dedup();
+        neighbours
+    }
+}
+
+/// Collects the triangles that have to be replaced when inserting the point with index p
+/// # Remarks
+/// Starts at the triangle that contains p and grows the cavity over the edges to every neighbour
+/// whose circumcircle contains p. An edge is crossed in any case if p does not lie strictly on the
+/// inner side, so the cavity stays connected and star-shaped even if the points are cocircular.
+/// If p lies outside of the convex hull, the search starts at a hull edge that is visible from p.
+fn get_cavity(vertices: &[Vector2D<f64>], triangles: &[[usize; 3]], p: usize) -> Vec<usize> {
+    let n = vertices.len();
+    let start = triangles.iter()
+        .position(|t| !t.contains(&n) && (0..3).all(|j| get_orientation(vertices, t[j], t[(j + 1) % 3], p) >= 0.0))
+        .or_else(|| triangles.iter().position(|&t| t.contains(&n) && is_in_circumcircle(vertices, t, p)))
+        .unwrap_or(0);
+
+    let mut cavity = vec![start];
+    let mut stack = vec![start];
+    while let Some(k) = stack.pop() {
+        let t = triangles[k];
+        for j in 0..3 {
+            let (a, b) = (t[j], t[(j + 1) % 3]);
+            let neighbour = triangles.iter().position(|s| (0..3).any(|l| s[l] == b && s[(l + 1) % 3] == a));
+            if let Some(neighbour) = neighbour {
+                let behind = a != n && b != n && get_orientation(vertices, a, b, p) <= 0.0;
+                if !cavity.contains(&neighbour) && (behind || is_in_circumcircle(vertices, triangles[neighbour], p)) {
+                    cavity.push(neighbour);
+                    stack.push(neighbour);
+                }
+            }
+        }
+    }
+    cavity
+}
+
+/// Calculates twice the signed area of the triangle with the vertices a, b, c
+/// # Remarks
+/// The indices are sorted before the calculation, so swapping two vertices flips exactly the sign
+/// of the result
+fn get_orientation(vertices: &[Vector2D<f64>], a: usize, b: usize, c: usize) -> f64 {
+    let mut indices = [a, b, c];
+    let mut sign = 1.0;
+    for &(j, k) in [(0, 1), (1, 2), (0, 1)].iter() {
+        if indices[j] > indices[k] {
+            indices.swap(j, k);
+            sign = -sign;
+        }
+    }
+    let (a, b, c) = (vertices[indices[0]], vertices[indices[1]], vertices[indices[2]]);
+    sign * (b - a).get_vector_product(c - a)
+}
+
+/// Checks if the point with index p lies inside of the circumcircle of a counterclockwise triangle
+/// # Remarks
+/// For a triangle with the vertex at infinity the circumcircle degenerates to the open half-plane
+/// beyond its finite edge, together with the inner part of the edge itself
+fn is_in_circumcircle(vertices: &[Vector2D<f64>], t: [usize; 3], p: usize) -> bool {
+    let n = vertices.len();
+    if let Some(j) = t.iter().position(|&v| v == n) {
+        let (a, b) = (t[(j + 1) % 3], t[(j + 2) % 3]);
+        let orientation = get_orientation(vertices, a, b, p);
+        let (a, b, p) = (vertices[a], vertices[b], vertices[p]);
+        return orientation > 0.0
+            || (orientation == 0.0 && (p - a).get_scalar_product(b - a) > 0.0 && (p - b).get_scalar_product(a - b) > 0.0);
+    }
+
+    let (a, b, c) = (vertices[t[0]] - vertices[p], vertices[t[1]] - vertices[p], vertices[t[2]] - vertices[p]);
+    let (aa, bb, cc) = (a.get_scalar_product(a), b.get_scalar_product(b), c.get_scalar_product(c));
+    let determinant = aa * b.get_vector_product(c) + bb * c.get_vector_product(a) + cc * a.get_vector_product(b);
+    determinant > 0.0
+}
+
+
+/// Struct representing a Voronoi diagram inside of a bounding box
+/// # Remarks
+/// The cell of a site contains all points of the box that are at least as close to this site as to
+/// every other site. The cells are convex polygons in counterclockwise order.
+/// # Usage
+/// ```
+/// use mathol::geometrics::triangulation::VoronoiDiagram;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoronoiDiagram {
+    /// The sites without duplicates in the order of their first appearance
+    pub sites: Vec<Vector2D<f64>>,
+    /// The cell of every site
+    pub cells: Vec<SimplePolygon>,
+}
+
+impl VoronoiDiagram {
+    /// Calculates the Voronoi diagram of a set of sites, clipped to a bounding box
+    /// # Return values
+    /// Returns the diagram in case of success
+    ///
+    /// Returns EmptyVectorError if the slice of sites is empty
+    ///
+    /// Returns VectorError if all sites lie on a line
+    ///
+    /// Returns RangeError if a site lies outside of the bounding box or if the box has no area
+    /// # Examples
+    /// ```
+    /// use mathol::geometrics::triangulation::VoronoiDiagram;
+    /// use mathol::geometrics::bounding::Aabb2D;
+    /// use mathol::geometrics::traits::Area;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let sites = [Vector2D::build_vector(1, 1), Vector2D::build_vector(3, 1), Vector2D::build_vector(1, 3), Vector2D::build_vector(3, 3)];
+    /// let bounds = Aabb2D::build_aabb(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 4)).expect("error");
+    /// let diagram = VoronoiDiagram::build_voronoi_diagram(&sites, bounds).expect("error");
+    /// assert!(diagram.cells.iter().all(|cell| (cell.get_area() - 4.0).abs() < 1e-12));
+    /// ```
+    pub fn build_voronoi_diagram<T>(sites: &[Vector2D<T>], bounds: Aabb2D) -> Result<VoronoiDiagram, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        DelaunayTriangulation::build_delaunay_triangulation(sites)?.get_voronoi_diagram(bounds)
+    }
+
+    /// Returns the index of the site that is closest to a point
+    /// # Remarks
+    /// If several sites have the same distance, the one with the smallest index is returned
+    pub fn get_nearest_site<T>(&self, p: Vector2D<T>) -> usize
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let p = p.convert_to_f64();
+        let mut nearest = 0;
+        for (i, site) in self.sites.iter().enumerate() {
+            if site.get_distance(p) < self.sites[nearest].get_distance(p) {
+                nearest = i;
+            }
+        }
+        nearest
+    }
+}
+
+impl DelaunayTriangulation {
+    /// Calculates the dual Voronoi diagram, clipped to a bounding box
+    /// # Remarks
+    /// The cell of a point is the box, cut by the perpendicular bisectors between the point and its
+    /// neighbours in the triangulation
+    /// # Return values
+    /// Returns the diagram in case of success
+    ///
+    /// Returns RangeError if a point lies outside of the bounding box or if the box has no area
+    pub fn get_voronoi_diagram(&self, bounds: Aabb2D) -> Result<VoronoiDiagram, MatholError> {
+        if self.points.iter().any(|p| !bounds.contains_point(*p)) {
+            return Err(MatholError::RangeCause(RangeError {
+                message: "The bounding box must contain all sites".to_string(),
+            }));
+        }
+        if bounds.get_size().x <= 0.0 || bounds.get_size().y <= 0.0 {
+            return Err(MatholError::RangeCause(RangeError {
+                message: "The bounding box must have a positive area".to_string(),
+            }));
+        }
+        let rectangle = SimplePolygon::build_polygon(vec![
+            bounds.min, Vector2D::build_vector(bounds.max.x, bounds.min.y),
+            bounds.max, Vector2D::build_vector(bounds.min.x, bounds.max.y),
+        ])?;
+
+        let cells = (0..self.points.len()).map(|i| {
+            let site = self.points[i];
+            let vertices = self.get_neighbours(i).iter().fold(rectangle.vertices.clone(), |cell, &k| {
+                let other = self.points[k];
+                clip_with_half_plane(&cell, site.lerp(other, 0.5), site - other)
+            });
+            SimplePolygon {vertices}
+        }).collect();
+
+        Ok(VoronoiDiagram {sites: self.points.clone(), cells})
+    }
+}
+
+/// Removes the part of a convex polygon that lies behind the line through p with the normal n
+fn clip_with_half_plane(vertices: &[Vector2D<f64>], p: Vector2D<f64>, n: Vector2D<f64>) -> Vec<Vector2D<f64>> {
+    let mut output = Vec::with_capacity(vertices.len() + 1);
+    for (i, &q) in vertices.iter().enumerate() {
+        let r = vertices[(i + vertices.len() - 1) % vertices.len()];
+        let (side_r, side_q) = (n.get_scalar_product(r - p), n.get_scalar_product(q - p));
+        if side_q >= 0.0 {
+            if side_r < 0.0 {
+                output.push(r + (q - r) * (side_r / (side_r - side_q)));
+            }
+            output.push(q);
+        } else if side_r >= 0.0 {
+            output.push(r + (q - r) * (side_r / (side_r - side_q)));
+        }
+    }
+    output.dedup();
+    output
+}
@@ -6,4 +6,5 @@ pub mod stochastics;
 pub mod statistics;
 pub mod vectoroperations;
 pub mod matrices;
+pub mod optimization;
 pub mod error;
\ No newline at end of file
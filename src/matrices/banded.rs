@@ -0,0 +1,335 @@
+use num::{Num, FromPrimitive};
+use std::ops::Add;
+use std::fmt::{Debug, Display};
+use basics::convert_trait::Convert;
+use matrices::matrice::Matrice;
+use error::*;
+
+/// Pivots with an absolute value below this tolerance are treated as zero
+const EPSILON: f64 = 1e-12;
+
+/// Solves a tridiagonal linear equation system with the Thomas algorithm in O(n)
+/// # Parameters
+/// lower: The subdiagonal with n - 1 elements
+///
+/// diagonal: The main diagonal with n elements
+///
+/// upper: The superdiagonal with n - 1 elements
+///
+/// c: The right-hand side with n elements
+/// # Return values
+/// Returns the solution vector in case of success
+///
+/// Returns LengthError if the diagonals and the right-hand side don't fit together
+///
+/// Returns MatriceError if a zero pivot occurs during the elimination
+/// # Remarks
+/// The Thomas algorithm doesn't pivot, so it is only guaranteed to be stable for diagonally
+/// dominant systems. Use is_diagonally_dominant() of BandedMatrice to check that beforehand.
+/// # Examples
+/// ```
+/// use mathol::matrices::banded::solve_tridiagonal;
+///
+/// let x = solve_tridiagonal(&[1, 1], &[1, 2, 2], &[1, 1], &[3, 8, 8]).unwrap();
+/// assert_eq!(vec![1.0, 2.0, 3.0], x);
+/// ```
+pub fn solve_tridiagonal<T>(lower: &[T], diagonal: &[T], upper: &[T], c: &[T]) -> Result<Vec<f64>, MatholError>
+    where T: Num + Convert + Copy
+{
+    let n = diagonal.len();
+    if n == 0 {
+        return Err(MatholError::EmptyVecCause(EmptyVectorError {
+            message: "Main diagonal is empty".to_string(),
+        }));
+    }
+    if lower.len() != n - 1 || upper.len() != n - 1 {
+        return Err(MatholError::LengthCause(LengthError {
+            message: format!("Sub- and superdiagonal must have {} elements", n - 1),
+        }));
+    }
+    if c.len() != n {
+        return Err(MatholError::LengthCause(LengthError {
+            message: format!("Right-hand side must have {} elements", n),
+        }));
+    }
+
+    let mut upper_prime = vec![0.0; n];
+    let mut c_prime = vec![0.0; n];
+
+    for i in 0..n {
+        let (a, u) = if i == 0 {
+            (0.0, 0.0)
+        } else {
+            (lower[i - 1].to_f64(), upper_prime[i - 1])
+        };
+        let pivot = diagonal[i].to_f64() - a * u;
+        if pivot.abs() < EPSILON {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: format!("Zero pivot in row {}", i),
+            }));
+        }
+        if i < n - 1 {
+            upper_prime[i] = upper[i].to_f64() / pivot;
+        }
+        let previous = if i == 0 { 0.0 } else { c_prime[i - 1] };
+        c_prime[i] = (c[i].to_f64() - a * previous) / pivot;
+    }
+
+    let mut x = c_prime;
+    for i in (0..n - 1).rev() {
+        x[i] -= upper_prime[i] * x[i + 1];
+    }
+
+    Ok(x)
+}
+
+/// A struct representing a quadratic band matrice
+/// # Remarks
+/// Only the elements inside the band are stored. Row i holds the elements from column
+/// i - lower up to column i + upper, so a band matrice with n rows needs n * (lower + upper + 1)
+/// elements instead of n * n.
+/// # Usage
+/// ```
+/// use mathol::matrices::banded::BandedMatrice;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandedMatrice<T>
+    where T: Num + Clone + Add<T> + Copy + Debug + Display + FromPrimitive + Convert
+{
+    /// Number of rows and columns in the matrice
+    pub rows: usize,
+    /// Number of subdiagonals inside the band
+    pub lower: usize,
+    /// Number of superdiagonals inside the band
+    pub upper: usize,
+    /// Content of the band, stored row by row
+    pub data: Vec<T>,
+}
+
+impl<T> BandedMatrice<T>
+    where T: Num + Clone + Add<T> + Copy + Debug + Display + FromPrimitive + Convert
+{
+    /// Builds a band matrice with the given size and bandwidths.
+    /// Every element inside the band is initialized with zero.
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::banded::BandedMatrice;
+    ///
+    /// let m: BandedMatrice<i32> = BandedMatrice::build_empty_banded_matrice(4, 1, 1);
+    /// assert_eq!(12, m.data.len());
+    /// ```
+    pub fn build_empty_banded_matrice(rows: usize, lower: usize, upper: usize) -> BandedMatrice<T> {
+        BandedMatrice {
+            rows,
+            lower,
+            upper,
+            data: vec![T::zero(); rows * (lower + upper + 1)],
+        }
+    }
+
+    /// Builds a band matrice from a quadratic matrice
+    /// # Return values
+    /// Returns the band matrice in case of success
+    ///
+    /// Returns MatriceError if the matrice is not quadratic or has non-zero elements outside of the band
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    /// use mathol::matrices::banded::BandedMatrice;
+    ///
+    /// let m = Matrice::build_matrice(3, 3, vec![2, 1, 0, 1, 2, 1, 0, 1, 2]).unwrap();
+    /// let b = BandedMatrice::build_from_matrice(&m, 1, 1).unwrap();
+    /// assert_eq!(Ok(1), b.get_element(2, 1));
+    /// ```
+    pub fn build_from_matrice(matrice: &Matrice<T>, lower: usize, upper: usize) -> Result<BandedMatrice<T>, MatholError> {
+        if matrice.rows != matrice.columns {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The matrice is not quadratic".to_string(),
+            }));
+        }
+
+        let mut banded = BandedMatrice::build_empty_banded_matrice(matrice.rows, lower, upper);
+        for i in 0..matrice.rows {
+            for k in 0..matrice.columns {
+                let element = matrice.data[i * matrice.columns + k];
+                if banded.is_in_band(i, k) {
+                    banded.insert_element(element, i, k)?;
+                } else if element != T::zero() {
+                    return Err(MatholError::MatriceCause(MatriceError {
+                        message: "The matrice has non-zero elements outside of the band".to_string(),
+                    }));
+                }
+            }
+        }
+
+        Ok(banded)
+    }
+
+    /// Checks if the position at the given row and column lies inside the band
+    pub fn is_in_band(&self, row: usize, column: usize) -> bool {
+        row < self.rows && column < self.rows && column + self.lower >= row && column <= row + self.upper
+    }
+
+    /// Returns the element at a given position
+    /// # Remarks
+    /// Elements outside of the band are zero
+    ///
+    /// Returns an error message if the position is outside of the matrice
+    pub fn get_element(&self, row: usize, column: usize) -> Result<T, MatholError> {
+        if row >= self.rows {
+            return Err(MatholError::OutOfBoundsCause(OutOfBoundsError {
+                message: "Row is out of bounds".to_string(),
+            }));
+        }
+        if column >= self.rows {
+            return Err(MatholError::OutOfBoundsCause(OutOfBoundsError {
+                message: "Column is out of bounds".to_string(),
+            }));
+        }
+
+        if self.is_in_band(row, column) {
+            Ok(self.data[self.get_index(row, column)])
+        } else {
+            Ok(T::zero())
+        }
+    }
+
+    /// Inserts an element at a given position
+    /// # Remarks
+    /// Returns an error message if the position is outside of the band
+    pub fn insert_element(&mut self, element: T, row: usize, column: usize) -> Result<(), MatholError> {
+        if !self.is_in_band(row, column) {
+            return Err(MatholError::OutOfBoundsCause(OutOfBoundsError {
+                message: "Position is outside of the band".to_string(),
+            }));
+        }
+
+        let index = self.get_index(row, column);
+        self.data[index] = element;
+        Ok(())
+    }
+
+    /// Converts the band matrice into a dense matrice
+    pub fn to_matrice(&self) -> Matrice<T> {
+        let mut matrice = Matrice::build_empty_matrice(self.rows, self.rows);
+        for i in 0..self.rows {
+            for k in self.get_band_columns(i) {
+                matrice.insert_element(self.data[self.get_index(i, k)], i, k);
+            }
+        }
+
+        matrice
+    }
+
+    /// Multiplies the band matrice with a vector
+    /// # Remarks
+    /// Returns an error message if the vector does not have one element per column
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    /// use mathol::matrices::banded::BandedMatrice;
+    ///
+    /// let m = Matrice::build_matrice(3, 3, vec![2, 1, 0, 1, 2, 1, 0, 1, 2]).unwrap();
+    /// let b = BandedMatrice::build_from_matrice(&m, 1, 1).unwrap();
+    /// assert_eq!(Ok(vec![4, 8, 8]), b.multiply_with_vector(&[1, 2, 3]));
+    /// ```
+    pub fn multiply_with_vector(&self, vec: &[T]) -> Result<Vec<T>, MatholError> {
+        if vec.len() != self.rows {
+            return Err(MatholError::LengthCause(LengthError {
+                message: format!("Vector must have {} elements", self.rows),
+            }));
+        }
+
+        let result = (0..self.rows).map(|i| {
+            self.get_band_columns(i).fold(T::zero(), |sum, k| sum + self.data[self.get_index(i, k)] * vec[k])
+        }).collect();
+
+        Ok(result)
+    }
+
+    /// Checks if the matrice is strictly diagonally dominant by rows
+    /// # Remarks
+    /// A matrice is strictly diagonally dominant if in every row the amount of the diagonal element is
+    /// bigger than the sum of the amounts of all other elements. Such systems can be solved without pivoting.
+    pub fn is_diagonally_dominant(&self) -> bool {
+        (0..self.rows).all(|i| {
+            let off = self.get_band_columns(i)
+                .filter(|k| *k != i)
+                .fold(0.0, |sum, k| sum + self.data[self.get_index(i, k)].to_f64().abs());
+            self.data[self.get_index(i, i)].to_f64().abs() > off
+        })
+    }
+
+    /// Solves a linear equation system with a LU decomposition that stays inside the band
+    /// # Remarks
+    /// The decomposition does not pivot, so L has the same number of subdiagonals and U the same
+    /// number of superdiagonals as the matrice. This takes O(n * lower * upper) operations.
+    ///
+    /// Returns an error message if c does not have one element per row or a zero pivot occurs
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    /// use mathol::matrices::banded::BandedMatrice;
+    ///
+    /// let m = Matrice::build_matrice(3, 3, vec![1, 1, 0, 1, 2, 1, 0, 1, 2]).unwrap();
+    /// let b = BandedMatrice::build_from_matrice(&m, 1, 1).unwrap();
+    /// assert_eq!(Ok(vec![1.0, 2.0, 3.0]), b.solve(&[3, 8, 8]));
+    /// ```
+    pub fn solve(&self, c: &[T]) -> Result<Vec<f64>, MatholError> {
+        if c.len() != self.rows {
+            return Err(MatholError::LengthCause(LengthError {
+                message: format!("Right-hand side must have {} elements", self.rows),
+            }));
+        }
+
+        let n = self.rows;
+        let mut lu: Vec<f64> = self.data.iter().map(|e| e.to_f64()).collect();
+
+        for k in 0..n {
+            let pivot = lu[self.get_index(k, k)];
+            if pivot.abs() < EPSILON {
+                return Err(MatholError::MatriceCause(MatriceError {
+                    message: format!("Zero pivot in row {}", k),
+                }));
+            }
+            let last_row = (k + self.lower).min(n - 1);
+            let last_column = (k + self.upper).min(n - 1);
+            for i in k + 1..last_row + 1 {
+                let index = self.get_index(i, k);
+                lu[index] /= pivot;
+                let factor = lu[index];
+                for j in k + 1..last_column + 1 {
+                    let target = self.get_index(i, j);
+                    lu[target] -= factor * lu[self.get_index(k, j)];
+                }
+            }
+        }
+
+        let mut x: Vec<f64> = c.iter().map(|e| e.to_f64()).collect();
+        for i in 0..n {
+            let first = i.saturating_sub(self.lower);
+            for k in first..i {
+                x[i] -= lu[self.get_index(i, k)] * x[k];
+            }
+        }
+        for i in (0..n).rev() {
+            let last = (i + self.upper).min(n - 1);
+            for k in i + 1..last + 1 {
+                x[i] -= lu[self.get_index(i, k)] * x[k];
+            }
+            x[i] /= lu[self.get_index(i, i)];
+        }
+
+        Ok(x)
+    }
+
+    /// A helper function returning the range of columns inside the band for a given row
+    fn get_band_columns(&self, row: usize) -> ::std::ops::Range<usize> {
+        row.saturating_sub(self.lower)..(row + self.upper + 1).min(self.rows)
+    }
+
+    /// A helper function mapping a position inside the band to its index in data
+    fn get_index(&self, row: usize, column: usize) -> usize {
+        row * (self.lower + self.upper + 1) + column + self.lower - row
+    }
+}
@@ -0,0 +1,172 @@
+/// Iterator over the rows of a matrice, created by Matrice::rows()
+/// # Remarks
+/// Every row is returned as a slice borrowing the data of the matrice
+#[derive(Debug, Clone)]
+pub struct Rows<'a, T: 'a> {
+    pub(crate) data: &'a [T],
+    pub(crate) columns: usize,
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let start = self.front * self.columns;
+        self.front += 1;
+        Some(&self.data[start..start + self.columns])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        let start = self.back * self.columns;
+        Some(&self.data[start..start + self.columns])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Rows<'a, T> {}
+
+/// Iterator over the columns of a matrice, created by Matrice::columns()
+/// # Remarks
+/// Every column is returned as a Column iterator borrowing the data of the matrice
+#[derive(Debug, Clone)]
+pub struct Columns<'a, T: 'a> {
+    pub(crate) data: &'a [T],
+    pub(crate) rows: usize,
+    pub(crate) columns: usize,
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+}
+
+impl<'a, T> Iterator for Columns<'a, T> {
+    type Item = Column<'a, T>;
+
+    fn next(&mut self) -> Option<Column<'a, T>> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let column = Column {
+            data: self.data,
+            columns: self.columns,
+            column: self.front,
+            front: 0,
+            back: self.rows,
+        };
+        self.front += 1;
+        Some(column)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Columns<'a, T> {
+    fn next_back(&mut self) -> Option<Column<'a, T>> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(Column {
+            data: self.data,
+            columns: self.columns,
+            column: self.back,
+            front: 0,
+            back: self.rows,
+        })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Columns<'a, T> {}
+
+/// Iterator over the elements of a single column, from the top row to the bottom row
+#[derive(Debug, Clone)]
+pub struct Column<'a, T: 'a> {
+    data: &'a [T],
+    columns: usize,
+    column: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for Column<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let element = &self.data[self.front * self.columns + self.column];
+        self.front += 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Column<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(&self.data[self.back * self.columns + self.column])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Column<'a, T> {}
+
+/// Iterator over the elements of a matrice together with their position, created by Matrice::indexed_iter()
+/// # Remarks
+/// The elements are returned row by row as ((row, column), &element)
+#[derive(Debug, Clone)]
+pub struct IndexedIter<'a, T: 'a> {
+    pub(crate) data: &'a [T],
+    pub(crate) columns: usize,
+    pub(crate) index: usize,
+}
+
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<((usize, usize), &'a T)> {
+        if self.index >= self.data.len() {
+            return None;
+        }
+
+        let i = self.index;
+        self.index += 1;
+        Some(((i / self.columns, i % self.columns), &self.data[i]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.data.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IndexedIter<'a, T> {}
@@ -3,8 +3,13 @@ use std::ops::Add;
 use std::fmt::{Debug, Display};
 use basics::pow;
 use basics::convert_trait::Convert;
+use basics::approx_trait::{ApproxEq, Tolerance};
 use matrices::solvable::Solvable;
 use matrices::vector_help::{add_gaussian, get_scalar_product_of_vectors, reduce_row};
+use matrices::iter::{Rows, Columns, IndexedIter};
+use std::iter::FromIterator;
+use std::slice::Iter;
+use std::cmp::Ordering;
 use error::*;
 
 /// A struct representing matrices
@@ -723,4 +728,338 @@ impl<T> Matrice<T>
 
         Ok((m, s))
     }
+
+    /// Returns an iterator over the rows of the matrice
+    /// # Remarks
+    /// Every row is a slice borrowing the data of the matrice, so nothing gets copied
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let mut rows = m.rows();
+    /// assert_eq!(Some(&[1, 2, 3][..]), rows.next());
+    /// assert_eq!(Some(&[4, 5, 6][..]), rows.next());
+    /// assert_eq!(None, rows.next());
+    /// ```
+    pub fn rows(&self) -> Rows<'_, T> {
+        Rows {
+            data: &self.data,
+            columns: self.columns,
+            front: 0,
+            back: self.rows,
+        }
+    }
+
+    /// Returns an iterator over the columns of the matrice
+    /// # Remarks
+    /// Every column is an iterator over references to its elements, so nothing gets copied
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let columns: Vec<Vec<i32>> = m.columns().map(|c| c.cloned().collect()).collect();
+    /// assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], columns);
+    /// ```
+    pub fn columns(&self) -> Columns<'_, T> {
+        Columns {
+            data: &self.data,
+            rows: self.rows,
+            columns: self.columns,
+            front: 0,
+            back: self.columns,
+        }
+    }
+
+    /// Returns an iterator over all elements of the matrice, row by row
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(10, m.iter_elements().sum::<i32>());
+    /// ```
+    pub fn iter_elements(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over all elements of the matrice together with their position
+    /// # Remarks
+    /// The elements are returned row by row as ((row, column), &element)
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// let mut iter = m.indexed_iter();
+    /// assert_eq!(Some(((0, 0), &1)), iter.next());
+    /// assert_eq!(Some(((0, 1), &2)), iter.next());
+    /// assert_eq!(Some(((1, 0), &3)), iter.next());
+    /// ```
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter {
+            data: &self.data,
+            columns: self.columns,
+            index: 0,
+        }
+    }
+
+    /// Applies a function to every element of the matrice
+    /// # Remarks
+    /// Returns the results as a new matrice with the same number of rows and columns
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(vec![0.5, 1.0, 1.5, 2.0], m.map(|e| e as f64 / 2.0).data);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Matrice<U>
+        where U: Num + Clone + Add<U> + Copy + Debug + Display + FromPrimitive + Convert,
+              F: Fn(T) -> U
+    {
+        Matrice {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.iter().map(|e| f(*e)).collect(),
+        }
+    }
+
+    /// Combines the elements of two matrices of the same type pairwise with a function
+    /// # Remarks
+    /// Returns the results as a new matrice
+    ///
+    /// If the matrices are not of the same type (not the same number of rows and columns), an error
+    /// message is returned.
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let a = Matrice::build_matrice(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// let b = Matrice::build_matrice(2, 2, vec![5, 6, 7, 8]).unwrap();
+    /// assert_eq!(vec![5, 12, 21, 32], a.zip_with(&b, |x, y| x * y).unwrap().data);
+    /// ```
+    pub fn zip_with<U, V, F>(&self, other: &Matrice<U>, f: F) -> Result<Matrice<V>, MatholError>
+        where U: Num + Clone + Add<U> + Copy + Debug + Display + FromPrimitive + Convert,
+              V: Num + Clone + Add<V> + Copy + Debug + Display + FromPrimitive + Convert,
+              F: Fn(T, U) -> V
+    {
+        if self.rows != other.rows || self.columns != other.columns {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The two matrices do not have the same number of rows or columns".to_string(),
+            }));
+        }
+
+        Ok(Matrice {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.iter().zip(other.data.iter()).map(|(a, b)| f(*a, *b)).collect(),
+        })
+    }
+
+    /// Folds every row of the matrice into a single value
+    /// # Remarks
+    /// Returns a vector with one accumulated value per row
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 3, vec![1, 5, 3, 4, 2, 6]).unwrap();
+    /// assert_eq!(vec![5, 6], m.fold_rows(0, |max, e| if e > max { e } else { max }));
+    /// ```
+    pub fn fold_rows<A, F>(&self, init: A, f: F) -> Vec<A>
+        where A: Clone, F: Fn(A, T) -> A
+    {
+        self.rows().map(|row| row.iter().fold(init.clone(), |acc, e| f(acc, *e))).collect()
+    }
+
+    /// Folds every column of the matrice into a single value
+    /// # Remarks
+    /// Returns a vector with one accumulated value per column
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 3, vec![1, 5, 3, 4, 2, 6]).unwrap();
+    /// assert_eq!(vec![4, 10, 18], m.fold_columns(1, |prod, e| prod * e));
+    /// ```
+    pub fn fold_columns<A, F>(&self, init: A, f: F) -> Vec<A>
+        where A: Clone, F: Fn(A, T) -> A
+    {
+        self.columns().map(|column| column.fold(init.clone(), |acc, e| f(acc, *e))).collect()
+    }
+
+    /// Calculates the sum of every row of the matrice
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(vec![6, 15], m.get_row_sums());
+    /// ```
+    pub fn get_row_sums(&self) -> Vec<T> {
+        self.fold_rows(T::zero(), |sum, e| sum + e)
+    }
+
+    /// Calculates the sum of every column of the matrice
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(vec![5, 7, 9], m.get_column_sums());
+    /// ```
+    pub fn get_column_sums(&self) -> Vec<T> {
+        self.fold_columns(T::zero(), |sum, e| sum + e)
+    }
+
+    /// Calculates the eigenvalues and eigenvectors of a symmetric matrice
+    /// # Remarks
+    /// Uses the cyclic Jacobi method. The eigenvalues are sorted in descending order and the k-th
+    /// column of the returned matrice is the normalized eigenvector of the k-th eigenvalue.
+    /// # Return values
+    /// Returns the tuple (eigenvalues, eigenvectors) in case of success
+    ///
+    /// Returns MatriceError if the matrice is not quadratic or not symmetric
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m = Matrice::build_matrice(2, 2, vec![2, 1, 1, 2]).unwrap();
+    /// let (values, vectors) = m.get_symmetric_eigen().unwrap();
+    /// assert!((values[0] - 3.0).abs() < 1e-12);
+    /// assert!((values[1] - 1.0).abs() < 1e-12);
+    /// assert!((vectors.data[0] - vectors.data[2]).abs() < 1e-12);
+    /// ```
+    pub fn get_symmetric_eigen(&self) -> Result<(Vec<f64>, Matrice<f64>), MatholError> {
+        if self.rows != self.columns {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The matrice is not quadratic".to_string(),
+            }));
+        }
+
+        let n = self.rows;
+        let mut a: Vec<f64> = self.data.iter().map(|e| e.to_f64()).collect();
+        let norm = a.iter().fold(0.0, |sum, e| sum + e * e).sqrt();
+        for i in 0..n {
+            for k in i + 1..n {
+                if (a[i * n + k] - a[k * n + i]).abs() > 1e-12 * (1.0 + norm) {
+                    return Err(MatholError::MatriceCause(MatriceError {
+                        message: "The matrice is not symmetric".to_string(),
+                    }));
+                }
+            }
+        }
+
+        let mut v = vec![0.0; n * n];
+        for i in 0..n {
+            v[i * n + i] = 1.0;
+        }
+
+        for _ in 0..100 {
+            let off = (0..n).fold(0.0, |sum, i| (0..n).filter(|k| *k != i).fold(sum, |sum, k| sum + a[i * n + k] * a[i * n + k]));
+            if off.sqrt() <= 1e-15 * norm {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a[p * n + q];
+                    if apq == 0.0 {
+                        continue;
+                    }
+
+                    let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = c * akp - s * akq;
+                        a[k * n + q] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * apk - s * aqk;
+                        a[q * n + k] = s * apk + c * aqk;
+                    }
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = c * vkp - s * vkq;
+                        v[k * n + q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|i, k| a[k * n + k].partial_cmp(&a[i * n + i]).unwrap_or(Ordering::Equal));
+
+        let values = order.iter().map(|i| a[i * n + i]).collect();
+        let mut vectors = vec![0.0; n * n];
+        for (column, i) in order.iter().enumerate() {
+            for row in 0..n {
+                vectors[row * n + column] = v[row * n + i];
+            }
+        }
+
+        Ok((values, Matrice::build_matrice(n, n, vectors)?))
+    }
+}
+
+impl<T> ApproxEq for Matrice<T>
+    where T: Num + Clone + Add<T> + Copy + Debug + Display + FromPrimitive + Convert + ApproxEq
+{
+    /// Checks if two matrices have the same type and approximately equal elements
+    /// # Examples
+    /// ```
+    /// use mathol::basics::approx_trait::ApproxEq;
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let a = Matrice::build_matrice(1, 2, vec![0.1 + 0.2, 1.0]).unwrap();
+    /// let b = Matrice::build_matrice(1, 2, vec![0.3, 1.0]).unwrap();
+    /// assert!(a.abs_diff_eq(&b, 1e-12));
+    /// ```
+    fn approx_eq(&self, other: &Matrice<T>, tolerance: Tolerance) -> bool {
+        self.rows == other.rows && self.columns == other.columns &&
+            self.data.iter().zip(other.data.iter()).all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<T> FromIterator<Vec<T>> for Matrice<T>
+    where T: Num + Clone + Add<T> + Copy + Debug + Display + FromPrimitive + Convert
+{
+    /// Builds a matrice from an iterator over its rows
+    /// # Remarks
+    /// Panics if the rows do not all have the same number of columns
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    ///
+    /// let m: Matrice<i32> = (1..3).map(|i| vec![i, 2 * i, 3 * i]).collect();
+    /// assert_eq!(2, m.rows);
+    /// assert_eq!(3, m.columns);
+    /// assert_eq!(vec![1, 2, 3, 2, 4, 6], m.data);
+    /// ```
+    fn from_iter<I: IntoIterator<Item=Vec<T>>>(iter: I) -> Matrice<T> {
+        let mut matrice = Matrice {
+            rows: 0,
+            columns: 0,
+            data: Vec::new(),
+        };
+
+        for row in iter {
+            if matrice.rows == 0 {
+                matrice.columns = row.len();
+            } else if row.len() != matrice.columns {
+                panic!("Row must have {} columns", matrice.columns);
+            }
+            matrice.data.extend(row);
+            matrice.rows += 1;
+        }
+
+        matrice
+    }
 }
\ No newline at end of file
@@ -1,3 +1,6 @@
 pub mod matrice;
 pub mod solvable;
-pub mod vector_help;
\ No newline at end of file
+pub mod vector_help;
+pub mod banded;
+pub mod iter;
+pub mod random;
\ No newline at end of file
@@ -0,0 +1,244 @@
+use std::f64::consts::PI;
+use matrices::matrice::Matrice;
+use error::*;
+
+/// A seedable generator for random matrices
+/// # Remarks
+/// The generator is a xoshiro256** generator whose state is filled from the seed with SplitMix64.
+/// It does not use any entropy of the operating system, so the same seed always reproduces the
+/// same sequence of matrices on every platform.
+/// # Usage
+/// ```
+/// use mathol::matrices::random::MatriceGenerator;
+/// ```
+#[derive(Debug, Clone)]
+pub struct MatriceGenerator {
+    state: [u64; 4],
+    spare: Option<f64>,
+}
+
+impl MatriceGenerator {
+    /// Creates a generator from a seed
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::random::MatriceGenerator;
+    ///
+    /// let mut g1 = MatriceGenerator::build_generator(42);
+    /// let mut g2 = MatriceGenerator::build_generator(42);
+    /// assert_eq!(g1.next_u64(), g2.next_u64());
+    /// ```
+    pub fn build_generator(seed: u64) -> MatriceGenerator {
+        let mut s = seed;
+        let mut state = [0; 4];
+        for e in state.iter_mut() {
+            s = s.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = s;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            *e = z ^ (z >> 31);
+        }
+
+        MatriceGenerator {
+            state,
+            spare: None,
+        }
+    }
+
+    /// Returns the next uniformly distributed 64 bit integer
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    /// Returns the next uniformly distributed number in the interval [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns the next standard normally distributed number
+    /// # Remarks
+    /// The numbers are generated pairwise with the Box-Muller transform
+    pub fn next_normal(&mut self) -> f64 {
+        if let Some(z) = self.spare.take() {
+            return z;
+        }
+
+        let u = 1.0 - self.next_f64();
+        let v = self.next_f64();
+        let r = (-2.0 * u.ln()).sqrt();
+        self.spare = Some(r * (2.0 * PI * v).sin());
+        r * (2.0 * PI * v).cos()
+    }
+
+    /// Generates a matrice with uniformly distributed elements in the interval [low, high)
+    /// # Return values
+    /// Returns the matrice in case of success
+    ///
+    /// Returns RangeError if low is not smaller than high
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::random::MatriceGenerator;
+    ///
+    /// let mut g = MatriceGenerator::build_generator(7);
+    /// let m = g.gen_uniform_matrice(3, 4, -1.0, 1.0).unwrap();
+    /// assert!(m.data.iter().all(|e| *e >= -1.0 && *e < 1.0));
+    /// ```
+    pub fn gen_uniform_matrice(&mut self, rows: usize, columns: usize, low: f64, high: f64) -> Result<Matrice<f64>, MatholError> {
+        if low >= high {
+            return Err(MatholError::RangeCause(RangeError {
+                message: "Lower bound must be smaller than upper bound".to_string(),
+            }));
+        }
+
+        let data = (0..rows * columns).map(|_| low + (high - low) * self.next_f64()).collect();
+        Matrice::build_matrice(rows, columns, data)
+    }
+
+    /// Generates a matrice with normally distributed elements
+    /// # Return values
+    /// Returns the matrice in case of success
+    ///
+    /// Returns NegativeValueError if the standard deviation is negative
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::random::MatriceGenerator;
+    ///
+    /// let mut g = MatriceGenerator::build_generator(7);
+    /// let m = g.gen_normal_matrice(3, 4, 10.0, 2.0).unwrap();
+    /// assert_eq!(12, m.data.len());
+    /// ```
+    pub fn gen_normal_matrice(&mut self, rows: usize, columns: usize, mean: f64, std_dev: f64) -> Result<Matrice<f64>, MatholError> {
+        if std_dev < 0.0 {
+            return Err(MatholError::NegativeValueCause(NegativeValueError {
+                message: "Standard deviation must not be negative".to_string(),
+            }));
+        }
+
+        let data = (0..rows * columns).map(|_| mean + std_dev * self.next_normal()).collect();
+        Matrice::build_matrice(rows, columns, data)
+    }
+
+    /// Generates a random orthogonal matrice distributed according to the Haar measure
+    /// # Remarks
+    /// The columns of a matrice with standard normally distributed elements are orthonormalized with
+    /// the Gram-Schmidt method. As the diagonal of the resulting R is positive, Q is Haar distributed.
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::random::MatriceGenerator;
+    ///
+    /// let mut g = MatriceGenerator::build_generator(7);
+    /// let q = g.gen_orthogonal_matrice(3);
+    /// let column: Vec<f64> = q.columns().next().unwrap().cloned().collect();
+    /// let length: f64 = column.iter().map(|e| e * e).sum();
+    /// assert!((length - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn gen_orthogonal_matrice(&mut self, n: usize) -> Matrice<f64> {
+        let mut columns: Vec<Vec<f64>> = Vec::with_capacity(n);
+
+        while columns.len() < n {
+            let mut v: Vec<f64> = (0..n).map(|_| self.next_normal()).collect();
+            for q in columns.iter() {
+                let p = (0..n).fold(0.0, |sum, i| sum + q[i] * v[i]);
+                for (e, qi) in v.iter_mut().zip(q.iter()) {
+                    *e -= p * qi;
+                }
+            }
+            let length = v.iter().fold(0.0, |sum, e| sum + e * e).sqrt();
+            // Almost surely never happens, a linearly dependent column is simply drawn again
+            if length < 1e-10 {
+                continue;
+            }
+            columns.push(v.iter().map(|e| e / length).collect());
+        }
+
+        let mut matrice = Matrice::build_empty_matrice(n, n);
+        for (k, column) in columns.iter().enumerate() {
+            for (i, e) in column.iter().enumerate() {
+                matrice.insert_element(*e, i, k);
+            }
+        }
+
+        matrice
+    }
+
+    /// Generates a random symmetric positive definite matrice
+    /// # Remarks
+    /// The matrice is calculated as G * Gᵀ + I, where G has standard normally distributed elements
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::random::MatriceGenerator;
+    ///
+    /// let mut g = MatriceGenerator::build_generator(7);
+    /// let m = g.gen_spd_matrice(3);
+    /// assert_eq!(m.get_element(0, 1), m.get_element(1, 0));
+    /// assert!(m.get_determinant().unwrap() > 0.0);
+    /// ```
+    pub fn gen_spd_matrice(&mut self, n: usize) -> Matrice<f64> {
+        let g = self.gen_normal_matrice(n, n, 0.0, 1.0).unwrap();
+
+        let mut matrice = Matrice::build_empty_matrice(n, n);
+        for i in 0..n {
+            for k in 0..i + 1 {
+                let mut e = (0..n).fold(0.0, |sum, j| sum + g.data[i * n + j] * g.data[k * n + j]);
+                if i == k {
+                    e += 1.0;
+                }
+                matrice.insert_element(e, i, k);
+                matrice.insert_element(e, k, i);
+            }
+        }
+
+        matrice
+    }
+
+    /// Generates a random sparse matrice with the given density
+    /// # Remarks
+    /// Exactly round(density * rows * columns) positions are chosen at random and filled with
+    /// standard normally distributed numbers, all other elements are zero.
+    /// # Return values
+    /// Returns the matrice in case of success
+    ///
+    /// Returns RangeError if the density is not between 0 and 1
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::random::MatriceGenerator;
+    ///
+    /// let mut g = MatriceGenerator::build_generator(7);
+    /// let m = g.gen_sparse_matrice(10, 10, 0.2).unwrap();
+    /// assert_eq!(20, m.data.iter().filter(|e| **e != 0.0).count());
+    /// ```
+    pub fn gen_sparse_matrice(&mut self, rows: usize, columns: usize, density: f64) -> Result<Matrice<f64>, MatholError> {
+        if !(0.0..=1.0).contains(&density) {
+            return Err(MatholError::RangeCause(RangeError {
+                message: "Density must be in a range between 0 and 1".to_string(),
+            }));
+        }
+
+        let size = rows * columns;
+        let count = (density * size as f64).round() as usize;
+        let mut positions: Vec<usize> = (0..size).collect();
+        let mut data = vec![0.0; size];
+
+        // Partial Fisher-Yates shuffle, the first count positions get a value
+        for i in 0..count {
+            let j = i + (self.next_u64() % (size - i) as u64) as usize;
+            positions.swap(i, j);
+            let mut value = 0.0;
+            while value == 0.0 {
+                value = self.next_normal();
+            }
+            data[positions[i]] = value;
+        }
+
+        Matrice::build_matrice(rows, columns, data)
+    }
+}
@@ -0,0 +1,244 @@
+use num::{Num, FromPrimitive};
+use std::ops::Add;
+use std::fmt::{Debug, Display};
+use basics::convert_trait::Convert;
+use matrices::matrice::Matrice;
+use error::*;
+
+/// Tolerance below which values in the simplex tableau are treated as zero
+const EPSILON: f64 = 1e-9;
+
+/// A struct representing a linear program in canonical form
+///
+/// maximise cᵀx subject to Ax ≤ b and x ≥ 0
+/// # Usage
+/// ```
+/// use mathol::optimization::linear::LinearProgram;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearProgram {
+    /// Coefficients of the constraints, one row per constraint
+    pub a: Matrice<f64>,
+    /// Right-hand side of the constraints
+    pub b: Vec<f64>,
+    /// Coefficients of the objective function
+    pub c: Vec<f64>,
+}
+
+/// The optimal solution of a linear program
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimplexSolution {
+    /// The optimal vertex
+    pub x: Vec<f64>,
+    /// Value of the objective function at the optimal vertex
+    pub objective: f64,
+    /// Dual values (shadow prices) of the constraints
+    pub duals: Vec<f64>,
+}
+
+impl LinearProgram {
+    /// Creates a linear program from the constraint matrice A, the bounds b and the objective c
+    /// # Parameters
+    /// a: Matrice with one row per constraint and one column per variable
+    ///
+    /// b: Right-hand side of the constraints
+    ///
+    /// c: Coefficients of the objective function
+    /// # Return values
+    /// Returns the linear program in case of success
+    ///
+    /// Returns LengthError if b does not have one entry per row or c does not have one entry per column of A
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    /// use mathol::optimization::linear::LinearProgram;
+    ///
+    /// let a = Matrice::build_matrice(3, 2, vec![1, 0, 0, 2, 3, 2]).unwrap();
+    /// let lp = LinearProgram::build_linear_program(&a, &[4, 12, 18], &[3, 5]).expect("error");
+    /// ```
+    pub fn build_linear_program<T>(a: &Matrice<T>, b: &[T], c: &[T]) -> Result<LinearProgram, MatholError>
+        where T: Num + Clone + Add<T> + Copy + Debug + Display + FromPrimitive + Convert
+    {
+        if b.len() != a.rows {
+            return Err(MatholError::LengthCause(LengthError {
+                message: format!("Vector b must have {} elements", a.rows),
+            }));
+        }
+        if c.len() != a.columns {
+            return Err(MatholError::LengthCause(LengthError {
+                message: format!("Vector c must have {} elements", a.columns),
+            }));
+        }
+
+        Ok(LinearProgram {
+            a: Matrice {
+                rows: a.rows,
+                columns: a.columns,
+                data: a.data.iter().map(|e| e.to_f64()).collect(),
+            },
+            b: b.iter().map(|e| e.to_f64()).collect(),
+            c: c.iter().map(|e| e.to_f64()).collect(),
+        })
+    }
+
+    /// Solves the linear program with the two-phase simplex method
+    /// # Remarks
+    /// Phase one searches a feasible vertex if some entries of b are negative, phase two optimises
+    /// the objective function from there. Bland's rule is used for choosing the pivot elements,
+    /// so the method terminates on degenerate problems as well.
+    /// # Return values
+    /// Returns the optimal vertex, the objective value and the dual values in case of success
+    ///
+    /// Returns InfeasibleError if no x satisfies the constraints
+    ///
+    /// Returns UnboundedError if the objective function can grow without limit
+    /// # Examples
+    /// maximise 3x + 5y subject to x ≤ 4, 2y ≤ 12, 3x + 2y ≤ 18
+    ///
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    /// use mathol::optimization::linear::LinearProgram;
+    ///
+    /// let a = Matrice::build_matrice(3, 2, vec![1, 0, 0, 2, 3, 2]).unwrap();
+    /// let lp = LinearProgram::build_linear_program(&a, &[4, 12, 18], &[3, 5]).unwrap();
+    /// let solution = lp.solve().unwrap();
+    /// assert_eq!(vec![2.0, 6.0], solution.x);
+    /// assert_eq!(36.0, solution.objective);
+    /// assert_eq!(vec![0.0, 1.5, 1.0], solution.duals);
+    /// ```
+    pub fn solve(&self) -> Result<SimplexSolution, MatholError> {
+        let m = self.a.rows;
+        let n = self.a.columns;
+        let artificials = self.b.iter().filter(|b| **b < 0.0).count();
+        let width = n + m + artificials;
+
+        // Every row gets a slack variable. Rows with a negative bound are negated and get an
+        // artificial variable, since their slack variable can't form a feasible basis.
+        let mut tableau = Vec::with_capacity(m);
+        let mut basis = Vec::with_capacity(m);
+        let mut k = 0;
+        for i in 0..m {
+            let sign = if self.b[i] < 0.0 { -1.0 } else { 1.0 };
+            let mut row = vec![0.0; width + 1];
+            for (e, a) in row.iter_mut().zip(self.a.data[i * n..(i + 1) * n].iter()) {
+                *e = sign * a;
+            }
+            row[n + i] = sign;
+            row[width] = sign * self.b[i];
+            if sign < 0.0 {
+                row[n + m + k] = 1.0;
+                basis.push(n + m + k);
+                k += 1;
+            } else {
+                basis.push(n + i);
+            }
+            tableau.push(row);
+        }
+
+        if artificials > 0 {
+            let cost: Vec<f64> = (0..width).map(|j| if j >= n + m { -1.0 } else { 0.0 }).collect();
+            run_simplex(&mut tableau, &mut basis, &cost, width)?;
+
+            let infeasibility = (0..m).fold(0.0, |sum, i| {
+                if basis[i] >= n + m { sum + tableau[i][width] } else { sum }
+            });
+            if infeasibility > EPSILON {
+                return Err(MatholError::InfeasibleCause(InfeasibleError {
+                    message: "The linear program has no feasible solution".to_string(),
+                }));
+            }
+
+            // Artificial variables that are still basic at level zero are pivoted out. If a row has
+            // no other candidate it is redundant and the artificial variable stays at zero.
+            for i in 0..m {
+                if basis[i] < n + m {
+                    continue;
+                }
+                if let Some(j) = (0..n + m).find(|j| !basis.contains(j) && tableau[i][*j].abs() > EPSILON) {
+                    pivot(&mut tableau, &mut basis, i, j);
+                }
+            }
+        }
+
+        let cost: Vec<f64> = (0..width).map(|j| if j < n { self.c[j] } else { 0.0 }).collect();
+        run_simplex(&mut tableau, &mut basis, &cost, n + m)?;
+
+        let mut x = vec![0.0; n];
+        for i in 0..m {
+            if basis[i] < n {
+                x[basis[i]] = tableau[i][width];
+            }
+        }
+        let objective = (0..n).fold(0.0, |sum, j| sum + self.c[j] * x[j]);
+        let duals = (0..m).map(|s| {
+            (0..m).fold(0.0, |sum, i| sum + cost[basis[i]] * tableau[i][n + s])
+        }).collect();
+
+        Ok(SimplexSolution {
+            x,
+            objective,
+            duals,
+        })
+    }
+}
+
+/// Maximises the given cost over the tableau, only letting columns below limit enter the basis
+fn run_simplex(tableau: &mut [Vec<f64>], basis: &mut [usize], cost: &[f64], limit: usize) -> Result<(), MatholError> {
+    let rhs = cost.len();
+
+    loop {
+        // Bland's rule: the entering variable is the lowest index with a positive reduced cost
+        let entering = (0..limit).find(|j| {
+            if basis.contains(j) {
+                return false;
+            }
+            let reduced = (0..tableau.len()).fold(cost[*j], |r, i| r - cost[basis[i]] * tableau[i][*j]);
+            reduced > EPSILON
+        });
+        let j = match entering {
+            Some(j) => j,
+            None => return Ok(()),
+        };
+
+        // Ratio test, ties are broken by the lowest index of the leaving variable
+        let mut leaving: Option<(usize, f64)> = None;
+        for i in 0..tableau.len() {
+            if tableau[i][j] <= EPSILON {
+                continue;
+            }
+            let ratio = tableau[i][rhs] / tableau[i][j];
+            leaving = match leaving {
+                Some((p, best)) if ratio > best + EPSILON || (ratio > best - EPSILON && basis[p] < basis[i]) => Some((p, best)),
+                _ => Some((i, ratio)),
+            };
+        }
+
+        match leaving {
+            Some((i, _)) => pivot(tableau, basis, i, j),
+            None => return Err(MatholError::UnboundedCause(UnboundedError {
+                message: "The linear program is unbounded".to_string(),
+            })),
+        }
+    }
+}
+
+/// Pivots the tableau on the element at the given row and column
+fn pivot(tableau: &mut [Vec<f64>], basis: &mut [usize], row: usize, column: usize) {
+    let p = tableau[row][column];
+    for e in tableau[row].iter_mut() {
+        *e /= p;
+    }
+
+    let pivot_row = tableau[row].clone();
+    for (i, r) in tableau.iter_mut().enumerate() {
+        if i == row || r[column] == 0.0 {
+            continue;
+        }
+        let factor = r[column];
+        for (e, q) in r.iter_mut().zip(pivot_row.iter()) {
+            *e -= factor * q;
+        }
+    }
+
+    basis[row] = column;
+}
@@ -0,0 +1,219 @@
+use num::{Num, FromPrimitive};
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use std::fmt::Debug;
+use std::f64::consts::PI;
+use vectoroperations::vector2d::Vector2D;
+use error::*;
+
+/// A struct for a parametric representation of a line in two-dimensional space
+#[derive(Debug, Copy, Clone)]
+pub struct Line2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    /// The support vector (a point on the line)
+    pub r: Vector2D<T>,
+    /// The direction vector of the line
+    pub a: Vector2D<T>,
+}
+
+impl<T> Line2D<T>
+    where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+{
+    /// Builds a line that goes through two given points p and q
+    /// # Remarks
+    /// Returns a line with p as support vector and q - p as direction vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let line = Line2D::build_line_from_two_points(Vector2D::build_vector(-1, 5), Vector2D::build_vector(1, -3));
+    /// assert_eq!(Vector2D::build_vector(-1, 5), line.r);
+    /// assert_eq!(Vector2D::build_vector(2, -8), line.a);
+    /// ```
+    pub fn build_line_from_two_points(p: Vector2D<T>, q: Vector2D<T>) -> Line2D<T> {
+        Line2D {
+            r: p,
+            a: q.sub_vector(p),
+        }
+    }
+
+    /// Builds the line y = mx + b
+    /// # Remarks
+    /// Returns a line with (0 | b) as support vector and (1 | m) as direction vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let line = Line2D::build_line_from_slope_intercept(2, -1);
+    /// assert_eq!(Vector2D::build_vector(0, -1), line.r);
+    /// assert_eq!(Vector2D::build_vector(1, 2), line.a);
+    /// ```
+    pub fn build_line_from_slope_intercept(m: T, b: T) -> Line2D<T> {
+        Line2D {
+            r: Vector2D::build_vector(T::zero(), b),
+            a: Vector2D::build_vector(T::one(), m),
+        }
+    }
+
+    /// Builds the line ax + by + c = 0
+    /// # Remarks
+    /// The direction vector of the resulting line is (b | -a)
+    /// # Return values
+    /// Returns the line in case of success
+    ///
+    /// Returns VectorError if a and b are both zero
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let line = Line2D::build_line_from_general_form(2, 4, -8).unwrap();
+    /// assert_eq!(Vector2D::build_vector(0.0, 2.0), line.r);
+    /// assert_eq!(Vector2D::build_vector(4.0, -2.0), line.a);
+    /// ```
+    pub fn build_line_from_general_form(a: T, b: T, c: T) -> Result<Line2D<f64>, MatholError> {
+        let (a, b, c) = (a.to_f64(), b.to_f64(), c.to_f64());
+        let r = if b != 0.0 {
+            Vector2D::build_vector(0.0, -c / b)
+        } else if a != 0.0 {
+            Vector2D::build_vector(-c / a, 0.0)
+        } else {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The coefficients a and b must not both be zero".to_string(),
+            }));
+        };
+
+        Ok(Line2D {
+            r,
+            a: Vector2D::build_vector(b, -a),
+        })
+    }
+
+    /// Calculates the general form ax + by + c = 0 of a line
+    /// # Remarks
+    /// Returns the coefficients as tuple (a, b, c), where (a | b) is a normal vector of the line
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let line = Line2D::build_line_from_two_points(Vector2D::build_vector(0, 2), Vector2D::build_vector(4, 0));
+    /// assert_eq!((-2, -4, 8), line.get_general_form());
+    /// ```
+    pub fn get_general_form(self) -> (T, T, T) {
+        let a = self.a.y;
+        let b = T::zero() - self.a.x;
+        (a, b, T::zero() - (a * self.r.x + b * self.r.y))
+    }
+
+    /// Calculates slope and intercept of the line y = mx + b
+    /// # Return values
+    /// Returns the tuple (m, b) in case of success
+    ///
+    /// Returns VectorError if the line is vertical
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let line = Line2D::build_line_from_two_points(Vector2D::build_vector(1, 1), Vector2D::build_vector(3, 2));
+    /// assert_eq!(Ok((0.5, 0.5)), line.get_slope_intercept());
+    /// ```
+    pub fn get_slope_intercept(self) -> Result<(f64, f64), MatholError> {
+        if self.a.x == T::zero() {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "A vertical line has no slope".to_string(),
+            }));
+        }
+
+        let m = self.a.y.to_f64() / self.a.x.to_f64();
+        Ok((m, self.r.y.to_f64() - m * self.r.x.to_f64()))
+    }
+
+    /// Calculates the distance between a point and a line
+    /// # Remarks
+    /// Returns the distance as f64 value
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let line = Line2D {r: Vector2D::build_vector(1, 1), a: Vector2D::build_vector(3, 4)};
+    /// assert_eq!(5.0, line.distance_from_point(Vector2D::build_vector(5, -2)));
+    /// ```
+    pub fn distance_from_point(self, p: Vector2D<T>) -> f64 {
+        let r = p.sub_vector(self.r);
+        self.a.get_vector_product(r).to_f64().abs() / self.a.get_length()
+    }
+
+    /// Checks if two lines are parallel to each other
+    /// # Remarks
+    /// Identical lines are parallel, too
+    pub fn are_parallel(self, l: Line2D<T>) -> bool {
+        self.a.get_vector_product(l.a) == T::zero()
+    }
+
+    /// Checks if two lines are perpendicular to each other
+    pub fn are_perpendicular(self, l: Line2D<T>) -> bool {
+        self.a.get_scalar_product(l.a) == T::zero()
+    }
+
+    /// Calculates the intersection point of two lines
+    /// # Return values
+    /// Returns the intersection point in case of success
+    ///
+    /// Returns VectorError if the lines are parallel
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let l1 = Line2D::build_line_from_slope_intercept(1, 0);
+    /// let l2 = Line2D {r: Vector2D::build_vector(0, 4), a: Vector2D::build_vector(1, -1)};
+    /// assert_eq!(Ok(Vector2D::build_vector(2.0, 2.0)), l1.intersection(l2));
+    /// ```
+    pub fn intersection(self, l: Line2D<T>) -> Result<Vector2D<f64>, MatholError> {
+        if self.are_parallel(l) {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The lines are parallel".to_string(),
+            }));
+        }
+
+        let t = l.r.sub_vector(self.r).get_vector_product(l.a).to_f64() / self.a.get_vector_product(l.a).to_f64();
+        Ok(self.r.convert_to_f64() + self.a.convert_to_f64() * t)
+    }
+
+    /// Calculates the angle between two lines
+    /// # Remarks
+    /// Returns the smaller of the two angles at the intersection as radian value,
+    /// so the result lies in the range from 0 to π/2
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line2d::Line2D;
+    /// use std::f64::consts::FRAC_PI_4;
+    ///
+    /// let l1 = Line2D::build_line_from_slope_intercept(0, 3);
+    /// let l2 = Line2D::build_line_from_slope_intercept(-1, 0);
+    /// assert_eq!(FRAC_PI_4, l1.get_cut_angle(l2));
+    /// ```
+    pub fn get_cut_angle(self, l: Line2D<T>) -> f64 {
+        let angle = self.a.angle_between(l.a);
+        angle.min(PI - angle)
+    }
+}
+
+impl<T> ApproxEq for Line2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive + ApproxEq
+{
+    /// Compares support and direction vector of two lines
+    /// # Remarks
+    /// Two lines with different support or direction vectors are not approximately equal, even if
+    /// they describe the same set of points
+    fn approx_eq(&self, other: &Line2D<T>, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance) && self.a.approx_eq(&other.a, tolerance)
+    }
+}
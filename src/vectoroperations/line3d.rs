@@ -1,10 +1,13 @@
 use num::Num;
 use basics::convert_trait::Convert;
 use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
 use std::fmt::Debug;
 use vectoroperations::vector3d::Vector3D;
 use error::*;
 
+const EPSILON: f64 = 1e-10;
+
 /// A struct for a parametric representation of a line in three-dimensional space
 #[derive(Debug, Copy, Clone)]
 pub struct Line3D<T>
@@ -153,4 +156,121 @@ impl<T> Line3D<T>
             }));
         }
     }
-}
\ No newline at end of file
+
+    /// Calculates the foot of the perpendicular from a point onto the line
+    /// # Remarks
+    /// Returns the point of the line that is nearest to the given point
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line3d::Line3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let l = Line3D {r: Vector3D::build_vector(1, 1, 0), a: Vector3D::build_vector(0, 2, 0)};
+    /// assert_eq!(Vector3D::build_vector(1.0, 4.0, 0.0), l.foot_of_perpendicular(Vector3D::build_vector(3, 4, 5)));
+    /// ```
+    pub fn foot_of_perpendicular(self, p: Vector3D<T>) -> Vector3D<f64> {
+        let a = self.a.convert_to_f64();
+        let t = p.sub_vector(self.r).convert_to_f64().get_scalar_product(a) / a.get_scalar_product(a);
+        self.r.convert_to_f64() + a * t
+    }
+
+    /// Calculates the points of two lines that are nearest to each other
+    /// # Remarks
+    /// For skew lines the connection of the two points stands perpendicular on both lines,
+    /// for crossing lines both points are the intersection point
+    /// # Return values
+    /// Returns the closest points and their parameters in case of success
+    ///
+    /// Returns VectorError if the lines are parallel, as the closest points are not unique then
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line3d::Line3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let l1 = Line3D {r: Vector3D::build_vector(0, 0, 0), a: Vector3D::build_vector(1, 0, 0)};
+    /// let l2 = Line3D {r: Vector3D::build_vector(2, -1, 3), a: Vector3D::build_vector(0, 1, 0)};
+    /// let closest = l1.closest_points(l2).unwrap();
+    /// assert_eq!(Vector3D::build_vector(2.0, 0.0, 0.0), closest.p);
+    /// assert_eq!(Vector3D::build_vector(2.0, 0.0, 3.0), closest.q);
+    /// assert_eq!((2.0, 1.0), (closest.s, closest.t));
+    /// ```
+    pub fn closest_points(self, l: Line3D<T>) -> Result<ClosestPoints, MatholError> {
+        if self.are_parallel(l) {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "Lines are parallel".to_string(),
+            }));
+        }
+
+        let (u, v) = (self.a.convert_to_f64(), l.a.convert_to_f64());
+        let w = self.r.sub_vector(l.r).convert_to_f64();
+        let (a, b, c) = (u.get_scalar_product(u), u.get_scalar_product(v), v.get_scalar_product(v));
+        let (d, e) = (u.get_scalar_product(w), v.get_scalar_product(w));
+        let denominator = a * c - b * b;
+        let s = (b * e - c * d) / denominator;
+        let t = (a * e - b * d) / denominator;
+
+        Ok(ClosestPoints {
+            p: self.r.convert_to_f64() + u * s,
+            q: l.r.convert_to_f64() + v * t,
+            s,
+            t,
+        })
+    }
+
+    /// Calculates the intersection point of two lines
+    /// # Remarks
+    /// The lines are considered crossing if the distance of their closest points is negligible
+    /// compared to the magnitude of the involved vectors
+    /// # Return values
+    /// Returns the intersection point in case of success
+    ///
+    /// Returns VectorError if the lines are parallel or skew
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::line3d::Line3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let l1 = Line3D {r: Vector3D::build_vector(1, 1, 0), a: Vector3D::build_vector(2, 1, 1)};
+    /// let l2 = Line3D {r: Vector3D::build_vector(2, 0, 2), a: Vector3D::build_vector(1, -1, 2)};
+    /// assert_eq!(Ok(Vector3D::build_vector(1.0, 1.0, 0.0)), l1.intersection(l2));
+    /// ```
+    pub fn intersection(self, l: Line3D<T>) -> Result<Vector3D<f64>, MatholError> {
+        let closest = self.closest_points(l)?;
+        let scale = [closest.p, closest.q, self.r.convert_to_f64(), l.r.convert_to_f64(), self.a.convert_to_f64(), l.a.convert_to_f64()]
+            .iter()
+            .fold(0.0_f64, |max, v| max.max(v.get_length()));
+
+        if closest.p.get_distance(closest.q) > EPSILON * scale {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "Lines are skew".to_string(),
+            }));
+        }
+
+        Ok(closest.p)
+    }
+}
+
+/// The result of Line3D::closest_points
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ClosestPoints {
+    /// The point on the first line
+    pub p: Vector3D<f64>,
+    /// The point on the second line
+    pub q: Vector3D<f64>,
+    /// The parameter of p, so p = r + s * a for the first line
+    pub s: f64,
+    /// The parameter of q, so q = r + t * a for the second line
+    pub t: f64,
+}
+
+impl<T> ApproxEq for Line3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd + PartialEq + ApproxEq
+{
+    /// Compares support and direction vector of two lines
+    /// # Remarks
+    /// Two lines with different support or direction vectors are not approximately equal, even if
+    /// they describe the same set of points
+    fn approx_eq(&self, other: &Line3D<T>, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance) && self.a.approx_eq(&other.a, tolerance)
+    }
+}
@@ -1,4 +1,10 @@
 pub mod vector2d;
 pub mod vector3d;
+pub mod vectorn;
+pub mod line2d;
+pub mod quaternion;
+pub mod transform;
 pub mod line3d;
-pub mod plane;
\ No newline at end of file
+pub mod plane;
+pub mod segment;
+pub mod ray;
\ No newline at end of file
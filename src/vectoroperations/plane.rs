@@ -1,7 +1,9 @@
 use num::Num;
 use std::fmt::Debug;
+use std::f64::consts::{PI, FRAC_PI_2};
 use basics::convert_trait::Convert;
 use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
 use vectoroperations::vector3d::Vector3D;
 use vectoroperations::line3d::Line3D;
 use error::*;
@@ -209,4 +211,239 @@ impl<T> Plane<T>
 
         Ok(l.r.add_vector(l.a.multiply_with_scalar(a / b)))
     }
-}
\ No newline at end of file
+
+    /// Builds a plane from its parametric form x = r + λa + μb
+    /// # Remarks
+    /// The normal vector of the resulting plane is a × b
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let plane = Plane::build_plane_from_parametric_form(Vector3D::build_vector(1, 2, 3), Vector3D::build_vector(1, 0, 0), Vector3D::build_vector(0, 2, 0));
+    /// assert_eq!(Vector3D::build_vector(0, 0, 2), plane.n);
+    /// ```
+    pub fn build_plane_from_parametric_form(r: Vector3D<T>, a: Vector3D<T>, b: Vector3D<T>) -> Plane<T> {
+        Plane {
+            r,
+            n: a.get_vector_product(b),
+        }
+    }
+
+    /// Builds a plane from its coordinate form ax + by + cz = d
+    /// # Remarks
+    /// The support vector of the resulting plane is the point of the plane nearest to the origin
+    /// # Return values
+    /// Returns the plane in case of success
+    ///
+    /// Returns VectorError if a, b and c are all zero
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let plane = Plane::build_plane_from_coordinate_form(0, 0, 2, 6).unwrap();
+    /// assert_eq!(Vector3D::build_vector(0.0, 0.0, 3.0), plane.r);
+    /// assert_eq!(Vector3D::build_vector(0.0, 0.0, 2.0), plane.n);
+    /// ```
+    pub fn build_plane_from_coordinate_form(a: T, b: T, c: T, d: T) -> Result<Plane<f64>, MatholError> {
+        let n = Vector3D::build_vector(a.to_f64(), b.to_f64(), c.to_f64());
+        let squared_length = n.get_scalar_product(n);
+        if squared_length == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The coefficients a, b and c must not all be zero".to_string(),
+            }));
+        }
+
+        Ok(Plane {
+            r: n * (d.to_f64() / squared_length),
+            n,
+        })
+    }
+
+    /// Calculates the parametric form x = r + λa + μb of the plane
+    /// # Remarks
+    /// Returns the tuple (r, a, b). The direction vectors a and b are perpendicular to each other
+    /// and to the normal vector, but they are not normalized.
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let plane = Plane::build_plane_with_vectors(Vector3D::build_vector(1, 1, 1), Vector3D::build_vector(1, 2, 2));
+    /// let (r, a, b) = plane.get_parametric_form();
+    /// assert_eq!(Vector3D::build_vector(1, 1, 1), r);
+    /// assert_eq!(0, a.get_scalar_product(plane.n));
+    /// assert_eq!(0, b.get_scalar_product(plane.n));
+    /// assert_eq!(0, a.get_scalar_product(b));
+    /// ```
+    pub fn get_parametric_form(self) -> (Vector3D<T>, Vector3D<T>, Vector3D<T>) {
+        let n = self.n;
+        let (x, y, z) = (n.x.get_amount(), n.y.get_amount(), n.z.get_amount());
+        let e = if x <= y && x <= z {
+            Vector3D::build_vector(T::one(), T::zero(), T::zero())
+        } else if y <= z {
+            Vector3D::build_vector(T::zero(), T::one(), T::zero())
+        } else {
+            Vector3D::build_vector(T::zero(), T::zero(), T::one())
+        };
+
+        let a = n.get_vector_product(e);
+        (self.r, a, n.get_vector_product(a))
+    }
+
+    /// Calculates the coordinate form ax + by + cz = d of the plane
+    /// # Remarks
+    /// Returns the coefficients as tuple (a, b, c, d), where (a | b | c) is the normal vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let plane = Plane::build_plane_with_vectors(Vector3D::build_vector(1, 1, 2), Vector3D::build_vector(2, 1, 1));
+    /// assert_eq!((2, 1, 1, 5), plane.get_coordinate_form());
+    /// ```
+    pub fn get_coordinate_form(self) -> (T, T, T, T) {
+        (self.n.x, self.n.y, self.n.z, self.n.get_scalar_product(self.r))
+    }
+
+    /// Calculates the Hesse normal form n₀ · x = d of the plane
+    /// # Remarks
+    /// Returns the tuple (n₀, d), where n₀ is the unit normal vector pointing away from the origin
+    /// and d is the distance of the plane from the origin
+    /// # Return values
+    /// Returns the Hesse normal form in case of success
+    ///
+    /// Returns VectorError if the normal vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let plane = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, -2), Vector3D::build_vector(0, 0, 4));
+    /// let (n, d) = plane.get_hesse_normal_form().unwrap();
+    /// assert_eq!(Vector3D::build_vector(0.0, 0.0, -1.0), n);
+    /// assert_eq!(2.0, d);
+    /// ```
+    pub fn get_hesse_normal_form(self) -> Result<(Vector3D<f64>, f64), MatholError> {
+        let n = self.n.normalize()?;
+        let d = n.get_scalar_product(self.r.convert_to_f64());
+        if d < 0.0 {
+            Ok((n * -1.0, -d))
+        } else {
+            Ok((n, d))
+        }
+    }
+
+    /// Calculates the line where the plane cuts through another plane
+    /// # Return values
+    /// Returns the line of intersection in case of success. Its direction vector is the vector
+    /// product of both normal vectors.
+    ///
+    /// Returns VectorError if the planes are parallel
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let p = Plane::build_plane_with_vectors(Vector3D::build_vector(1, 0, 0), Vector3D::build_vector(1, 0, 0));
+    /// let q = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 2, 0), Vector3D::build_vector(0, 1, 0));
+    /// let l = p.intersection_with_plane(q).unwrap();
+    /// assert_eq!(Vector3D::build_vector(1.0, 2.0, 0.0), l.r);
+    /// assert_eq!(Vector3D::build_vector(0.0, 0.0, 1.0), l.a);
+    /// ```
+    pub fn intersection_with_plane(self, p: Plane<T>) -> Result<Line3D<f64>, MatholError> {
+        if self.is_parallel_to_plane(p) {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The planes are parallel".to_string(),
+            }));
+        }
+
+        let (n1, n2) = (self.n.convert_to_f64(), p.n.convert_to_f64());
+        let (d1, d2) = (n1.get_scalar_product(self.r.convert_to_f64()), n2.get_scalar_product(p.r.convert_to_f64()));
+        let u = n1.get_vector_product(n2);
+        let r = (n2.get_vector_product(u) * d1 + u.get_vector_product(n1) * d2) / u.get_scalar_product(u);
+
+        Ok(Line3D {r, a: u})
+    }
+
+    /// Calculates the point where three planes cut through each other
+    /// # Return values
+    /// Returns the point in case of success
+    ///
+    /// Returns VectorError if the planes do not meet in a single point, which happens if two of
+    /// them are parallel or all of them are parallel to one line
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let p = Plane::build_plane_with_vectors(Vector3D::build_vector(1, 0, 0), Vector3D::build_vector(1, 0, 0));
+    /// let q = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 2, 0), Vector3D::build_vector(0, 1, 0));
+    /// let r = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 3), Vector3D::build_vector(0, 0, 1));
+    /// assert_eq!(Ok(Vector3D::build_vector(1.0, 2.0, 3.0)), p.intersection_of_three_planes(q, r));
+    /// ```
+    pub fn intersection_of_three_planes(self, p: Plane<T>, q: Plane<T>) -> Result<Vector3D<f64>, MatholError> {
+        let (n1, n2, n3) = (self.n.convert_to_f64(), p.n.convert_to_f64(), q.n.convert_to_f64());
+        let denominator = n1.get_triple_product(n2, n3);
+        if denominator == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The planes do not cut in a single point".to_string(),
+            }));
+        }
+
+        let d1 = n1.get_scalar_product(self.r.convert_to_f64());
+        let d2 = n2.get_scalar_product(p.r.convert_to_f64());
+        let d3 = n3.get_scalar_product(q.r.convert_to_f64());
+        Ok((n2.get_vector_product(n3) * d1 + n3.get_vector_product(n1) * d2 + n1.get_vector_product(n2) * d3) / denominator)
+    }
+
+    /// Calculates the angle between two planes
+    /// # Remarks
+    /// Returns the smaller angle between the planes as radian value in the range from 0 to π/2
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use std::f64::consts::FRAC_PI_4;
+    ///
+    /// let p = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(0, 0, 1));
+    /// let q = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(0, -1, 1));
+    /// assert!((FRAC_PI_4 - p.get_angle_with_plane(q)).abs() < 1e-15);
+    /// ```
+    pub fn get_angle_with_plane(self, p: Plane<T>) -> f64 {
+        let angle = self.n.angle_between(p.n);
+        angle.min(PI - angle)
+    }
+
+    /// Calculates the angle between the plane and a line
+    /// # Remarks
+    /// Returns the angle between the line and its projection onto the plane as radian value
+    /// in the range from 0 to π/2
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::line3d::Line3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let p = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(0, 0, 1));
+    /// let l = Line3D {r: Vector3D::build_vector(1, 1, 1), a: Vector3D::build_vector(0, 0, -3)};
+    /// assert_eq!(FRAC_PI_2, p.get_angle_with_line(l));
+    /// ```
+    pub fn get_angle_with_line(self, l: Line3D<T>) -> f64 {
+        (FRAC_PI_2 - self.n.angle_between(l.a)).abs()
+    }
+}
+
+impl<T> ApproxEq for Plane<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd + PartialEq + ApproxEq
+{
+    /// Compares support and normal vector of two planes
+    /// # Remarks
+    /// Two planes with different support or normal vectors are not approximately equal, even if
+    /// they describe the same set of points
+    fn approx_eq(&self, other: &Plane<T>, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance) && self.n.approx_eq(&other.n, tolerance)
+    }
+}
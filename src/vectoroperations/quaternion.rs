@@ -0,0 +1,330 @@
+use num::Num;
+use std::fmt::Debug;
+use std::ops::Mul;
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use vectoroperations::vector3d::Vector3D;
+use matrices::matrice::Matrice;
+use error::*;
+
+const EPSILON: f64 = 1e-9;
+
+/// Order of the axes for the construction of a quaternion from Euler angles
+/// # Remarks
+/// The rotations are intrinsic, so every rotation turns about an axis of the already rotated
+/// coordinate system. XYZ therefore rotates about x first, then about the new y axis and at
+/// last about the new z axis. This is the same as extrinsic rotations about the fixed axes in
+/// the reversed order Z, Y, X.
+///
+/// The first six orders are Tait-Bryan angles, the remaining six are proper Euler angles.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+/// Struct for quaternions w + xi + yj + zk
+/// # Remarks
+/// Unit quaternions describe rotations in the three-dimensional space
+/// # Usage
+/// ```
+/// use mathol::vectoroperations::quaternion::Quaternion;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    /// Takes the real part w and the imaginary parts x, y and z and returns a Quaternion instance
+    pub fn build_quaternion(w: f64, x: f64, y: f64, z: f64) -> Quaternion {
+        Quaternion {w, x, y, z}
+    }
+
+    /// Returns the identity quaternion, which describes no rotation at all
+    pub fn build_identity_quaternion() -> Quaternion {
+        Quaternion::build_quaternion(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates the unit quaternion for a rotation about an axis through the origin
+    /// # Parameters
+    /// axis: The direction of the axis, it does not have to be a unit vector
+    ///
+    /// angle: The angle of rotation as radian value, counterclockwise when looking against the axis
+    /// # Return values
+    /// Returns the quaternion in case of success
+    ///
+    /// Returns VectorError if the axis is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::Quaternion;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use std::f64::consts::PI;
+    ///
+    /// let q = Quaternion::build_from_axis_angle(Vector3D::build_vector(0, 0, 2), PI).unwrap();
+    /// assert!(q.w.abs() < 1e-15);
+    /// assert_eq!(1.0, q.z);
+    /// ```
+    pub fn build_from_axis_angle<T>(axis: Vector3D<T>, angle: f64) -> Result<Quaternion, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let n = axis.normalize()?;
+        let s = (angle / 2.0).sin();
+        Ok(Quaternion::build_quaternion((angle / 2.0).cos(), n.x * s, n.y * s, n.z * s))
+    }
+
+    /// Creates the unit quaternion for a sequence of three rotations about coordinate axes
+    /// # Parameters
+    /// alpha, beta, gamma: The angles as radian values in the order of the axes
+    ///
+    /// order: The order of the axes, see EulerOrder
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::{Quaternion, EulerOrder};
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let q = Quaternion::build_from_euler_angles(FRAC_PI_2, 0.0, 0.0, EulerOrder::ZYX);
+    /// let r = Quaternion::build_from_axis_angle(Vector3D::build_vector(0, 0, 1), FRAC_PI_2).unwrap();
+    /// assert_eq!(r, q);
+    /// ```
+    pub fn build_from_euler_angles(alpha: f64, beta: f64, gamma: f64, order: EulerOrder) -> Quaternion {
+        let axes = match order {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        };
+
+        let elementary = |axis: usize, angle: f64| {
+            let mut v = [0.0; 3];
+            v[axis] = (angle / 2.0).sin();
+            Quaternion::build_quaternion((angle / 2.0).cos(), v[0], v[1], v[2])
+        };
+
+        elementary(axes[0], alpha) * elementary(axes[1], beta) * elementary(axes[2], gamma)
+    }
+
+    /// Calculates the Hamilton product of two quaternions
+    /// # Remarks
+    /// The product is not commutative. For rotations, self * other first rotates by other and
+    /// then by self. The product is also available with the * operator.
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::Quaternion;
+    ///
+    /// let i = Quaternion::build_quaternion(0.0, 1.0, 0.0, 0.0);
+    /// let j = Quaternion::build_quaternion(0.0, 0.0, 1.0, 0.0);
+    /// assert_eq!(Quaternion::build_quaternion(0.0, 0.0, 0.0, 1.0), i.get_hamilton_product(j));
+    /// assert_eq!(Quaternion::build_quaternion(0.0, 0.0, 0.0, -1.0), j * i);
+    /// ```
+    pub fn get_hamilton_product(self, other: Quaternion) -> Quaternion {
+        Quaternion {
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        }
+    }
+
+    /// Returns the conjugate quaternion w - xi - yj - zk
+    pub fn get_conjugate(self) -> Quaternion {
+        Quaternion::build_quaternion(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Calculates the norm of a quaternion
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::Quaternion;
+    ///
+    /// assert_eq!(5.0, Quaternion::build_quaternion(1.0, 2.0, 2.0, 4.0).get_norm());
+    /// ```
+    pub fn get_norm(self) -> f64 {
+        self.get_squared_norm().sqrt()
+    }
+
+    /// Calculates the unit quaternion with the same direction
+    /// # Return values
+    /// Returns the unit quaternion in case of success
+    ///
+    /// Returns VectorError if the quaternion is zero
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::build_quaternion(0.0, 3.0, 0.0, 4.0).normalize().unwrap();
+    /// assert_eq!(Quaternion::build_quaternion(0.0, 0.6, 0.0, 0.8), q);
+    /// ```
+    pub fn normalize(self) -> Result<Quaternion, MatholError> {
+        let norm = self.get_norm();
+        if norm == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The zero quaternion cannot be normalized".to_string(),
+            }));
+        }
+
+        Ok(Quaternion::build_quaternion(self.w / norm, self.x / norm, self.y / norm, self.z / norm))
+    }
+
+    /// Calculates the inverse quaternion
+    /// # Remarks
+    /// For unit quaternions the inverse equals the conjugate
+    /// # Return values
+    /// Returns the inverse quaternion in case of success
+    ///
+    /// Returns VectorError if the quaternion is zero
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::build_quaternion(1.0, 1.0, 1.0, 1.0);
+    /// assert_eq!(Quaternion::build_quaternion(0.25, -0.25, -0.25, -0.25), q.get_inverse().unwrap());
+    /// assert_eq!(Quaternion::build_identity_quaternion(), q * q.get_inverse().unwrap());
+    /// ```
+    pub fn get_inverse(self) -> Result<Quaternion, MatholError> {
+        let squared_norm = self.get_squared_norm();
+        if squared_norm == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The zero quaternion has no inverse".to_string(),
+            }));
+        }
+
+        let c = self.get_conjugate();
+        Ok(Quaternion::build_quaternion(c.w / squared_norm, c.x / squared_norm, c.y / squared_norm, c.z / squared_norm))
+    }
+
+    /// Converts a unit quaternion to a 3x3 rotation matrice
+    /// # Remarks
+    /// The quaternion is expected to be a unit quaternion
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::Quaternion;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let q = Quaternion::build_from_axis_angle(Vector3D::build_vector(0, 0, 1), FRAC_PI_2).unwrap();
+    /// let m = q.to_rotation_matrice();
+    /// assert!((m.get_element(0, 1).unwrap() + 1.0).abs() < 1e-15);
+    /// assert!((m.get_element(1, 0).unwrap() - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn to_rotation_matrice(self) -> Matrice<f64> {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        let data = vec![
+            1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y),
+            2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x),
+            2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y),
+        ];
+
+        Matrice::build_matrice(3, 3, data).unwrap()
+    }
+
+    /// Rotates a vector with a unit quaternion
+    /// # Remarks
+    /// The quaternion is expected to be a unit quaternion. The result equals q * v * q⁻¹,
+    /// where v is the vector as pure quaternion.
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::Quaternion;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use mathol::basics::approx_trait::ApproxEq;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let q = Quaternion::build_from_axis_angle(Vector3D::build_vector(0, 0, 1), FRAC_PI_2).unwrap();
+    /// let v = q.rotate(Vector3D::build_vector(1, 0, 3));
+    /// assert!(v.abs_diff_eq(&Vector3D::build_vector(0.0, 1.0, 3.0), 1e-15));
+    /// ```
+    pub fn rotate<T>(self, vec: Vector3D<T>) -> Vector3D<f64>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let v = vec.convert_to_f64();
+        let u = Vector3D::build_vector(self.x, self.y, self.z);
+        let t = u.get_vector_product(v) * 2.0;
+        v + t * self.w + u.get_vector_product(t)
+    }
+
+    /// Interpolates spherically between two unit quaternions
+    /// # Remarks
+    /// The interpolation always takes the shorter way, so the rotation angle changes with constant
+    /// angular velocity from self to other. Nearly identical rotations are interpolated linearly.
+    /// # Parameters
+    /// other: The quaternion reached for t = 1
+    ///
+    /// t: The interpolation parameter, the quaternion itself is returned for t = 0
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::quaternion::Quaternion;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use mathol::basics::approx_trait::ApproxEq;
+    /// use std::f64::consts::{PI, FRAC_PI_2};
+    ///
+    /// let axis = Vector3D::build_vector(1, 1, 0);
+    /// let p = Quaternion::build_identity_quaternion();
+    /// let q = Quaternion::build_from_axis_angle(axis, PI).unwrap();
+    /// let expected = Quaternion::build_from_axis_angle(axis, FRAC_PI_2).unwrap();
+    /// assert!(p.slerp(q, 0.5).abs_diff_eq(&expected, 1e-15));
+    /// ```
+    pub fn slerp(self, other: Quaternion, t: f64) -> Quaternion {
+        let mut cos_omega = self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z;
+        let mut end = other;
+        if cos_omega < 0.0 {
+            cos_omega = -cos_omega;
+            end = Quaternion::build_quaternion(-other.w, -other.x, -other.y, -other.z);
+        }
+
+        let (s, e) = if 1.0 - cos_omega < EPSILON {
+            (1.0 - t, t)
+        } else {
+            let omega = cos_omega.min(1.0).acos();
+            (((1.0 - t) * omega).sin() / omega.sin(), (t * omega).sin() / omega.sin())
+        };
+
+        let result = Quaternion {
+            w: s * self.w + e * end.w,
+            x: s * self.x + e * end.x,
+            y: s * self.y + e * end.y,
+            z: s * self.z + e * end.z,
+        };
+        result.normalize().unwrap_or(result)
+    }
+
+    fn get_squared_norm(self) -> f64 {
+        self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: Quaternion) -> Quaternion {
+        self.get_hamilton_product(other)
+    }
+}
+
+impl ApproxEq for Quaternion {
+    fn approx_eq(&self, other: &Quaternion, tolerance: Tolerance) -> bool {
+        self.w.approx_eq(&other.w, tolerance) && self.x.approx_eq(&other.x, tolerance)
+            && self.y.approx_eq(&other.y, tolerance) && self.z.approx_eq(&other.z, tolerance)
+    }
+}
@@ -0,0 +1,224 @@
+use num::Num;
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use std::fmt::Debug;
+use vectoroperations::vector3d::Vector3D;
+use vectoroperations::plane::Plane;
+
+const EPSILON: f64 = 1e-12;
+
+/// The point where a ray hits an object
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RayHit {
+    /// The hit point
+    pub point: Vector3D<f64>,
+    /// The parameter of the hit point, so point = r + t * a
+    pub t: f64,
+}
+
+/// A struct for a ray in three-dimensional space
+/// # Remarks
+/// The points of the ray are r + t * a with t ≥ 0. The direction vector does not have to be a
+/// unit vector, but then t is not the distance from the origin of the ray.
+#[derive(Debug, Copy, Clone)]
+pub struct Ray3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd + PartialEq
+{
+    /// The origin of the ray
+    pub r: Vector3D<T>,
+    /// The direction vector of the ray
+    pub a: Vector3D<T>,
+}
+
+impl<T> Ray3D<T>
+    where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd + PartialEq
+{
+    /// Builds a ray with the given origin and direction
+    pub fn build_ray(r: Vector3D<T>, a: Vector3D<T>) -> Ray3D<T> {
+        Ray3D {r, a}
+    }
+
+    /// Calculates the point r + t * a of the ray
+    pub fn get_point_at(self, t: f64) -> Vector3D<f64> {
+        self.r.convert_to_f64() + self.a.convert_to_f64() * t
+    }
+
+    /// Calculates where the ray hits a plane
+    /// # Return values
+    /// Returns None if the ray is parallel to the plane or points away from it
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::ray::Ray3D;
+    /// use mathol::vectoroperations::plane::Plane;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let ray = Ray3D::build_ray(Vector3D::build_vector(1, 2, 5), Vector3D::build_vector(0, 0, -2));
+    /// let plane = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 1), Vector3D::build_vector(0, 0, 1));
+    /// let hit = ray.intersect_plane(plane).unwrap();
+    /// assert_eq!(Vector3D::build_vector(1.0, 2.0, 1.0), hit.point);
+    /// assert_eq!(2.0, hit.t);
+    /// ```
+    pub fn intersect_plane(self, plane: Plane<T>) -> Option<RayHit> {
+        let n = plane.n.convert_to_f64();
+        let a = self.a.convert_to_f64();
+        let denominator = n.get_scalar_product(a);
+        if denominator.abs() <= EPSILON * n.get_length() * a.get_length() {
+            return None;
+        }
+
+        let t = n.get_scalar_product(plane.r.convert_to_f64() - self.r.convert_to_f64()) / denominator;
+        self.hit_at(t)
+    }
+
+    /// Calculates where the ray hits the triangle with the corners p, q and s
+    /// # Remarks
+    /// Uses the Möller-Trumbore algorithm. Both sides of the triangle are hit.
+    /// # Return values
+    /// Returns None if the ray misses the triangle
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::ray::Ray3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let ray = Ray3D::build_ray(Vector3D::build_vector(1, 1, 3), Vector3D::build_vector(0, 0, -1));
+    /// let (p, q, s) = (Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(4, 0, 0), Vector3D::build_vector(0, 4, 0));
+    /// assert_eq!(3.0, ray.intersect_triangle(p, q, s).unwrap().t);
+    /// let ray = Ray3D::build_ray(Vector3D::build_vector(3, 3, 3), Vector3D::build_vector(0, 0, -1));
+    /// assert!(ray.intersect_triangle(p, q, s).is_none());
+    /// ```
+    pub fn intersect_triangle(self, p: Vector3D<T>, q: Vector3D<T>, s: Vector3D<T>) -> Option<RayHit> {
+        let p = p.convert_to_f64();
+        let e1 = q.convert_to_f64() - p;
+        let e2 = s.convert_to_f64() - p;
+        let a = self.a.convert_to_f64();
+
+        let h = a.get_vector_product(e2);
+        let det = e1.get_scalar_product(h);
+        if det.abs() <= EPSILON * e1.get_length() * e2.get_length() * a.get_length() {
+            return None;
+        }
+
+        let w = self.r.convert_to_f64() - p;
+        let u = w.get_scalar_product(h) / det;
+        if !(-EPSILON..=1.0 + EPSILON).contains(&u) {
+            return None;
+        }
+        let k = w.get_vector_product(e1);
+        let v = a.get_scalar_product(k) / det;
+        if v < -EPSILON || u + v > 1.0 + EPSILON {
+            return None;
+        }
+
+        self.hit_at(e2.get_scalar_product(k) / det)
+    }
+
+    /// Calculates where the ray hits the surface of a sphere
+    /// # Remarks
+    /// Returns the first point where the ray hits the surface. If the origin of the ray lies
+    /// inside of the sphere, this is the point where the ray leaves the sphere.
+    /// # Return values
+    /// Returns None if the ray misses the sphere
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::ray::Ray3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let ray = Ray3D::build_ray(Vector3D::build_vector(-5, 0, 0), Vector3D::build_vector(1, 0, 0));
+    /// let hit = ray.intersect_sphere(Vector3D::build_vector(0, 0, 0), 2.0).unwrap();
+    /// assert_eq!(Vector3D::build_vector(-2.0, 0.0, 0.0), hit.point);
+    /// assert_eq!(3.0, hit.t);
+    /// ```
+    pub fn intersect_sphere(self, centre: Vector3D<T>, radius: f64) -> Option<RayHit> {
+        let a = self.a.convert_to_f64();
+        let w = self.r.convert_to_f64() - centre.convert_to_f64();
+        let aa = a.get_scalar_product(a);
+        let b = a.get_scalar_product(w);
+        let c = w.get_scalar_product(w) - radius * radius;
+
+        let discriminant = b * b - aa * c;
+        if discriminant < 0.0 || aa == 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let t_near = (-b - root) / aa;
+        let t_far = (-b + root) / aa;
+        if t_near >= 0.0 {
+            self.hit_at(t_near)
+        } else {
+            self.hit_at(t_far)
+        }
+    }
+
+    /// Calculates where the ray hits the surface of an axis-aligned box
+    /// # Parameters
+    /// min: The corner of the box with the smallest coordinates
+    ///
+    /// max: The corner of the box with the largest coordinates
+    /// # Remarks
+    /// Uses the slab method. If the origin of the ray lies inside of the box, the point where the
+    /// ray leaves the box is returned.
+    /// # Return values
+    /// Returns None if the ray misses the box
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::ray::Ray3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let ray = Ray3D::build_ray(Vector3D::build_vector(-2, 1, 1), Vector3D::build_vector(1, 0, 0));
+    /// let hit = ray.intersect_box(Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(2, 2, 2)).unwrap();
+    /// assert_eq!(Vector3D::build_vector(0.0, 1.0, 1.0), hit.point);
+    /// ```
+    pub fn intersect_box(self, min: Vector3D<T>, max: Vector3D<T>) -> Option<RayHit> {
+        let r = self.r.convert_to_f64();
+        let a = self.a.convert_to_f64();
+        let (min, max) = (min.convert_to_f64(), max.convert_to_f64());
+
+        let mut t_near = f64::NEG_INFINITY;
+        let mut t_far = f64::INFINITY;
+        for i in 0..3 {
+            if a[i] == 0.0 {
+                if r[i] < min[i] || r[i] > max[i] {
+                    return None;
+                }
+                continue;
+            }
+            let t1 = (min[i] - r[i]) / a[i];
+            let t2 = (max[i] - r[i]) / a[i];
+            t_near = t_near.max(t1.min(t2));
+            t_far = t_far.min(t1.max(t2));
+        }
+
+        if t_near > t_far {
+            return None;
+        }
+        if t_near >= 0.0 {
+            self.hit_at(t_near)
+        } else {
+            self.hit_at(t_far)
+        }
+    }
+
+    fn hit_at(self, t: f64) -> Option<RayHit> {
+        if t < 0.0 || !t.is_finite() {
+            return None;
+        }
+
+        Some(RayHit {point: self.get_point_at(t), t})
+    }
+}
+
+impl<T> ApproxEq for Ray3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd + PartialEq + ApproxEq
+{
+    fn approx_eq(&self, other: &Ray3D<T>, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance) && self.a.approx_eq(&other.a, tolerance)
+    }
+}
+
+impl ApproxEq for RayHit {
+    fn approx_eq(&self, other: &RayHit, tolerance: Tolerance) -> bool {
+        self.point.approx_eq(&other.point, tolerance) && self.t.approx_eq(&other.t, tolerance)
+    }
+}
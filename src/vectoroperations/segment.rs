@@ -0,0 +1,326 @@
+use num::{Num, FromPrimitive};
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use std::fmt::Debug;
+use vectoroperations::vector2d::Vector2D;
+use vectoroperations::vector3d::Vector3D;
+
+const EPSILON: f64 = 1e-12;
+
+/// The result of an intersection test between two segments
+/// # Remarks
+/// V is the point type, Vector2D<f64> for Segment2D and Vector3D<f64> for Segment3D
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SegmentIntersection<V> {
+    /// The segments have no common point
+    Disjoint,
+    /// The segments have exactly one common point. s is its parameter on the first segment and t
+    /// its parameter on the second segment, both in the range from 0 to 1.
+    Point { point: V, s: f64, t: f64 },
+    /// The segments are collinear and share the part from start to end, ordered in the
+    /// direction of the first segment
+    Overlap { start: V, end: V },
+}
+
+/// A struct for a line segment in two-dimensional space
+/// # Remarks
+/// The points of the segment are p + t * (q - p) with t in the range from 0 to 1
+#[derive(Debug, Copy, Clone)]
+pub struct Segment2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    /// The start point
+    pub p: Vector2D<T>,
+    /// The end point
+    pub q: Vector2D<T>,
+}
+
+impl<T> Segment2D<T>
+    where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+{
+    /// Builds the segment from p to q
+    pub fn build_segment(p: Vector2D<T>, q: Vector2D<T>) -> Segment2D<T> {
+        Segment2D {p, q}
+    }
+
+    /// Calculates the length of the segment
+    pub fn get_length(self) -> f64 {
+        self.p.get_distance(self.q)
+    }
+
+    /// Calculates the point p + t * (q - p) of the segment
+    pub fn get_point_at(self, t: f64) -> Vector2D<f64> {
+        self.p.lerp(self.q, t)
+    }
+
+    /// Calculates the distance between a point and the segment
+    /// # Remarks
+    /// If the foot of the perpendicular lies outside of the segment, the distance to the nearer
+    /// end point is returned
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::segment::Segment2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let segment = Segment2D::build_segment(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0));
+    /// assert_eq!(3.0, segment.distance_from_point(Vector2D::build_vector(2, 3)));
+    /// assert_eq!(5.0, segment.distance_from_point(Vector2D::build_vector(7, 4)));
+    /// ```
+    pub fn distance_from_point(self, point: Vector2D<T>) -> f64 {
+        let d = self.q.convert_to_f64() - self.p.convert_to_f64();
+        let w = point.convert_to_f64() - self.p.convert_to_f64();
+        let squared_length = d.get_scalar_product(d);
+        let t = if squared_length == 0.0 {
+            0.0
+        } else {
+            (w.get_scalar_product(d) / squared_length).clamp(0.0, 1.0)
+        };
+
+        self.get_point_at(t).get_distance(point.convert_to_f64())
+    }
+
+    /// Calculates the common points of two segments
+    /// # Remarks
+    /// Collinear segments that share more than one point return an Overlap, segments that touch
+    /// in an end point return a Point. Segments whose end points are equal are treated as points.
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::segment::{Segment2D, SegmentIntersection};
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let s1 = Segment2D::build_segment(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 4));
+    /// let s2 = Segment2D::build_segment(Vector2D::build_vector(0, 4), Vector2D::build_vector(4, 0));
+    /// let point = Vector2D::build_vector(2.0, 2.0);
+    /// assert_eq!(SegmentIntersection::Point {point, s: 0.5, t: 0.5}, s1.intersection(s2));
+    ///
+    /// let s3 = Segment2D::build_segment(Vector2D::build_vector(2, 2), Vector2D::build_vector(6, 6));
+    /// let (start, end) = (Vector2D::build_vector(2.0, 2.0), Vector2D::build_vector(4.0, 4.0));
+    /// assert_eq!(SegmentIntersection::Overlap {start, end}, s1.intersection(s3));
+    /// ```
+    pub fn intersection(self, other: Segment2D<T>) -> SegmentIntersection<Vector2D<f64>> {
+        let p = self.p.convert_to_f64();
+        let r = self.q.convert_to_f64() - p;
+        let s = other.q.convert_to_f64() - other.p.convert_to_f64();
+        let w = other.p.convert_to_f64() - p;
+        let (r_length, s_length, w_length) = (r.get_length(), s.get_length(), w.get_length());
+
+        if r_length == 0.0 || s_length == 0.0 {
+            return self.intersection_with_degenerate(other);
+        }
+
+        let denominator = r.get_vector_product(s);
+        if denominator.abs() <= EPSILON * r_length * s_length {
+            if w.get_vector_product(r).abs() > EPSILON * w_length * r_length {
+                return SegmentIntersection::Disjoint;
+            }
+            let rr = r.get_scalar_product(r);
+            let t0 = w.get_scalar_product(r) / rr;
+            let t1 = t0 + s.get_scalar_product(r) / rr;
+            return overlap(t0, t1, |t| p + r * t, |t| (t - t0) / (t1 - t0));
+        }
+
+        let t = w.get_vector_product(s) / denominator;
+        let u = w.get_vector_product(r) / denominator;
+        if is_in_unit_interval(t) && is_in_unit_interval(u) {
+            let t = t.clamp(0.0, 1.0);
+            SegmentIntersection::Point {point: p + r * t, s: t, t: u.clamp(0.0, 1.0)}
+        } else {
+            SegmentIntersection::Disjoint
+        }
+    }
+
+    fn intersection_with_degenerate(self, other: Segment2D<T>) -> SegmentIntersection<Vector2D<f64>> {
+        let scale = EPSILON * (1.0 + self.get_length().max(other.get_length()));
+        if self.p.get_distance(self.q) == 0.0 {
+            if other.distance_from_point(self.p) > scale {
+                return SegmentIntersection::Disjoint;
+            }
+            let t = project_parameter(other.p.convert_to_f64(), other.q.convert_to_f64(), self.p.convert_to_f64());
+            SegmentIntersection::Point {point: self.p.convert_to_f64(), s: 0.0, t}
+        } else {
+            if self.distance_from_point(other.p) > scale {
+                return SegmentIntersection::Disjoint;
+            }
+            let s = project_parameter(self.p.convert_to_f64(), self.q.convert_to_f64(), other.p.convert_to_f64());
+            SegmentIntersection::Point {point: other.p.convert_to_f64(), s, t: 0.0}
+        }
+    }
+}
+
+/// A struct for a line segment in three-dimensional space
+/// # Remarks
+/// The points of the segment are p + t * (q - p) with t in the range from 0 to 1
+#[derive(Debug, Copy, Clone)]
+pub struct Segment3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd + PartialEq
+{
+    /// The start point
+    pub p: Vector3D<T>,
+    /// The end point
+    pub q: Vector3D<T>,
+}
+
+impl<T> Segment3D<T>
+    where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd + PartialEq
+{
+    /// Builds the segment from p to q
+    pub fn build_segment(p: Vector3D<T>, q: Vector3D<T>) -> Segment3D<T> {
+        Segment3D {p, q}
+    }
+
+    /// Calculates the length of the segment
+    pub fn get_length(self) -> f64 {
+        self.p.get_distance(self.q)
+    }
+
+    /// Calculates the point p + t * (q - p) of the segment
+    pub fn get_point_at(self, t: f64) -> Vector3D<f64> {
+        self.p.lerp(self.q, t)
+    }
+
+    /// Calculates the distance between a point and the segment
+    /// # Remarks
+    /// If the foot of the perpendicular lies outside of the segment, the distance to the nearer
+    /// end point is returned
+    pub fn distance_from_point(self, point: Vector3D<T>) -> f64 {
+        let d = self.q.convert_to_f64() - self.p.convert_to_f64();
+        let w = point.convert_to_f64() - self.p.convert_to_f64();
+        let squared_length = d.get_scalar_product(d);
+        let t = if squared_length == 0.0 {
+            0.0
+        } else {
+            (w.get_scalar_product(d) / squared_length).clamp(0.0, 1.0)
+        };
+
+        self.get_point_at(t).get_distance(point.convert_to_f64())
+    }
+
+    /// Calculates the common points of two segments
+    /// # Remarks
+    /// Skew segments never intersect. Collinear segments that share more than one point return
+    /// an Overlap, segments that touch in an end point return a Point. Segments whose end points
+    /// are equal are treated as points.
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::segment::{Segment3D, SegmentIntersection};
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let s1 = Segment3D::build_segment(Vector3D::build_vector(0, 0, 1), Vector3D::build_vector(2, 0, 1));
+    /// let s2 = Segment3D::build_segment(Vector3D::build_vector(1, -1, 1), Vector3D::build_vector(1, 1, 1));
+    /// let point = Vector3D::build_vector(1.0, 0.0, 1.0);
+    /// assert_eq!(SegmentIntersection::Point {point, s: 0.5, t: 0.5}, s1.intersection(s2));
+    /// ```
+    pub fn intersection(self, other: Segment3D<T>) -> SegmentIntersection<Vector3D<f64>> {
+        let p = self.p.convert_to_f64();
+        let r = self.q.convert_to_f64() - p;
+        let s = other.q.convert_to_f64() - other.p.convert_to_f64();
+        let w = other.p.convert_to_f64() - p;
+        let (r_length, s_length, w_length) = (r.get_length(), s.get_length(), w.get_length());
+
+        if r_length == 0.0 || s_length == 0.0 {
+            return self.intersection_with_degenerate(other);
+        }
+
+        let n = r.get_vector_product(s);
+        let n_length = n.get_length();
+        if n_length <= EPSILON * r_length * s_length {
+            if w.get_vector_product(r).get_length() > EPSILON * w_length * r_length {
+                return SegmentIntersection::Disjoint;
+            }
+            let rr = r.get_scalar_product(r);
+            let t0 = w.get_scalar_product(r) / rr;
+            let t1 = t0 + s.get_scalar_product(r) / rr;
+            return overlap(t0, t1, |t| p + r * t, |t| (t - t0) / (t1 - t0));
+        }
+
+        if w.get_scalar_product(n).abs() > EPSILON * w_length * n_length {
+            return SegmentIntersection::Disjoint;
+        }
+
+        let nn = n_length * n_length;
+        let t = w.get_vector_product(s).get_scalar_product(n) / nn;
+        let u = w.get_vector_product(r).get_scalar_product(n) / nn;
+        if is_in_unit_interval(t) && is_in_unit_interval(u) {
+            let t = t.clamp(0.0, 1.0);
+            SegmentIntersection::Point {point: p + r * t, s: t, t: u.clamp(0.0, 1.0)}
+        } else {
+            SegmentIntersection::Disjoint
+        }
+    }
+
+    fn intersection_with_degenerate(self, other: Segment3D<T>) -> SegmentIntersection<Vector3D<f64>> {
+        let scale = EPSILON * (1.0 + self.get_length().max(other.get_length()));
+        if self.p.get_distance(self.q) == 0.0 {
+            if other.distance_from_point(self.p) > scale {
+                return SegmentIntersection::Disjoint;
+            }
+            let t = project_parameter_3d(other.p.convert_to_f64(), other.q.convert_to_f64(), self.p.convert_to_f64());
+            SegmentIntersection::Point {point: self.p.convert_to_f64(), s: 0.0, t}
+        } else {
+            if self.distance_from_point(other.p) > scale {
+                return SegmentIntersection::Disjoint;
+            }
+            let s = project_parameter_3d(self.p.convert_to_f64(), self.q.convert_to_f64(), other.p.convert_to_f64());
+            SegmentIntersection::Point {point: other.p.convert_to_f64(), s, t: 0.0}
+        }
+    }
+}
+
+fn is_in_unit_interval(t: f64) -> bool {
+    (-EPSILON..=1.0 + EPSILON).contains(&t)
+}
+
+/// Intersects the parameter interval from t0 to t1 of the second segment with the unit interval
+/// of the first segment. point_at maps a parameter of the first segment to a point, other_parameter
+/// maps it to the parameter of the same point on the second segment.
+fn overlap<V, F, G>(t0: f64, t1: f64, point_at: F, other_parameter: G) -> SegmentIntersection<V>
+    where F: Fn(f64) -> V, G: Fn(f64) -> f64
+{
+    let low = t0.min(t1).max(0.0);
+    let high = t0.max(t1).min(1.0);
+
+    if low > high + EPSILON {
+        SegmentIntersection::Disjoint
+    } else if high - low <= EPSILON {
+        SegmentIntersection::Point {point: point_at(low), s: low, t: other_parameter(low).clamp(0.0, 1.0)}
+    } else {
+        SegmentIntersection::Overlap {start: point_at(low), end: point_at(high)}
+    }
+}
+
+fn project_parameter(p: Vector2D<f64>, q: Vector2D<f64>, x: Vector2D<f64>) -> f64 {
+    let d = q - p;
+    let squared_length = d.get_scalar_product(d);
+    if squared_length == 0.0 {
+        0.0
+    } else {
+        ((x - p).get_scalar_product(d) / squared_length).clamp(0.0, 1.0)
+    }
+}
+
+fn project_parameter_3d(p: Vector3D<f64>, q: Vector3D<f64>, x: Vector3D<f64>) -> f64 {
+    let d = q - p;
+    let squared_length = d.get_scalar_product(d);
+    if squared_length == 0.0 {
+        0.0
+    } else {
+        ((x - p).get_scalar_product(d) / squared_length).clamp(0.0, 1.0)
+    }
+}
+
+impl<T> ApproxEq for Segment2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive + ApproxEq
+{
+    fn approx_eq(&self, other: &Segment2D<T>, tolerance: Tolerance) -> bool {
+        self.p.approx_eq(&other.p, tolerance) && self.q.approx_eq(&other.q, tolerance)
+    }
+}
+
+impl<T> ApproxEq for Segment3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd + PartialEq + ApproxEq
+{
+    fn approx_eq(&self, other: &Segment3D<T>, tolerance: Tolerance) -> bool {
+        self.p.approx_eq(&other.p, tolerance) && self.q.approx_eq(&other.q, tolerance)
+    }
+}
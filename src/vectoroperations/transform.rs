@@ -0,0 +1,442 @@
+use num::{Num, FromPrimitive};
+use std::fmt::Debug;
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use vectoroperations::vector2d::Vector2D;
+use vectoroperations::vector3d::Vector3D;
+use vectoroperations::quaternion::Quaternion;
+use vectoroperations::line3d::Line3D;
+use vectoroperations::plane::Plane;
+use matrices::matrice::Matrice;
+use error::*;
+
+const EPSILON: f64 = 1e-12;
+
+/// An affine transformation of the two-dimensional space
+/// # Remarks
+/// The transformation is stored as 3x3 matrice in homogeneous coordinates, so a point (x | y)
+/// is transformed like the column vector (x, y, 1). The last row of the matrice is always (0, 0, 1).
+/// # Usage
+/// ```
+/// use mathol::vectoroperations::transform::Transform2D;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Transform2D {
+    pub matrice: Matrice<f64>,
+}
+
+impl Transform2D {
+    /// Builds a transformation from a 3x3 matrice in homogeneous coordinates
+    /// # Return values
+    /// Returns the transformation in case of success
+    ///
+    /// Returns MatriceError if the matrice is not a 3x3 matrice or its last row is not (0, 0, 1)
+    pub fn build_from_matrice(matrice: &Matrice<f64>) -> Result<Transform2D, MatholError> {
+        if matrice.rows != 3 || matrice.columns != 3 {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The matrice must be a 3x3 matrice".to_string(),
+            }));
+        }
+        if matrice.data[6..] != [0.0, 0.0, 1.0] {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The matrice does not describe an affine transformation".to_string(),
+            }));
+        }
+
+        Ok(Transform2D {matrice: matrice.clone()})
+    }
+
+    /// Returns the identity transformation
+    pub fn build_identity_transform() -> Transform2D {
+        Transform2D::build_affine([1.0, 0.0, 0.0, 1.0], [0.0, 0.0])
+    }
+
+    /// Builds a translation by dx and dy
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::transform::Transform2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let t = Transform2D::build_translation(2.0, -1.0);
+    /// assert_eq!(Vector2D::build_vector(3.0, 1.0), t.apply_to_point(Vector2D::build_vector(1, 2)));
+    /// assert_eq!(Vector2D::build_vector(1.0, 2.0), t.apply_to_vector(Vector2D::build_vector(1, 2)));
+    /// ```
+    pub fn build_translation(dx: f64, dy: f64) -> Transform2D {
+        Transform2D::build_affine([1.0, 0.0, 0.0, 1.0], [dx, dy])
+    }
+
+    /// Builds a counterclockwise rotation about the origin
+    /// # Parameters
+    /// angle: The angle of rotation as radian value
+    pub fn build_rotation(angle: f64) -> Transform2D {
+        let (sin, cos) = angle.sin_cos();
+        Transform2D::build_affine([cos, -sin, sin, cos], [0.0, 0.0])
+    }
+
+    /// Builds a scaling with the origin as centre
+    /// # Remarks
+    /// Negative factors mirror the respective axis
+    pub fn build_scaling(sx: f64, sy: f64) -> Transform2D {
+        Transform2D::build_affine([sx, 0.0, 0.0, sy], [0.0, 0.0])
+    }
+
+    /// Builds a shearing, the point (x | y) is mapped to (x + shx * y | y + shy * x)
+    pub fn build_shearing(shx: f64, shy: f64) -> Transform2D {
+        Transform2D::build_affine([1.0, shx, shy, 1.0], [0.0, 0.0])
+    }
+
+    /// Builds a reflection about a line through the origin
+    /// # Parameters
+    /// normal: A vector perpendicular to the line, it does not have to be a unit vector
+    /// # Return values
+    /// Returns the transformation in case of success
+    ///
+    /// Returns VectorError if the normal vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::transform::Transform2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let t = Transform2D::build_reflection(Vector2D::build_vector(0, 4)).unwrap();
+    /// assert_eq!(Vector2D::build_vector(2.0, -3.0), t.apply_to_point(Vector2D::build_vector(2, 3)));
+    /// ```
+    pub fn build_reflection<T>(normal: Vector2D<T>) -> Result<Transform2D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let n = normal.normalize()?;
+        Ok(Transform2D::build_affine([1.0 - 2.0 * n.x * n.x, -2.0 * n.x * n.y, -2.0 * n.x * n.y, 1.0 - 2.0 * n.y * n.y], [0.0, 0.0]))
+    }
+
+    /// Composes two transformations
+    /// # Remarks
+    /// Returns the transformation that applies self first and next afterwards
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::transform::Transform2D;
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let t = Transform2D::build_scaling(2.0, 2.0).compose_with(&Transform2D::build_translation(1.0, 0.0));
+    /// assert_eq!(Vector2D::build_vector(3.0, 4.0), t.apply_to_point(Vector2D::build_vector(1, 2)));
+    /// ```
+    pub fn compose_with(&self, next: &Transform2D) -> Transform2D {
+        Transform2D {matrice: next.matrice.multiply_with_matrice(&self.matrice).unwrap()}
+    }
+
+    /// Calculates the inverse transformation
+    /// # Return values
+    /// Returns the inverse transformation in case of success
+    ///
+    /// Returns MatriceError if the transformation is not invertible
+    pub fn get_inverse(&self) -> Result<Transform2D, MatholError> {
+        if self.matrice.get_determinant()?.abs() < EPSILON {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The transformation is not invertible".to_string(),
+            }));
+        }
+
+        Ok(Transform2D {matrice: self.matrice.get_inverse_matrice()?})
+    }
+
+    /// Applies the transformation to a point
+    pub fn apply_to_point<T>(&self, p: Vector2D<T>) -> Vector2D<f64>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let m = &self.matrice.data;
+        let (x, y) = (p.x.to_f64(), p.y.to_f64());
+        Vector2D::build_vector(m[0] * x + m[1] * y + m[2], m[3] * x + m[4] * y + m[5])
+    }
+
+    /// Applies the transformation to a direction vector
+    /// # Remarks
+    /// In contrast to points, direction vectors are not affected by translations
+    pub fn apply_to_vector<T>(&self, v: Vector2D<T>) -> Vector2D<f64>
+        where T: Num + Copy + Convert + Amount<T> + Debug + FromPrimitive
+    {
+        let m = &self.matrice.data;
+        let (x, y) = (v.x.to_f64(), v.y.to_f64());
+        Vector2D::build_vector(m[0] * x + m[1] * y, m[3] * x + m[4] * y)
+    }
+
+    fn build_affine(linear: [f64; 4], translation: [f64; 2]) -> Transform2D {
+        let data = vec![
+            linear[0], linear[1], translation[0],
+            linear[2], linear[3], translation[1],
+            0.0, 0.0, 1.0,
+        ];
+
+        Transform2D {matrice: Matrice::build_matrice(3, 3, data).unwrap()}
+    }
+}
+
+/// An affine transformation of the three-dimensional space
+/// # Remarks
+/// The transformation is stored as 4x4 matrice in homogeneous coordinates, so a point (x | y | z)
+/// is transformed like the column vector (x, y, z, 1). The last row of the matrice is always (0, 0, 0, 1).
+/// # Usage
+/// ```
+/// use mathol::vectoroperations::transform::Transform3D;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Transform3D {
+    pub matrice: Matrice<f64>,
+}
+
+impl Transform3D {
+    /// Builds a transformation from a 4x4 matrice in homogeneous coordinates
+    /// # Return values
+    /// Returns the transformation in case of success
+    ///
+    /// Returns MatriceError if the matrice is not a 4x4 matrice or its last row is not (0, 0, 0, 1)
+    pub fn build_from_matrice(matrice: &Matrice<f64>) -> Result<Transform3D, MatholError> {
+        if matrice.rows != 4 || matrice.columns != 4 {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The matrice must be a 4x4 matrice".to_string(),
+            }));
+        }
+        if matrice.data[12..] != [0.0, 0.0, 0.0, 1.0] {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The matrice does not describe an affine transformation".to_string(),
+            }));
+        }
+
+        Ok(Transform3D {matrice: matrice.clone()})
+    }
+
+    /// Returns the identity transformation
+    pub fn build_identity_transform() -> Transform3D {
+        Transform3D::build_affine([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0])
+    }
+
+    /// Builds a translation by dx, dy and dz
+    pub fn build_translation(dx: f64, dy: f64, dz: f64) -> Transform3D {
+        Transform3D::build_affine([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0], [dx, dy, dz])
+    }
+
+    /// Builds a rotation about the x axis
+    /// # Parameters
+    /// angle: The angle of rotation as radian value, counterclockwise when looking against the axis
+    pub fn build_rotation_x(angle: f64) -> Transform3D {
+        let (sin, cos) = angle.sin_cos();
+        Transform3D::build_affine([1.0, 0.0, 0.0, 0.0, cos, -sin, 0.0, sin, cos], [0.0, 0.0, 0.0])
+    }
+
+    /// Builds a rotation about the y axis
+    /// # Parameters
+    /// angle: The angle of rotation as radian value, counterclockwise when looking against the axis
+    pub fn build_rotation_y(angle: f64) -> Transform3D {
+        let (sin, cos) = angle.sin_cos();
+        Transform3D::build_affine([cos, 0.0, sin, 0.0, 1.0, 0.0, -sin, 0.0, cos], [0.0, 0.0, 0.0])
+    }
+
+    /// Builds a rotation about the z axis
+    /// # Parameters
+    /// angle: The angle of rotation as radian value, counterclockwise when looking against the axis
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::transform::Transform3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use mathol::basics::approx_trait::ApproxEq;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let t = Transform3D::build_rotation_z(FRAC_PI_2);
+    /// let p = t.apply_to_point(Vector3D::build_vector(1, 0, 2));
+    /// assert!(p.abs_diff_eq(&Vector3D::build_vector(0.0, 1.0, 2.0), 1e-15));
+    /// ```
+    pub fn build_rotation_z(angle: f64) -> Transform3D {
+        let (sin, cos) = angle.sin_cos();
+        Transform3D::build_affine([cos, -sin, 0.0, sin, cos, 0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0])
+    }
+
+    /// Builds a rotation about an axis through the origin
+    /// # Parameters
+    /// axis: The direction of the axis, it does not have to be a unit vector
+    ///
+    /// angle: The angle of rotation as radian value, counterclockwise when looking against the axis
+    /// # Return values
+    /// Returns the transformation in case of success
+    ///
+    /// Returns VectorError if the axis is the zero vector
+    pub fn build_rotation<T>(axis: Vector3D<T>, angle: f64) -> Result<Transform3D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        Ok(Transform3D::build_rotation_from_quaternion(Quaternion::build_from_axis_angle(axis, angle)?))
+    }
+
+    /// Builds the rotation described by a unit quaternion
+    pub fn build_rotation_from_quaternion(q: Quaternion) -> Transform3D {
+        let m = q.to_rotation_matrice().data;
+        Transform3D::build_affine([m[0], m[1], m[2], m[3], m[4], m[5], m[6], m[7], m[8]], [0.0, 0.0, 0.0])
+    }
+
+    /// Builds a scaling with the origin as centre
+    /// # Remarks
+    /// Negative factors mirror the respective axis
+    pub fn build_scaling(sx: f64, sy: f64, sz: f64) -> Transform3D {
+        Transform3D::build_affine([sx, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, sz], [0.0, 0.0, 0.0])
+    }
+
+    /// Builds a shearing
+    /// # Remarks
+    /// The point (x | y | z) is mapped to (x + xy * y + xz * z | y + yx * x + yz * z | z + zx * x + zy * y)
+    pub fn build_shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Transform3D {
+        Transform3D::build_affine([1.0, xy, xz, yx, 1.0, yz, zx, zy, 1.0], [0.0, 0.0, 0.0])
+    }
+
+    /// Builds a reflection about a plane
+    /// # Return values
+    /// Returns the transformation in case of success
+    ///
+    /// Returns VectorError if the normal vector of the plane is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::transform::Transform3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use mathol::vectoroperations::plane::Plane;
+    ///
+    /// let plane = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 1), Vector3D::build_vector(0, 0, 3));
+    /// let t = Transform3D::build_reflection(plane).unwrap();
+    /// assert_eq!(Vector3D::build_vector(1.0, 2.0, -1.0), t.apply_to_point(Vector3D::build_vector(1, 2, 3)));
+    /// ```
+    pub fn build_reflection<T>(plane: Plane<T>) -> Result<Transform3D, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd + PartialEq
+    {
+        let n = plane.n.normalize()?;
+        let d = 2.0 * n.get_scalar_product(plane.r.convert_to_f64());
+        let linear = [
+            1.0 - 2.0 * n.x * n.x, -2.0 * n.x * n.y, -2.0 * n.x * n.z,
+            -2.0 * n.y * n.x, 1.0 - 2.0 * n.y * n.y, -2.0 * n.y * n.z,
+            -2.0 * n.z * n.x, -2.0 * n.z * n.y, 1.0 - 2.0 * n.z * n.z,
+        ];
+
+        Ok(Transform3D::build_affine(linear, [d * n.x, d * n.y, d * n.z]))
+    }
+
+    /// Composes two transformations
+    /// # Remarks
+    /// Returns the transformation that applies self first and next afterwards
+    pub fn compose_with(&self, next: &Transform3D) -> Transform3D {
+        Transform3D {matrice: next.matrice.multiply_with_matrice(&self.matrice).unwrap()}
+    }
+
+    /// Calculates the inverse transformation
+    /// # Return values
+    /// Returns the inverse transformation in case of success
+    ///
+    /// Returns MatriceError if the transformation is not invertible
+    pub fn get_inverse(&self) -> Result<Transform3D, MatholError> {
+        if self.matrice.get_determinant()?.abs() < EPSILON {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The transformation is not invertible".to_string(),
+            }));
+        }
+
+        Ok(Transform3D {matrice: self.matrice.get_inverse_matrice()?})
+    }
+
+    /// Applies the transformation to a point
+    pub fn apply_to_point<T>(&self, p: Vector3D<T>) -> Vector3D<f64>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let m = &self.matrice.data;
+        let (x, y, z) = (p.x.to_f64(), p.y.to_f64(), p.z.to_f64());
+        Vector3D::build_vector(
+            m[0] * x + m[1] * y + m[2] * z + m[3],
+            m[4] * x + m[5] * y + m[6] * z + m[7],
+            m[8] * x + m[9] * y + m[10] * z + m[11],
+        )
+    }
+
+    /// Applies the transformation to a direction vector
+    /// # Remarks
+    /// In contrast to points, direction vectors are not affected by translations
+    pub fn apply_to_vector<T>(&self, v: Vector3D<T>) -> Vector3D<f64>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd
+    {
+        let m = &self.matrice.data;
+        let (x, y, z) = (v.x.to_f64(), v.y.to_f64(), v.z.to_f64());
+        Vector3D::build_vector(
+            m[0] * x + m[1] * y + m[2] * z,
+            m[4] * x + m[5] * y + m[6] * z,
+            m[8] * x + m[9] * y + m[10] * z,
+        )
+    }
+
+    /// Applies the transformation to a line
+    /// # Remarks
+    /// The support vector is transformed as point and the direction vector as direction vector
+    pub fn apply_to_line<T>(&self, l: Line3D<T>) -> Line3D<f64>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd + PartialEq
+    {
+        Line3D {
+            r: self.apply_to_point(l.r),
+            a: self.apply_to_vector(l.a),
+        }
+    }
+
+    /// Applies the transformation to a plane
+    /// # Remarks
+    /// The support vector is transformed as point. The normal vector is transformed with the
+    /// inverse transpose of the linear part, so it stays perpendicular to the plane even for
+    /// scalings and shearings.
+    /// # Return values
+    /// Returns the transformed plane in case of success
+    ///
+    /// Returns MatriceError if the transformation is not invertible
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::transform::Transform3D;
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use mathol::vectoroperations::plane::Plane;
+    ///
+    /// let plane = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(1, 1, 0));
+    /// let t = Transform3D::build_scaling(2.0, 1.0, 1.0);
+    /// let result = t.apply_to_plane(plane).unwrap();
+    /// assert_eq!(Vector3D::build_vector(0.5, 1.0, 0.0), result.n);
+    /// ```
+    pub fn apply_to_plane<T>(&self, p: Plane<T>) -> Result<Plane<f64>, MatholError>
+        where T: Num + Copy + Convert + Amount<T> + Debug + PartialOrd + PartialEq
+    {
+        let m = &self.matrice.data;
+        let linear = Matrice::build_matrice(3, 3, vec![m[0], m[1], m[2], m[4], m[5], m[6], m[8], m[9], m[10]])?;
+        if linear.get_determinant()?.abs() < EPSILON {
+            return Err(MatholError::MatriceCause(MatriceError {
+                message: "The transformation is not invertible".to_string(),
+            }));
+        }
+
+        let inv = linear.get_inverse_matrice()?.data;
+        let n = p.n.convert_to_f64();
+        Ok(Plane {
+            r: self.apply_to_point(p.r),
+            n: Vector3D::build_vector(
+                inv[0] * n.x + inv[3] * n.y + inv[6] * n.z,
+                inv[1] * n.x + inv[4] * n.y + inv[7] * n.z,
+                inv[2] * n.x + inv[5] * n.y + inv[8] * n.z,
+            ),
+        })
+    }
+
+    fn build_affine(linear: [f64; 9], translation: [f64; 3]) -> Transform3D {
+        let data = vec![
+            linear[0], linear[1], linear[2], translation[0],
+            linear[3], linear[4], linear[5], translation[1],
+            linear[6], linear[7], linear[8], translation[2],
+            0.0, 0.0, 0.0, 1.0,
+        ];
+
+        Transform3D {matrice: Matrice::build_matrice(4, 4, data).unwrap()}
+    }
+}
+
+impl ApproxEq for Transform2D {
+    fn approx_eq(&self, other: &Transform2D, tolerance: Tolerance) -> bool {
+        self.matrice.approx_eq(&other.matrice, tolerance)
+    }
+}
+
+impl ApproxEq for Transform3D {
+    fn approx_eq(&self, other: &Transform3D, tolerance: Tolerance) -> bool {
+        self.matrice.approx_eq(&other.matrice, tolerance)
+    }
+}
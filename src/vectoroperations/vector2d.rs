@@ -1,16 +1,22 @@
 use num::{Num, FromPrimitive};
 use std::fmt::Debug;
+use std::ops::{Add, Sub, Neg, Mul, Div, AddAssign, SubAssign, Index};
+use std::iter::Sum;
+use std::f64::consts::PI;
 use basics::{pow, pythagoras2d};
 use basics::convert_trait::Convert;
 use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
+use error::*;
 
+const EPSILON: f64 = 1e-9;
 
 /// Struct for two-dimensional vectors
 /// # Usage
 /// ```
 /// use mathol::vectoroperations::vector3d::Vector3D;
 /// ```
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Vector2D<T>
     where T: Num + Copy + Convert + Amount<T> + FromPrimitive
 {
@@ -148,6 +154,286 @@ impl<T> Vector2D<T>
     pub fn get_vector_product(self, vec: Vector2D<T>) -> T {
         self.x * vec.y - self.y * vec.x
     }
+
+    /// Converts the components of a vector to f64 values
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let vector = Vector2D::build_vector(3, 4).convert_to_f64();
+    /// assert_eq!(Vector2D::build_vector(3.0, 4.0), vector);
+    /// ```
+    pub fn convert_to_f64(self) -> Vector2D<f64> {
+        Vector2D::build_vector(self.x.to_f64(), self.y.to_f64())
+    }
+
+    /// Calculates the unit vector pointing in the same direction as the vector
+    /// # Return values
+    /// Returns the unit vector in case of success
+    ///
+    /// Returns VectorError if the vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let vector = Vector2D::build_vector(3, 4).normalize().unwrap();
+    /// assert_eq!(Vector2D::build_vector(0.6, 0.8), vector);
+    /// ```
+    pub fn normalize(self) -> Result<Vector2D<f64>, MatholError> {
+        let length = self.get_length();
+        if length == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The zero vector cannot be normalized".to_string(),
+            }));
+        }
+
+        Ok(self.convert_to_f64() / length)
+    }
+
+    /// Calculates the orthogonal projection of the vector onto another vector
+    /// # Return values
+    /// Returns the projected vector in case of success
+    ///
+    /// Returns VectorError if the other vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let vector = Vector2D::build_vector(2, 3);
+    /// let projection = vector.project_onto(Vector2D::build_vector(4, 0)).unwrap();
+    /// assert_eq!(Vector2D::build_vector(2.0, 0.0), projection);
+    /// ```
+    pub fn project_onto(self, vec: Vector2D<T>) -> Result<Vector2D<f64>, MatholError> {
+        let squared_length = vec.get_scalar_product(vec).to_f64();
+        if squared_length == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "Cannot project onto the zero vector".to_string(),
+            }));
+        }
+
+        Ok(vec.convert_to_f64() * (self.get_scalar_product(vec).to_f64() / squared_length))
+    }
+
+    /// Calculates the rejection of the vector from another vector
+    /// # Remarks
+    /// The rejection is the part of the vector that is orthogonal to the other vector,
+    /// so the projection and the rejection add up to the vector again
+    /// # Return values
+    /// Returns the rejected vector in case of success
+    ///
+    /// Returns VectorError if the other vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let vector = Vector2D::build_vector(2, 3);
+    /// let rejection = vector.reject_from(Vector2D::build_vector(4, 0)).unwrap();
+    /// assert_eq!(Vector2D::build_vector(0.0, 3.0), rejection);
+    /// ```
+    pub fn reject_from(self, vec: Vector2D<T>) -> Result<Vector2D<f64>, MatholError> {
+        Ok(self.convert_to_f64() - self.project_onto(vec)?)
+    }
+
+    /// Reflects the vector about a line through the origin with the given normal vector
+    /// # Remarks
+    /// The normal vector does not have to be a unit vector
+    /// # Return values
+    /// Returns the reflected vector in case of success
+    ///
+    /// Returns VectorError if the normal vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let vector = Vector2D::build_vector(1, -1);
+    /// let reflection = vector.reflect_about(Vector2D::build_vector(0, 2)).unwrap();
+    /// assert_eq!(Vector2D::build_vector(1.0, 1.0), reflection);
+    /// ```
+    pub fn reflect_about(self, normal: Vector2D<T>) -> Result<Vector2D<f64>, MatholError> {
+        let n = normal.normalize()?;
+        let v = self.convert_to_f64();
+        Ok(v - n * (2.0 * v.get_scalar_product(n)))
+    }
+
+    /// Interpolates linearly between two vectors
+    /// # Parameters
+    /// other: The vector reached for t = 1
+    ///
+    /// t: The interpolation parameter, the vector itself is returned for t = 0
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    ///
+    /// let p = Vector2D::build_vector(0, 2);
+    /// let q = Vector2D::build_vector(4, 6);
+    /// assert_eq!(Vector2D::build_vector(1.0, 3.0), p.lerp(q, 0.25));
+    /// ```
+    pub fn lerp(self, other: Vector2D<T>, t: f64) -> Vector2D<f64> {
+        let v = self.convert_to_f64();
+        v + (other.convert_to_f64() - v) * t
+    }
+
+    /// Interpolates spherically between two vectors
+    /// # Remarks
+    /// The direction rotates with constant angular velocity from one vector to the other, while
+    /// the length is interpolated linearly. For parallel vectors the result equals lerp.
+    /// # Parameters
+    /// other: The vector reached for t = 1
+    ///
+    /// t: The interpolation parameter, the vector itself is returned for t = 0
+    /// # Return values
+    /// Returns the interpolated vector in case of success
+    ///
+    /// Returns VectorError if one of the vectors is the zero vector or if the vectors point in
+    /// opposite directions, as the rotation is not unique then
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    /// use mathol::basics::approx_trait::ApproxEq;
+    ///
+    /// let p = Vector2D::build_vector(1, 0);
+    /// let q = Vector2D::build_vector(0, 3);
+    /// let r = p.slerp(q, 0.5).unwrap();
+    /// let expected = Vector2D::build_vector(2.0_f64.sqrt(), 2.0_f64.sqrt());
+    /// assert!(r.abs_diff_eq(&expected, 1e-12));
+    /// ```
+    pub fn slerp(self, other: Vector2D<T>, t: f64) -> Result<Vector2D<f64>, MatholError> {
+        let u = self.normalize()?;
+        let w = other.normalize()?;
+        let omega = u.angle_between(w);
+        let length = (1.0 - t) * self.get_length() + t * other.get_length();
+
+        if omega < EPSILON {
+            return Ok(self.lerp(other, t));
+        }
+        if PI - omega < EPSILON {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The vectors point in opposite directions".to_string(),
+            }));
+        }
+
+        let direction = u * (((1.0 - t) * omega).sin() / omega.sin()) + w * ((t * omega).sin() / omega.sin());
+        Ok(direction * length)
+    }
+
+    /// Calculates the angle between two vectors
+    /// # Remarks
+    /// Returns the angle as radian value in the range from 0 to π.
+    /// If one of the vectors is the zero vector, the angle is 0.
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector2d::Vector2D;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let vec_1 = Vector2D::build_vector(2, 2);
+    /// let vec_2 = Vector2D::build_vector(-3, 3);
+    /// assert_eq!(FRAC_PI_2, vec_1.angle_between(vec_2));
+    /// ```
+    pub fn angle_between(self, vec: Vector2D<T>) -> f64 {
+        let u = self.convert_to_f64();
+        let w = vec.convert_to_f64();
+        u.get_vector_product(w).abs().atan2(u.get_scalar_product(w))
+    }
+}
+
+
+impl<T> Add for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    type Output = Vector2D<T>;
+
+    fn add(self, other: Vector2D<T>) -> Vector2D<T> {
+        Vector2D {x: self.x + other.x, y: self.y + other.y}
+    }
+}
+
+impl<T> Sub for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    type Output = Vector2D<T>;
+
+    fn sub(self, other: Vector2D<T>) -> Vector2D<T> {
+        Vector2D {x: self.x - other.x, y: self.y - other.y}
+    }
+}
+
+impl<T> Neg for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive + Neg<Output=T>
+{
+    type Output = Vector2D<T>;
+
+    fn neg(self) -> Vector2D<T> {
+        Vector2D {x: -self.x, y: -self.y}
+    }
+}
+
+impl<T> Mul<T> for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    type Output = Vector2D<T>;
+
+    fn mul(self, lambda: T) -> Vector2D<T> {
+        Vector2D {x: self.x * lambda, y: self.y * lambda}
+    }
+}
+
+impl<T> Div<T> for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    type Output = Vector2D<T>;
+
+    fn div(self, lambda: T) -> Vector2D<T> {
+        Vector2D {x: self.x / lambda, y: self.y / lambda}
+    }
+}
+
+impl<T> AddAssign for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    fn add_assign(&mut self, other: Vector2D<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T> SubAssign for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    fn sub_assign(&mut self, other: Vector2D<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T> Sum for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    fn sum<I: Iterator<Item=Vector2D<T>>>(iter: I) -> Vector2D<T> {
+        iter.fold(Vector2D {x: T::zero(), y: T::zero()}, |sum, v| sum + v)
+    }
+}
+
+impl<'a, T> Sum<&'a Vector2D<T>> for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    fn sum<I: Iterator<Item=&'a Vector2D<T>>>(iter: I) -> Vector2D<T> {
+        iter.fold(Vector2D {x: T::zero(), y: T::zero()}, |sum, v| sum + *v)
+    }
+}
+
+impl<T> Index<usize> for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    type Output = T;
+
+    /// Returns the component at the given index, starting with 0 for x
+    /// # Remarks
+    /// Panics if the index is out of bounds
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Index is out of bounds"),
+        }
+    }
 }
 
 
@@ -180,4 +466,20 @@ impl<T> Polar<T>
             y: self.r.to_f64()* self.phi.to_f64().to_radians().sin(),
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T> ApproxEq for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive + ApproxEq
+{
+    fn approx_eq(&self, other: &Vector2D<T>, tolerance: Tolerance) -> bool {
+        self.x.approx_eq(&other.x, tolerance) && self.y.approx_eq(&other.y, tolerance)
+    }
+}
+
+impl<T> ApproxEq for Polar<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive + ApproxEq
+{
+    fn approx_eq(&self, other: &Polar<T>, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance) && self.phi.approx_eq(&other.phi, tolerance)
+    }
+}
@@ -2,15 +2,22 @@ use num::Num;
 use basics::{pythagoras2d, pythagoras3d};
 use basics::convert_trait::Convert;
 use basics::amount_trait::Amount;
+use basics::approx_trait::{ApproxEq, Tolerance};
 use std::fmt::Debug;
 use std::cmp::PartialOrd;
+use std::ops::{Add, Sub, Neg, Mul, Div, AddAssign, SubAssign, Index};
+use std::iter::Sum;
+use std::f64::consts::PI;
+use error::*;
+
+const EPSILON: f64 = 1e-9;
 
 /// Struct for three-dimensional vectors
 /// # Usage
 /// ```
 /// use mathol::vectoroperations::vector3d::Vector3D;
 /// ```
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Vector3D<T>
     where T: Num + Copy + Convert + Amount<T> + PartialOrd
 {
@@ -234,6 +241,187 @@ impl<T> Vector3D<T>
         Vector3D::build_vector(self.y * vec.z - self.z * vec.y, self.z * vec.x - self.x * vec.z, self.x * vec.y - self.y * vec.x)
     }
 
+    /// Converts the components of a vector to f64 values
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let vector = Vector3D::build_vector(1, 2, 2).convert_to_f64();
+    /// assert_eq!(Vector3D::build_vector(1.0, 2.0, 2.0), vector);
+    /// ```
+    pub fn convert_to_f64(self) -> Vector3D<f64> {
+        Vector3D::build_vector(self.x.to_f64(), self.y.to_f64(), self.z.to_f64())
+    }
+
+    /// Calculates the unit vector pointing in the same direction as the vector
+    /// # Return values
+    /// Returns the unit vector in case of success
+    ///
+    /// Returns VectorError if the vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let vector = Vector3D::build_vector(0, 3, 4).normalize().unwrap();
+    /// assert_eq!(Vector3D::build_vector(0.0, 0.6, 0.8), vector);
+    /// ```
+    pub fn normalize(self) -> Result<Vector3D<f64>, MatholError> {
+        let length = self.get_length();
+        if length == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The zero vector cannot be normalized".to_string(),
+            }));
+        }
+
+        Ok(self.convert_to_f64() / length)
+    }
+
+    /// Calculates the orthogonal projection of the vector onto another vector
+    /// # Return values
+    /// Returns the projected vector in case of success
+    ///
+    /// Returns VectorError if the other vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let vector = Vector3D::build_vector(2, 3, 4);
+    /// let projection = vector.project_onto(Vector3D::build_vector(0, 0, 2)).unwrap();
+    /// assert_eq!(Vector3D::build_vector(0.0, 0.0, 4.0), projection);
+    /// ```
+    pub fn project_onto(self, vec: Vector3D<T>) -> Result<Vector3D<f64>, MatholError> {
+        let squared_length = vec.get_scalar_product(vec).to_f64();
+        if squared_length == 0.0 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "Cannot project onto the zero vector".to_string(),
+            }));
+        }
+
+        Ok(vec.convert_to_f64() * (self.get_scalar_product(vec).to_f64() / squared_length))
+    }
+
+    /// Calculates the rejection of the vector from another vector
+    /// # Remarks
+    /// The rejection is the part of the vector that is orthogonal to the other vector,
+    /// so the projection and the rejection add up to the vector again
+    /// # Return values
+    /// Returns the rejected vector in case of success
+    ///
+    /// Returns VectorError if the other vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let vector = Vector3D::build_vector(2, 3, 4);
+    /// let rejection = vector.reject_from(Vector3D::build_vector(0, 0, 2)).unwrap();
+    /// assert_eq!(Vector3D::build_vector(2.0, 3.0, 0.0), rejection);
+    /// ```
+    pub fn reject_from(self, vec: Vector3D<T>) -> Result<Vector3D<f64>, MatholError> {
+        Ok(self.convert_to_f64() - self.project_onto(vec)?)
+    }
+
+    /// Reflects the vector about a plane through the origin with the given normal vector
+    /// # Remarks
+    /// The normal vector does not have to be a unit vector
+    /// # Return values
+    /// Returns the reflected vector in case of success
+    ///
+    /// Returns VectorError if the normal vector is the zero vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let vector = Vector3D::build_vector(1, 2, -3);
+    /// let reflection = vector.reflect_about(Vector3D::build_vector(0, 0, 5)).unwrap();
+    /// assert_eq!(Vector3D::build_vector(1.0, 2.0, 3.0), reflection);
+    /// ```
+    pub fn reflect_about(self, normal: Vector3D<T>) -> Result<Vector3D<f64>, MatholError> {
+        let n = normal.normalize()?;
+        let v = self.convert_to_f64();
+        Ok(v - n * (2.0 * v.get_scalar_product(n)))
+    }
+
+    /// Interpolates linearly between two vectors
+    /// # Parameters
+    /// other: The vector reached for t = 1
+    ///
+    /// t: The interpolation parameter, the vector itself is returned for t = 0
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    ///
+    /// let p = Vector3D::build_vector(0, 2, 4);
+    /// let q = Vector3D::build_vector(4, 6, 0);
+    /// assert_eq!(Vector3D::build_vector(1.0, 3.0, 3.0), p.lerp(q, 0.25));
+    /// ```
+    pub fn lerp(self, other: Vector3D<T>, t: f64) -> Vector3D<f64> {
+        let v = self.convert_to_f64();
+        v + (other.convert_to_f64() - v) * t
+    }
+
+    /// Interpolates spherically between two vectors
+    /// # Remarks
+    /// The direction rotates with constant angular velocity from one vector to the other in the
+    /// plane spanned by both vectors, while the length is interpolated linearly.
+    /// For parallel vectors the result equals lerp.
+    /// # Parameters
+    /// other: The vector reached for t = 1
+    ///
+    /// t: The interpolation parameter, the vector itself is returned for t = 0
+    /// # Return values
+    /// Returns the interpolated vector in case of success
+    ///
+    /// Returns VectorError if one of the vectors is the zero vector or if the vectors point in
+    /// opposite directions, as the plane of rotation is not unique then
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use mathol::basics::approx_trait::ApproxEq;
+    ///
+    /// let p = Vector3D::build_vector(0, 1, 0);
+    /// let q = Vector3D::build_vector(0, 0, 3);
+    /// let r = p.slerp(q, 0.5).unwrap();
+    /// let expected = Vector3D::build_vector(0.0, 2.0_f64.sqrt(), 2.0_f64.sqrt());
+    /// assert!(r.abs_diff_eq(&expected, 1e-12));
+    /// ```
+    pub fn slerp(self, other: Vector3D<T>, t: f64) -> Result<Vector3D<f64>, MatholError> {
+        let u = self.normalize()?;
+        let w = other.normalize()?;
+        let omega = u.angle_between(w);
+        let length = (1.0 - t) * self.get_length() + t * other.get_length();
+
+        if omega < EPSILON {
+            return Ok(self.lerp(other, t));
+        }
+        if PI - omega < EPSILON {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The vectors point in opposite directions".to_string(),
+            }));
+        }
+
+        let direction = u * (((1.0 - t) * omega).sin() / omega.sin()) + w * ((t * omega).sin() / omega.sin());
+        Ok(direction * length)
+    }
+
+    /// Calculates the angle between two vectors
+    /// # Remarks
+    /// Returns the angle as radian value in the range from 0 to π.
+    /// If one of the vectors is the zero vector, the angle is 0.
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vector3d::Vector3D;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let vec_1 = Vector3D::build_vector(1, 1, 0);
+    /// let vec_2 = Vector3D::build_vector(0, 0, 7);
+    /// assert_eq!(FRAC_PI_2, vec_1.angle_between(vec_2));
+    /// ```
+    pub fn angle_between(self, vec: Vector3D<T>) -> f64 {
+        let u = self.convert_to_f64();
+        let w = vec.convert_to_f64();
+        u.get_vector_product(w).get_length().atan2(u.get_scalar_product(w))
+    }
+
     /// Calculates the triple product of three vectors
     /// # Remarks
     /// Returns the result as numeric value
@@ -249,6 +437,106 @@ impl<T> Vector3D<T>
     }
 }
 
+impl<T> Add for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    type Output = Vector3D<T>;
+
+    fn add(self, other: Vector3D<T>) -> Vector3D<T> {
+        Vector3D {x: self.x + other.x, y: self.y + other.y, z: self.z + other.z}
+    }
+}
+
+impl<T> Sub for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    type Output = Vector3D<T>;
+
+    fn sub(self, other: Vector3D<T>) -> Vector3D<T> {
+        Vector3D {x: self.x - other.x, y: self.y - other.y, z: self.z - other.z}
+    }
+}
+
+impl<T> Neg for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd + Neg<Output=T>
+{
+    type Output = Vector3D<T>;
+
+    fn neg(self) -> Vector3D<T> {
+        Vector3D {x: -self.x, y: -self.y, z: -self.z}
+    }
+}
+
+impl<T> Mul<T> for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    type Output = Vector3D<T>;
+
+    fn mul(self, lambda: T) -> Vector3D<T> {
+        Vector3D {x: self.x * lambda, y: self.y * lambda, z: self.z * lambda}
+    }
+}
+
+impl<T> Div<T> for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    type Output = Vector3D<T>;
+
+    fn div(self, lambda: T) -> Vector3D<T> {
+        Vector3D {x: self.x / lambda, y: self.y / lambda, z: self.z / lambda}
+    }
+}
+
+impl<T> AddAssign for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    fn add_assign(&mut self, other: Vector3D<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T> SubAssign for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    fn sub_assign(&mut self, other: Vector3D<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T> Sum for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    fn sum<I: Iterator<Item=Vector3D<T>>>(iter: I) -> Vector3D<T> {
+        iter.fold(Vector3D {x: T::zero(), y: T::zero(), z: T::zero()}, |sum, v| sum + v)
+    }
+}
+
+impl<'a, T> Sum<&'a Vector3D<T>> for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    fn sum<I: Iterator<Item=&'a Vector3D<T>>>(iter: I) -> Vector3D<T> {
+        iter.fold(Vector3D {x: T::zero(), y: T::zero(), z: T::zero()}, |sum, v| sum + *v)
+    }
+}
+
+impl<T> Index<usize> for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    type Output = T;
+
+    /// Returns the component at the given index, starting with 0 for x
+    /// # Remarks
+    /// Panics if the index is out of bounds
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index is out of bounds"),
+        }
+    }
+}
+
 /// Rust struct for points in the cylindrical coordinate system.
 #[derive(Debug, Copy, Clone)]
 pub struct Cylindrical<T>
@@ -301,4 +589,28 @@ impl<T> Spherical<T>
             z: self.r.to_f64() * self.theta.to_f64().to_radians().cos(),
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T> ApproxEq for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd + ApproxEq
+{
+    fn approx_eq(&self, other: &Vector3D<T>, tolerance: Tolerance) -> bool {
+        self.x.approx_eq(&other.x, tolerance) && self.y.approx_eq(&other.y, tolerance) && self.z.approx_eq(&other.z, tolerance)
+    }
+}
+
+impl<T> ApproxEq for Cylindrical<T>
+    where T: Num + Copy + Convert + Amount<T> + ApproxEq
+{
+    fn approx_eq(&self, other: &Cylindrical<T>, tolerance: Tolerance) -> bool {
+        self.rho.approx_eq(&other.rho, tolerance) && self.phi.approx_eq(&other.phi, tolerance) && self.z.approx_eq(&other.z, tolerance)
+    }
+}
+
+impl<T> ApproxEq for Spherical<T>
+    where T: Num + Copy + Convert + Amount<T> + ApproxEq
+{
+    fn approx_eq(&self, other: &Spherical<T>, tolerance: Tolerance) -> bool {
+        self.r.approx_eq(&other.r, tolerance) && self.theta.approx_eq(&other.theta, tolerance) && self.phi.approx_eq(&other.phi, tolerance)
+    }
+}
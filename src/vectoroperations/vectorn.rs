@@ -0,0 +1,472 @@
+use num::{Num, FromPrimitive};
+use std::ops::Add;
+use std::fmt::{Debug, Display};
+use basics::convert_trait::Convert;
+use basics::amount_trait::Amount;
+use vectoroperations::vector2d::Vector2D;
+use vectoroperations::vector3d::Vector3D;
+use matrices::matrice::Matrice;
+use error::*;
+
+/// Struct for vectors of any dimension, the dimension is chosen at runtime
+/// # Usage
+/// ```
+/// use mathol::vectoroperations::vectorn::VectorN;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorN<T>
+    where T: Num + Copy + Convert
+{
+    /// The components of the vector
+    pub data: Vec<T>,
+}
+
+impl<T> VectorN<T>
+    where T: Num + Copy + Convert
+{
+    /// Takes the components and returns a VectorN instance
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::VectorN;
+    ///
+    /// let vector = VectorN::build_vector(vec![1, 2, 3, 4]);
+    /// assert_eq!(4, vector.get_dimension());
+    /// ```
+    pub fn build_vector(data: Vec<T>) -> VectorN<T> {
+        VectorN {data}
+    }
+
+    /// Returns the zero vector of the given dimension
+    pub fn build_empty_vector(n: usize) -> VectorN<T> {
+        VectorN {data: vec![T::zero(); n]}
+    }
+
+    /// Returns the number of components of the vector
+    pub fn get_dimension(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Adds a vector to another vector
+    /// # Remarks
+    /// Returns the result of the addition as a new vector
+    ///
+    /// Returns VectorError if the vectors do not have the same dimension
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::VectorN;
+    ///
+    /// let vector_1 = VectorN::build_vector(vec![1, 2, 3, 4]);
+    /// let vector_2 = VectorN::build_vector(vec![4, 3, 2, 1]);
+    /// assert_eq!(vec![5, 5, 5, 5], vector_1.add_vector(&vector_2).unwrap().data);
+    /// ```
+    pub fn add_vector(&self, vec: &VectorN<T>) -> Result<VectorN<T>, MatholError> {
+        self.check_dimension(vec)?;
+        Ok(VectorN::build_vector(self.data.iter().zip(vec.data.iter()).map(|(a, b)| *a + *b).collect()))
+    }
+
+    /// Subtracts a vector from another vector
+    /// # Remarks
+    /// Returns the result of the subtraction as a new vector
+    ///
+    /// Returns VectorError if the vectors do not have the same dimension
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::VectorN;
+    ///
+    /// let vector_1 = VectorN::build_vector(vec![1, 2, 3, 4]);
+    /// let vector_2 = VectorN::build_vector(vec![4, 3, 2, 1]);
+    /// assert_eq!(vec![-3, -1, 1, 3], vector_1.sub_vector(&vector_2).unwrap().data);
+    /// ```
+    pub fn sub_vector(&self, vec: &VectorN<T>) -> Result<VectorN<T>, MatholError> {
+        self.check_dimension(vec)?;
+        Ok(VectorN::build_vector(self.data.iter().zip(vec.data.iter()).map(|(a, b)| *a - *b).collect()))
+    }
+
+    /// Calculates the length of a vector
+    /// # Remarks
+    /// Returns the length as an f64 value
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::VectorN;
+    ///
+    /// let vector = VectorN::build_vector(vec![1, 1, 1, 1]);
+    /// assert_eq!(2.0, vector.get_length());
+    /// ```
+    pub fn get_length(&self) -> f64 {
+        self.data.iter().fold(T::zero(), |sum, e| sum + *e * *e).to_f64().sqrt()
+    }
+
+    /// Calculates the distance between two points
+    /// # Remarks
+    /// Returns VectorError if the vectors do not have the same dimension
+    pub fn get_distance(&self, other: &VectorN<T>) -> Result<f64, MatholError> {
+        Ok(self.sub_vector(other)?.get_length())
+    }
+
+    /// Multiplies a vector with a scalar value
+    /// # Remarks
+    /// Returns the result of the multiplication as a new vector
+    pub fn multiply_with_scalar(&self, lambda: T) -> VectorN<T> {
+        VectorN::build_vector(self.data.iter().map(|e| lambda * *e).collect())
+    }
+
+    /// Calculates the scalar product of two vectors
+    /// # Remarks
+    /// Returns the result as numeric value
+    ///
+    /// Returns VectorError if the vectors do not have the same dimension
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::VectorN;
+    ///
+    /// let vector_1 = VectorN::build_vector(vec![1, 2, 3, 4]);
+    /// let vector_2 = VectorN::build_vector(vec![4, 3, 2, 1]);
+    /// assert_eq!(Ok(20), vector_1.get_scalar_product(&vector_2));
+    /// ```
+    pub fn get_scalar_product(&self, vec: &VectorN<T>) -> Result<T, MatholError> {
+        self.check_dimension(vec)?;
+        Ok(self.data.iter().zip(vec.data.iter()).fold(T::zero(), |sum, (a, b)| sum + *a * *b))
+    }
+
+    /// Calculates the angle between two crossing vectors
+    /// # Remarks
+    /// Returns the cut angle as radian value
+    ///
+    /// Returns VectorError if the vectors do not have the same dimension
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::VectorN;
+    ///
+    /// let vector_1 = VectorN::build_vector(vec![1, 0, 0, 0]);
+    /// let vector_2 = VectorN::build_vector(vec![0, 0, 0, 2]);
+    /// assert_eq!(Ok(std::f64::consts::FRAC_PI_2), vector_1.get_cut_angle(&vector_2));
+    /// ```
+    pub fn get_cut_angle(&self, vec: &VectorN<T>) -> Result<f64, MatholError> {
+        Ok((self.get_scalar_product(vec)?.to_f64() / (self.get_length() * vec.get_length())).acos())
+    }
+
+    /// A helper function checking that two vectors have the same dimension
+    fn check_dimension(&self, vec: &VectorN<T>) -> Result<(), MatholError> {
+        if self.data.len() != vec.data.len() {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "The vectors do not have the same dimension".to_string(),
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> VectorN<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    /// Converts the vector into a Vector2D
+    /// # Remarks
+    /// Returns VectorError if the vector is not two-dimensional
+    pub fn to_vector2d(&self) -> Result<Vector2D<T>, MatholError> {
+        if self.data.len() != 2 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "Vector must have 2 dimensions".to_string(),
+            }));
+        }
+
+        Ok(Vector2D {x: self.data[0], y: self.data[1]})
+    }
+}
+
+impl<T> VectorN<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    /// Converts the vector into a Vector3D
+    /// # Remarks
+    /// Returns VectorError if the vector is not three-dimensional
+    pub fn to_vector3d(&self) -> Result<Vector3D<T>, MatholError> {
+        if self.data.len() != 3 {
+            return Err(MatholError::VectorCause(VectorError {
+                message: "Vector must have 3 dimensions".to_string(),
+            }));
+        }
+
+        Ok(Vector3D {x: self.data[0], y: self.data[1], z: self.data[2]})
+    }
+}
+
+impl<T> VectorN<T>
+    where T: Num + Clone + Add<T> + Copy + Debug + Display + FromPrimitive + Convert
+{
+    /// Builds a vector from a row of a matrice
+    /// # Remarks
+    /// Returns an error message if the row is out of bounds
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    /// use mathol::vectoroperations::vectorn::VectorN;
+    ///
+    /// let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(vec![4, 5, 6], VectorN::build_from_row(&m, 1).unwrap().data);
+    /// ```
+    pub fn build_from_row(matrice: &Matrice<T>, row: usize) -> Result<VectorN<T>, MatholError> {
+        Ok(VectorN::build_vector(matrice.get_row(row)?))
+    }
+
+    /// Builds a vector from a column of a matrice
+    /// # Remarks
+    /// Returns an error message if the column is out of bounds
+    /// # Examples
+    /// ```
+    /// use mathol::matrices::matrice::Matrice;
+    /// use mathol::vectoroperations::vectorn::VectorN;
+    ///
+    /// let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(vec![3, 6], VectorN::build_from_column(&m, 2).unwrap().data);
+    /// ```
+    pub fn build_from_column(matrice: &Matrice<T>, column: usize) -> Result<VectorN<T>, MatholError> {
+        Ok(VectorN::build_vector(matrice.get_column(column)?))
+    }
+
+    /// Converts the vector into a matrice with one row
+    pub fn to_row_matrice(&self) -> Matrice<T> {
+        Matrice {
+            rows: 1,
+            columns: self.data.len(),
+            data: self.data.clone(),
+        }
+    }
+
+    /// Converts the vector into a matrice with one column
+    pub fn to_column_matrice(&self) -> Matrice<T> {
+        Matrice {
+            rows: self.data.len(),
+            columns: 1,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T> From<Vector2D<T>> for VectorN<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    fn from(vec: Vector2D<T>) -> VectorN<T> {
+        VectorN::build_vector(vec![vec.x, vec.y])
+    }
+}
+
+impl<T> From<Vector3D<T>> for VectorN<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    fn from(vec: Vector3D<T>) -> VectorN<T> {
+        VectorN::build_vector(vec![vec.x, vec.y, vec.z])
+    }
+}
+
+
+/// Struct for vectors of any dimension, the dimension N is fixed at compile time
+/// # Remarks
+/// As both vectors of an operation always have the same dimension, no operation can fail
+/// because of a dimension mismatch.
+/// # Usage
+/// ```
+/// use mathol::vectoroperations::vectorn::FixedVector;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FixedVector<T, const N: usize>
+    where T: Num + Copy + Convert
+{
+    /// The components of the vector
+    pub data: [T; N],
+}
+
+impl<T, const N: usize> FixedVector<T, N>
+    where T: Num + Copy + Convert
+{
+    /// Takes the components and returns a FixedVector instance
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::FixedVector;
+    ///
+    /// let vector = FixedVector::build_vector([1, 2, 3, 4]);
+    /// assert_eq!(4, vector.get_dimension());
+    /// ```
+    pub fn build_vector(data: [T; N]) -> FixedVector<T, N> {
+        FixedVector {data}
+    }
+
+    /// Returns the zero vector
+    pub fn build_empty_vector() -> FixedVector<T, N> {
+        FixedVector {data: [T::zero(); N]}
+    }
+
+    /// Returns the number of components of the vector
+    pub fn get_dimension(&self) -> usize {
+        N
+    }
+
+    /// Adds a vector to another vector
+    /// # Remarks
+    /// Returns the result of the addition as a new vector
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::FixedVector;
+    ///
+    /// let vector_1 = FixedVector::build_vector([1, 2, 3, 4]);
+    /// let vector_2 = FixedVector::build_vector([4, 3, 2, 1]);
+    /// assert_eq!([5, 5, 5, 5], vector_1.add_vector(vector_2).data);
+    /// ```
+    pub fn add_vector(self, vec: FixedVector<T, N>) -> FixedVector<T, N> {
+        let mut data = self.data;
+        for (a, b) in data.iter_mut().zip(vec.data.iter()) {
+            *a = *a + *b;
+        }
+        FixedVector {data}
+    }
+
+    /// Subtracts a vector from another vector
+    /// # Remarks
+    /// Returns the result of the subtraction as a new vector
+    pub fn sub_vector(self, vec: FixedVector<T, N>) -> FixedVector<T, N> {
+        let mut data = self.data;
+        for (a, b) in data.iter_mut().zip(vec.data.iter()) {
+            *a = *a - *b;
+        }
+        FixedVector {data}
+    }
+
+    /// Calculates the length of a vector
+    /// # Remarks
+    /// Returns the length as an f64 value
+    pub fn get_length(self) -> f64 {
+        self.data.iter().fold(T::zero(), |sum, e| sum + *e * *e).to_f64().sqrt()
+    }
+
+    /// Calculates the distance between two points
+    pub fn get_distance(self, other: FixedVector<T, N>) -> f64 {
+        self.sub_vector(other).get_length()
+    }
+
+    /// Multiplies a vector with a scalar value
+    /// # Remarks
+    /// Returns the result of the multiplication as a new vector
+    pub fn multiply_with_scalar(self, lambda: T) -> FixedVector<T, N> {
+        let mut data = self.data;
+        for a in data.iter_mut() {
+            *a = lambda * *a;
+        }
+        FixedVector {data}
+    }
+
+    /// Calculates the scalar product of two vectors
+    /// # Remarks
+    /// Returns the result as numeric value
+    /// # Examples
+    /// ```
+    /// use mathol::vectoroperations::vectorn::FixedVector;
+    ///
+    /// let vector_1 = FixedVector::build_vector([1, 2, 3, 4]);
+    /// let vector_2 = FixedVector::build_vector([4, 3, 2, 1]);
+    /// assert_eq!(20, vector_1.get_scalar_product(vector_2));
+    /// ```
+    pub fn get_scalar_product(self, vec: FixedVector<T, N>) -> T {
+        self.data.iter().zip(vec.data.iter()).fold(T::zero(), |sum, (a, b)| sum + *a * *b)
+    }
+
+    /// Calculates the angle between two crossing vectors
+    /// # Remarks
+    /// Returns the cut angle as radian value
+    pub fn get_cut_angle(self, vec: FixedVector<T, N>) -> f64 {
+        (self.get_scalar_product(vec).to_f64() / (self.get_length() * vec.get_length())).acos()
+    }
+
+    /// Converts the vector into a VectorN
+    pub fn to_vector_n(self) -> VectorN<T> {
+        VectorN::build_vector(self.data.to_vec())
+    }
+}
+
+impl<T, const N: usize> FixedVector<T, N>
+    where T: Num + Clone + Add<T> + Copy + Debug + Display + FromPrimitive + Convert
+{
+    /// Builds a vector from a row of a matrice
+    /// # Remarks
+    /// Returns an error message if the row is out of bounds or the matrice does not have N columns
+    pub fn build_from_row(matrice: &Matrice<T>, row: usize) -> Result<FixedVector<T, N>, MatholError> {
+        FixedVector::build_from_slice(&matrice.get_row(row)?)
+    }
+
+    /// Builds a vector from a column of a matrice
+    /// # Remarks
+    /// Returns an error message if the column is out of bounds or the matrice does not have N rows
+    pub fn build_from_column(matrice: &Matrice<T>, column: usize) -> Result<FixedVector<T, N>, MatholError> {
+        FixedVector::build_from_slice(&matrice.get_column(column)?)
+    }
+
+    /// Converts the vector into a matrice with one row
+    pub fn to_row_matrice(self) -> Matrice<T> {
+        Matrice {
+            rows: 1,
+            columns: N,
+            data: self.data.to_vec(),
+        }
+    }
+
+    /// Converts the vector into a matrice with one column
+    pub fn to_column_matrice(self) -> Matrice<T> {
+        Matrice {
+            rows: N,
+            columns: 1,
+            data: self.data.to_vec(),
+        }
+    }
+
+    /// A helper function for building a vector from a slice of length N
+    fn build_from_slice(slice: &[T]) -> Result<FixedVector<T, N>, MatholError> {
+        if slice.len() != N {
+            return Err(MatholError::VectorCause(VectorError {
+                message: format!("Vector must have {} dimensions", N),
+            }));
+        }
+
+        let mut data = [T::zero(); N];
+        data.copy_from_slice(slice);
+        Ok(FixedVector {data})
+    }
+}
+
+impl<T, const N: usize> From<FixedVector<T, N>> for VectorN<T>
+    where T: Num + Copy + Convert
+{
+    fn from(vec: FixedVector<T, N>) -> VectorN<T> {
+        vec.to_vector_n()
+    }
+}
+
+impl<T> From<Vector2D<T>> for FixedVector<T, 2>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    fn from(vec: Vector2D<T>) -> FixedVector<T, 2> {
+        FixedVector::build_vector([vec.x, vec.y])
+    }
+}
+
+impl<T> From<FixedVector<T, 2>> for Vector2D<T>
+    where T: Num + Copy + Convert + Amount<T> + FromPrimitive
+{
+    fn from(vec: FixedVector<T, 2>) -> Vector2D<T> {
+        Vector2D {x: vec.data[0], y: vec.data[1]}
+    }
+}
+
+impl<T> From<Vector3D<T>> for FixedVector<T, 3>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    fn from(vec: Vector3D<T>) -> FixedVector<T, 3> {
+        FixedVector::build_vector([vec.x, vec.y, vec.z])
+    }
+}
+
+impl<T> From<FixedVector<T, 3>> for Vector3D<T>
+    where T: Num + Copy + Convert + Amount<T> + PartialOrd
+{
+    fn from(vec: FixedVector<T, 3>) -> Vector3D<T> {
+        Vector3D {x: vec.data[0], y: vec.data[1], z: vec.data[2]}
+    }
+}
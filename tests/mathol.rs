@@ -1,7 +1,14 @@
 extern crate mathol;
 use mathol::basics::{pow};
-use mathol::geometrics::planimetry::{Triangle, Rectangle, Parallelogram, Trapeze, Polygon, Circle, Ellipsis};
+use mathol::basics::approx_trait::{ApproxEq, Tolerance};
+use mathol::basics::elliptic::{get_elliptic_integral_first_kind, get_elliptic_integral_second_kind};
+use mathol::basics::elliptic::{get_complete_elliptic_integral_first_kind, get_complete_elliptic_integral_second_kind};
+use mathol::geometrics::planimetry::{Triangle, Rectangle, Parallelogram, Trapeze, Polygon, SimplePolygon, Orientation, Circle, Circle2D, Ellipsis, Parabola, Hyperbola};
 use mathol::geometrics::stereometry::{Cuboid, Pyramid, Wedge, Cylinder, Cone, Sphere, Ellipsoid, SphericBarrel, ParabolicBarrel, Torus};
+use mathol::geometrics::bounding::{Aabb2D, Aabb3D, Obb2D, Obb3D};
+use mathol::geometrics::hull::{get_convex_hull_2d, ConvexHull3D};
+use mathol::geometrics::triangulation::{DelaunayTriangulation, VoronoiDiagram};
+use mathol::geometrics::conic::{Conic, ConicType};
 use mathol::geometrics::traits::*;
 use mathol::stochastics::probability::{factorial, permutation, combination, combination_with_repetition, variation, variation_with_repetition};
 use mathol::stochastics::distribution::{binomial_distribution, hypergeometric_distribution, poisson_distribution};
@@ -10,10 +17,19 @@ use mathol::statistics::{get_arithmetic_mean, get_harmonic_mean, get_quadratic_m
 use mathol::statistics::{get_min, get_max, get_span};
 use mathol::vectoroperations::vector2d::{Vector2D, Polar};
 use mathol::vectoroperations::vector3d::{Vector3D, Cylindrical, Spherical};
-use mathol::vectoroperations::line3d::Line3D;
+use mathol::vectoroperations::vectorn::{VectorN, FixedVector};
+use mathol::vectoroperations::quaternion::{Quaternion, EulerOrder};
+use mathol::vectoroperations::transform::{Transform2D, Transform3D};
+use mathol::vectoroperations::line2d::Line2D;
+use mathol::vectoroperations::line3d::{Line3D, ClosestPoints};
 use mathol::vectoroperations::plane::Plane;
+use mathol::vectoroperations::segment::{Segment2D, Segment3D, SegmentIntersection};
+use mathol::vectoroperations::ray::{Ray3D, RayHit};
 use mathol::matrices::matrice::Matrice;
 use mathol::matrices::solvable::Solvable;
+use mathol::matrices::banded::{BandedMatrice, solve_tridiagonal};
+use mathol::matrices::random::MatriceGenerator;
+use mathol::optimization::linear::LinearProgram;
 
 #[test]
 fn test_pow() {
@@ -215,6 +231,266 @@ fn get_ellipsis_panic() {
     Ellipsis::build_ellipsis(2, -3).expect("error");
 }
 
+#[test]
+fn get_ellipsis_foci_and_eccentricity() {
+    let ellipsis = Ellipsis::build_ellipsis(5, 3).expect("error");
+    assert_eq!(4.0, ellipsis.get_linear_eccentricity());
+    assert_eq!(0.8, ellipsis.get_eccentricity());
+    assert_eq!(3.6, ellipsis.get_latus_rectum());
+    let foci = ellipsis.get_foci();
+    assert_eq!([Vector2D::build_vector(-4.0, 0.0), Vector2D::build_vector(4.0, 0.0)], foci);
+
+    // The distances to the foci add up to the major axis for every point on the ellipsis
+    for i in 0..12 {
+        let t = i as f64 * std::f64::consts::PI / 6.0;
+        let p = Vector2D::build_vector(5.0 * t.cos(), 3.0 * t.sin());
+        assert!(ellipsis.is_point_on_ellipsis(p));
+        assert!(ellipsis.contains_point(p));
+        assert!((p.get_distance(foci[0]) + p.get_distance(foci[1]) - 10.0).abs() < 1e-12);
+    }
+    assert!(ellipsis.contains_point(Vector2D::build_vector(0, 0)));
+    assert!(!ellipsis.contains_point(Vector2D::build_vector(0, 4)));
+    assert_eq!(0.0, Ellipsis::build_ellipsis(2, 2).expect("error").get_eccentricity());
+}
+
+#[test]
+fn get_ellipsis_exact_perimeter() {
+    let ellipsis = Ellipsis::build_ellipsis(2, 3).expect("error");
+    assert!((ellipsis.get_exact_perimeter() - 15.865439589290595).abs() < 1e-12);
+    assert!((ellipsis.get_exact_perimeter() - Ellipsis::build_ellipsis(3, 2).expect("error").get_exact_perimeter()).abs() < 1e-12);
+
+    let circle = Ellipsis::build_ellipsis(2, 2).expect("error");
+    assert!((circle.get_exact_perimeter() - 4.0 * std::f64::consts::PI).abs() < 1e-12);
+}
+
+#[test]
+fn get_ellipsis_arc_length() {
+    let pi = std::f64::consts::PI;
+    for ellipsis in [Ellipsis::build_ellipsis(5, 3).expect("error"), Ellipsis::build_ellipsis(3, 5).expect("error")].iter() {
+        let perimeter = ellipsis.get_exact_perimeter();
+        assert!((ellipsis.get_arc_length(0.0, 2.0 * pi) - perimeter).abs() < 1e-12);
+        assert!((ellipsis.get_arc_length(1.0, 1.0 + 4.0 * pi) - 2.0 * perimeter).abs() < 1e-11);
+        assert!((ellipsis.get_arc_length(0.3, 1.2) + ellipsis.get_arc_length(1.2, 0.3)).abs() < 1e-12);
+
+        // Compare with the length of a fine polyline along the arc
+        let (t1, t2) = (0.4, 2.5);
+        let point = |t: f64| Vector2D::build_vector(ellipsis.a * t.cos(), ellipsis.b * t.sin());
+        let polyline: f64 = (0..10000).map(|i| {
+            let t = t1 + (t2 - t1) * i as f64 / 10000.0;
+            point(t).get_distance(point(t + (t2 - t1) / 10000.0))
+        }).sum();
+        assert!((ellipsis.get_arc_length(t1, t2) - polyline).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn get_parabola_properties() {
+    let parabola = Parabola::build_parabola(3).expect("error");
+    assert_eq!(Vector2D::build_vector(1.5, 0.0), parabola.get_focus());
+    assert_eq!(1.0, parabola.get_eccentricity());
+    assert_eq!(6.0, parabola.get_latus_rectum());
+
+    // Every point has the same distance to the focus and the directrix
+    for &y in [-4.0, -1.0, 0.0, 2.5, 6.0].iter() {
+        let p = Vector2D::build_vector(y * y / 6.0, y);
+        assert!(parabola.is_point_on_parabola(p));
+        assert!((p.get_distance(parabola.get_focus()) - parabola.get_directrix().distance_from_point(p)).abs() < 1e-12);
+    }
+    assert!(!parabola.is_point_on_parabola(Vector2D::build_vector(1, 1)));
+}
+
+#[test]
+fn get_parabola_tangents() {
+    let parabola = Parabola::build_parabola(2).expect("error");
+    let tangent = parabola.get_tangent(Vector2D::build_vector(4, 4)).expect("error");
+    assert_eq!((0.5, 2.0), tangent.get_slope_intercept().expect("error"));
+
+    let tangents = parabola.get_tangents_from_point(Vector2D::build_vector(-2, 1));
+    assert_eq!(2, tangents.len());
+    for t in tangents.iter() {
+        assert!(parabola.is_point_on_parabola(t.r));
+        assert_eq!(1, parabola.to_conic().intersection_with_line(*t).len());
+    }
+    assert_eq!(1, parabola.get_tangents_from_point(Vector2D::build_vector(1, 2)).len());
+    assert!(parabola.get_tangents_from_point(Vector2D::build_vector(2, 0)).is_empty());
+}
+
+#[test]
+#[should_panic(expected="The point does not lie on the conic")]
+fn get_parabola_tangent_panic() {
+    Parabola::build_parabola(2).expect("error").get_tangent(Vector2D::build_vector(1, 1)).expect("error");
+}
+
+#[test]
+#[should_panic(expected="Parabola must have a positive parameter")]
+fn get_parabola_panic() {
+    Parabola::build_parabola(0).expect("error");
+}
+
+#[test]
+fn get_hyperbola_properties() {
+    let hyperbola = Hyperbola::build_hyperbola(3, 4).expect("error");
+    assert_eq!(5.0, hyperbola.get_linear_eccentricity());
+    assert_eq!(5.0 / 3.0, hyperbola.get_eccentricity());
+    assert!((hyperbola.get_latus_rectum() - 32.0 / 3.0).abs() < 1e-12);
+    let foci = hyperbola.get_foci();
+    let directrices = hyperbola.get_directrices();
+
+    for i in -5..6 {
+        let t = i as f64 / 2.0;
+        let p = Vector2D::build_vector(3.0 * t.cosh(), 4.0 * t.sinh());
+        assert!(hyperbola.is_point_on_hyperbola(p));
+        assert!(((p.get_distance(foci[0]) - p.get_distance(foci[1])).abs() - 6.0).abs() < 1e-9);
+        assert!((p.get_distance(foci[1]) / directrices[1].distance_from_point(p) - hyperbola.get_eccentricity()).abs() < 1e-9);
+    }
+
+    // Far away points approach the asymptotes
+    let far = Vector2D::build_vector(3.0 * 20.0f64.cosh(), 4.0 * 20.0f64.sinh());
+    let asymptotes = hyperbola.get_asymptotes();
+    assert!(asymptotes[0].distance_from_point(far) < 1e-6);
+    assert!(hyperbola.get_tangents_from_point(Vector2D::build_vector(0, 0)).is_empty());
+}
+
+#[test]
+fn get_hyperbola_tangents() {
+    let hyperbola = Hyperbola::build_hyperbola(2, 1).expect("error");
+    let p = Vector2D::build_vector(2.0 * 5.0f64.sqrt(), 2.0);
+    let tangent = hyperbola.get_tangent(p).expect("error");
+    // The tangent bisects the angle between the focal rays
+    let foci = hyperbola.get_foci();
+    let (u, v) = (foci[0] - p, foci[1] - p);
+    let (cu, cv) = (tangent.a.get_scalar_product(u) / u.get_length(), tangent.a.get_scalar_product(v) / v.get_length());
+    assert!((cu.abs() - cv.abs()).abs() < 1e-12);
+
+    for q in [Vector2D::build_vector(1.0, 0.0), Vector2D::build_vector(0.0, 3.0), Vector2D::build_vector(-1.0, 0.2)].iter() {
+        let tangents = hyperbola.get_tangents_from_point(*q);
+        assert_eq!(2, tangents.len());
+        assert!(tangents.iter().all(|t| hyperbola.is_point_on_hyperbola(t.r) && t.distance_from_point(*q) < 1e-12));
+    }
+}
+
+#[test]
+#[should_panic(expected="Hyperbola must have positive semi-axes")]
+fn get_hyperbola_panic() {
+    Hyperbola::build_hyperbola(-1, 2).expect("error");
+}
+
+/// Returns the conic whose canonical coordinates are rotated by angle and moved to (dx | dy)
+fn transform_conic(conic: Conic, angle: f64, dx: f64, dy: f64) -> Conic {
+    let (sin, cos) = angle.sin_cos();
+    let r = Matrice::build_matrice(2, 2, vec![cos, -sin, sin, cos]).expect("error");
+    let m = Matrice::build_matrice(2, 2, vec![conic.a, conic.b / 2.0, conic.b / 2.0, conic.c]).expect("error");
+    let rt = Matrice::build_matrice(2, 2, vec![cos, sin, -sin, cos]).expect("error");
+    let n = r.multiply_with_matrice(&m).expect("error").multiply_with_matrice(&rt).expect("error").data;
+    let l = Vector2D::build_vector(cos * conic.d - sin * conic.e, sin * conic.d + cos * conic.e);
+    let o = Vector2D::build_vector(dx, dy);
+    let no = Vector2D::build_vector(n[0] * dx + n[1] * dy, n[2] * dx + n[3] * dy);
+    Conic {
+        a: n[0], b: 2.0 * n[1], c: n[3],
+        d: l.x - 2.0 * no.x, e: l.y - 2.0 * no.y,
+        f: o.get_scalar_product(no) - l.get_scalar_product(o) + conic.f,
+    }
+}
+
+#[test]
+fn get_conic_type() {
+    let types = [
+        ((1, 0, 4, 0, 0, -4), ConicType::Ellipsis),
+        ((2, 0, 2, -4, 0, -6), ConicType::Circle),
+        ((0, 2, 0, 1, 1, -3), ConicType::Hyperbola),
+        ((1, 2, 1, 3, -1, 0), ConicType::Parabola),
+        ((1, 0, 1, -2, -2, 2), ConicType::Point),
+        ((1, 0, -4, 2, 0, 1), ConicType::IntersectingLines),
+        ((1, 2, 1, 0, 0, -1), ConicType::ParallelLines),
+        ((1, -2, 1, 2, -2, 1), ConicType::CoincidentLines),
+        ((1, 0, 1, 0, 0, 1), ConicType::Empty),
+        ((0, 0, 1, 0, 0, 1), ConicType::Empty),
+    ];
+    for &((a, b, c, d, e, f), kind) in types.iter() {
+        assert_eq!(Ok(kind), Conic::build_conic(a, b, c, d, e, f).expect("error").get_type());
+    }
+}
+
+#[test]
+fn get_conic_canonical_form() {
+    let ellipsis = Ellipsis::build_ellipsis(2.0, 5.0).expect("error");
+    let hyperbola = Hyperbola::build_hyperbola(1.5, 0.5).expect("error");
+    let parabola = Parabola::build_parabola(0.75).expect("error");
+
+    for &(angle, dx, dy) in [(0.0, 0.0, 0.0), (0.4, 3.0, -2.0), (2.5, -10.0, 7.0), (-1.2, 0.5, 100.0)].iter() {
+        let conic = transform_conic(ellipsis.to_conic(), angle, dx, dy);
+        let canonical = conic.get_canonical_form().expect("error");
+        let result = canonical.to_ellipsis().expect("error");
+        assert!((result.a - 5.0).abs() < 1e-9 && (result.b - 2.0).abs() < 1e-9);
+        let centre = canonical.transform.apply_to_point(Vector2D::build_vector(0, 0));
+        assert!(centre.get_distance(Vector2D::build_vector(dx, dy)) < 1e-9);
+
+        let conic = transform_conic(hyperbola.to_conic(), angle, dx, dy);
+        let canonical = conic.get_canonical_form().expect("error");
+        let result = canonical.to_hyperbola().expect("error");
+        assert!((result.a - 1.5).abs() < 1e-9 && (result.b - 0.5).abs() < 1e-9);
+        let rotate = |p: Vector2D<f64>| Vector2D::build_vector(angle.cos() * p.x - angle.sin() * p.y + dx, angle.sin() * p.x + angle.cos() * p.y + dy);
+        for focus in result.get_foci().iter() {
+            let p = canonical.transform.apply_to_point(*focus);
+            assert!(hyperbola.get_foci().iter().any(|f| rotate(*f).get_distance(p) < 1e-9));
+        }
+
+        let conic = transform_conic(parabola.to_conic(), angle, dx, dy);
+        let canonical = conic.get_canonical_form().expect("error");
+        assert!((canonical.to_parabola().expect("error").p - 0.75).abs() < 1e-9);
+        let vertex = canonical.transform.apply_to_point(Vector2D::build_vector(0, 0));
+        assert!(vertex.get_distance(Vector2D::build_vector(dx, dy)) < 1e-9);
+        let focus = canonical.transform.apply_to_point(parabola.get_focus());
+        let expected = Vector2D::build_vector(dx + 0.375 * angle.cos(), dy + 0.375 * angle.sin());
+        assert!(focus.get_distance(expected) < 1e-9);
+
+        // Points of the canonical curve lie on the original conic
+        for i in 0..8 {
+            let t = i as f64 - 4.0;
+            let p = canonical.transform.apply_to_point(Vector2D::build_vector(t * t / 1.5, t));
+            assert!(conic.is_point_on_conic(p));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected="The conic is not a parabola")]
+fn get_conic_canonical_form_panic() {
+    Conic::build_conic(1, 0, 1, 0, 0, -1).expect("error").get_canonical_form().expect("error").to_parabola().expect("error");
+}
+
+#[test]
+#[should_panic(expected="The coefficients a, b and c must not all be zero")]
+fn get_conic_canonical_form_zero_panic() {
+    let mut conic = Conic::build_conic(1, 0, 1, 2, 0, -1).expect("error");
+    conic.a = 0.0;
+    conic.c = 0.0;
+    conic.get_canonical_form().expect("error");
+}
+
+#[test]
+#[should_panic(expected="The coefficients a, b and c must not all be zero")]
+fn get_conic_panic() {
+    Conic::build_conic(0, 0, 0, 1, 1, 1).expect("error");
+}
+
+#[test]
+fn get_elliptic_integrals() {
+    let pi = std::f64::consts::PI;
+    assert!((get_complete_elliptic_integral_first_kind(0.8).unwrap() - 1.995302777664729).abs() < 1e-13);
+    assert!((get_complete_elliptic_integral_second_kind(0.8).unwrap() - 1.276349943169906).abs() < 1e-13);
+    assert!((get_elliptic_integral_first_kind(pi / 2.0, 0.8).unwrap() - get_complete_elliptic_integral_first_kind(0.8).unwrap()).abs() < 1e-13);
+    assert!((get_elliptic_integral_first_kind(2.0, 0.6).unwrap() - 2.278702362280015).abs() < 1e-13);
+    assert!((get_elliptic_integral_second_kind(-2.0, 0.6).unwrap() + 1.767080004479775).abs() < 1e-13);
+    assert!((get_elliptic_integral_second_kind(1.0, 1.0).unwrap() - 1.0f64.sin()).abs() < 1e-13);
+}
+
+#[test]
+#[should_panic(expected="The modulus k must lie strictly between -1 and 1")]
+fn get_elliptic_integral_panic() {
+    get_complete_elliptic_integral_first_kind(1.0).expect("error");
+}
+
 #[test]
 fn get_cuboid_diagonal_1() {
     let cuboid = Cuboid::build_cuboid(4, 4, 4).expect("error");
@@ -1670,4 +1946,1447 @@ fn test_solve_1() {
 //fn test_solve_3() {
 //    let m = Matrice::build_matrice(3, 3, vec![1, 1, -2, 1, -1, -2, 2, 3, -4]).unwrap();
 //    assert_eq!(Err("The linear system is not solvable or it has infinite solutions"), m.solve(&vec![0, 0, 0]));
-//}
\ No newline at end of file
+//}
+
+#[test]
+fn test_simplex_1() {
+    let a = Matrice::build_matrice(3, 2, vec![1, 0, 0, 2, 3, 2]).unwrap();
+    let lp = LinearProgram::build_linear_program(&a, &[4, 12, 18], &[3, 5]).unwrap();
+    let solution = lp.solve().unwrap();
+    assert_eq!(vec![2.0, 6.0], solution.x);
+    assert_eq!(36.0, solution.objective);
+    assert_eq!(vec![0.0, 1.5, 1.0], solution.duals);
+}
+
+#[test]
+fn test_simplex_2() {
+    let a = Matrice::build_matrice(3, 2, vec![-1, -1, 1, 0, 0, 1]).unwrap();
+    let lp = LinearProgram::build_linear_program(&a, &[-2, 3, 3], &[-1, -1]).unwrap();
+    let solution = lp.solve().unwrap();
+    assert!((solution.objective + 2.0).abs() < 1e-9);
+    assert!((solution.x[0] + solution.x[1] - 2.0).abs() < 1e-9);
+    assert!((solution.duals[0] - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_simplex_degenerate() {
+    let a = Matrice::build_matrice(3, 4, vec![0.25, -8.0, -1.0, 9.0, 0.5, -12.0, -0.5, 3.0, 0.0, 0.0, 1.0, 0.0]).unwrap();
+    let lp = LinearProgram::build_linear_program(&a, &[0.0, 0.0, 1.0], &[0.75, -20.0, 0.5, -6.0]).unwrap();
+    let solution = lp.solve().unwrap();
+    assert!((solution.objective - 1.25).abs() < 1e-9);
+}
+
+#[test]
+#[should_panic(expected="The linear program has no feasible solution")]
+fn test_simplex_infeasible() {
+    let a = Matrice::build_matrice(2, 2, vec![1, 1, -1, -1]).unwrap();
+    let lp = LinearProgram::build_linear_program(&a, &[1, -2], &[1, 1]).unwrap();
+    lp.solve().expect("error");
+}
+
+#[test]
+#[should_panic(expected="The linear program is unbounded")]
+fn test_simplex_unbounded() {
+    let a = Matrice::build_matrice(1, 2, vec![-1, 1]).unwrap();
+    let lp = LinearProgram::build_linear_program(&a, &[1], &[1, 0]).unwrap();
+    lp.solve().expect("error");
+}
+
+#[test]
+#[should_panic(expected="Vector b must have 2 elements")]
+fn test_simplex_panic() {
+    let a = Matrice::build_matrice(2, 2, vec![1, 1, -1, -1]).unwrap();
+    LinearProgram::build_linear_program(&a, &[1], &[1, 1]).expect("error");
+}
+
+#[test]
+fn test_solve_tridiagonal() {
+    let x = solve_tridiagonal(&[1, 1], &[1, 2, 2], &[1, 1], &[3, 8, 8]).unwrap();
+    assert_eq!(vec![1.0, 2.0, 3.0], x);
+}
+
+#[test]
+#[should_panic(expected="Zero pivot in row 1")]
+fn test_solve_tridiagonal_panic() {
+    solve_tridiagonal(&[1.0], &[1.0, 1.0], &[1.0], &[1.0, 2.0]).expect("error");
+}
+
+#[test]
+fn test_banded_matrice() {
+    let m = Matrice::build_matrice(4, 4, vec![4, 1, 1, 0, 1, 5, 2, 1, 0, 2, 6, 1, 0, 0, 1, 3]).unwrap();
+    let b = BandedMatrice::build_from_matrice(&m, 1, 2).unwrap();
+    assert_eq!(16, b.data.len());
+    assert_eq!(m, b.to_matrice());
+    assert!(b.is_diagonally_dominant());
+    let x = b.solve(&[8, 19, 20, 14]).unwrap();
+    for (e, expected) in x.iter().zip([1.0, 2.0, 2.0, 4.0].iter()) {
+        assert!((e - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+#[should_panic(expected="The matrice has non-zero elements outside of the band")]
+fn test_banded_matrice_panic() {
+    let m = Matrice::build_matrice(3, 3, vec![2, 1, 1, 1, 2, 1, 0, 1, 2]).unwrap();
+    BandedMatrice::build_from_matrice(&m, 1, 1).expect("error");
+}
+
+#[test]
+fn test_matrice_rows() {
+    let m = Matrice::build_matrice(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    let rows: Vec<&[i32]> = m.rows().collect();
+    assert_eq!(vec![&[1, 2][..], &[3, 4][..], &[5, 6][..]], rows);
+    assert_eq!(Some(&[5, 6][..]), m.rows().next_back());
+    assert_eq!(3, m.rows().len());
+}
+
+#[test]
+fn test_matrice_columns() {
+    let m = Matrice::build_matrice(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    let columns: Vec<Vec<i32>> = m.columns().map(|c| c.cloned().collect()).collect();
+    assert_eq!(vec![vec![1, 3, 5], vec![2, 4, 6]], columns);
+    assert_eq!(vec![6, 4, 2], m.columns().next_back().unwrap().rev().cloned().collect::<Vec<i32>>());
+}
+
+#[test]
+fn test_matrice_indexed_iter() {
+    let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    let positions: Vec<(usize, usize)> = m.indexed_iter().filter(|&(_, e)| e % 2 == 0).map(|(p, _)| p).collect();
+    assert_eq!(vec![(0, 1), (1, 0), (1, 2)], positions);
+    assert_eq!(21, m.iter_elements().sum::<i32>());
+}
+
+#[test]
+fn test_matrice_map_and_zip_with() {
+    let a = Matrice::build_matrice(2, 2, vec![1, 2, 3, 4]).unwrap();
+    let b = a.map(|e| e * e);
+    assert_eq!(vec![1, 4, 9, 16], b.data);
+    assert_eq!(vec![0, 2, 6, 12], b.zip_with(&a, |x, y| x - y).unwrap().data);
+}
+
+#[test]
+#[should_panic(expected="The two matrices do not have the same number of rows or columns")]
+fn test_matrice_zip_with_panic() {
+    let a = Matrice::build_matrice(2, 2, vec![1, 2, 3, 4]).unwrap();
+    let b = Matrice::build_matrice(1, 2, vec![1, 2]).unwrap();
+    a.zip_with(&b, |x, y| x + y).expect("error");
+}
+
+#[test]
+fn test_matrice_sums() {
+    let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(vec![6, 15], m.get_row_sums());
+    assert_eq!(vec![5, 7, 9], m.get_column_sums());
+    assert_eq!(vec![3, 3], m.fold_rows(0, |count, _| count + 1));
+}
+
+#[test]
+fn test_matrice_from_iter() {
+    let m: Matrice<i32> = vec![vec![1, 2], vec![3, 4]].into_iter().collect();
+    assert_eq!(Matrice::build_matrice(2, 2, vec![1, 2, 3, 4]).unwrap(), m);
+}
+
+#[test]
+#[should_panic(expected="Row must have 2 columns")]
+fn test_matrice_from_iter_panic() {
+    let _: Matrice<i32> = vec![vec![1, 2], vec![3]].into_iter().collect();
+}
+
+#[test]
+fn test_random_reproducible() {
+    let mut g1 = MatriceGenerator::build_generator(2018);
+    let mut g2 = MatriceGenerator::build_generator(2018);
+    let mut g3 = MatriceGenerator::build_generator(2019);
+    let m1 = g1.gen_normal_matrice(4, 4, 0.0, 1.0).unwrap();
+    let m2 = g2.gen_normal_matrice(4, 4, 0.0, 1.0).unwrap();
+    let m3 = g3.gen_normal_matrice(4, 4, 0.0, 1.0).unwrap();
+    assert_eq!(m1, m2);
+    assert_ne!(m1, m3);
+}
+
+#[test]
+fn test_random_uniform() {
+    let mut g = MatriceGenerator::build_generator(1);
+    let m = g.gen_uniform_matrice(50, 40, 2.0, 5.0).unwrap();
+    assert!(m.data.iter().all(|e| *e >= 2.0 && *e < 5.0));
+    let mean = m.data.iter().sum::<f64>() / 2000.0;
+    assert!((mean - 3.5).abs() < 0.1);
+}
+
+#[test]
+#[should_panic(expected="Lower bound must be smaller than upper bound")]
+fn test_random_uniform_panic() {
+    let mut g = MatriceGenerator::build_generator(1);
+    g.gen_uniform_matrice(2, 2, 5.0, 2.0).expect("error");
+}
+
+#[test]
+fn test_random_orthogonal() {
+    let mut g = MatriceGenerator::build_generator(3);
+    let q = g.gen_orthogonal_matrice(4);
+    let mut qt = Matrice::build_empty_matrice(4, 4);
+    for ((i, k), e) in q.indexed_iter() {
+        qt.insert_element(*e, k, i);
+    }
+    let p = qt.multiply_with_matrice(&q).unwrap();
+    for ((i, k), e) in p.indexed_iter() {
+        let expected = if i == k { 1.0 } else { 0.0 };
+        assert!((e - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_random_spd() {
+    let mut g = MatriceGenerator::build_generator(4);
+    let m = g.gen_spd_matrice(4);
+    for ((i, k), e) in m.indexed_iter() {
+        assert_eq!(*e, m.get_element(k, i).unwrap());
+    }
+    assert!(m.get_determinant().unwrap() > 0.0);
+    assert!(m.get_submatrice(3, 3).get_determinant().unwrap() > 0.0);
+}
+
+#[test]
+fn test_random_sparse() {
+    let mut g = MatriceGenerator::build_generator(5);
+    let m = g.gen_sparse_matrice(8, 5, 0.25).unwrap();
+    assert_eq!(10, m.iter_elements().filter(|e| **e != 0.0).count());
+}
+
+#[test]
+#[should_panic(expected="Density must be in a range between 0 and 1")]
+fn test_random_sparse_panic() {
+    let mut g = MatriceGenerator::build_generator(5);
+    g.gen_sparse_matrice(8, 5, 1.5).expect("error");
+}
+
+#[test]
+fn test_approx_eq_f64() {
+    assert!(1.0.approx_eq(&1.05, Tolerance::Absolute(0.1)));
+    assert!(!1.0.approx_eq(&1.05, Tolerance::Absolute(0.01)));
+    assert!(1000.0.relative_eq(&1001.0, 1e-3));
+    assert!(!1.0.relative_eq(&1.01, 1e-3));
+    assert!(1.0.ulps_eq(&(1.0 + f64::EPSILON), 1));
+    assert!(!1.0.ulps_eq(&(1.0 + 4.0 * f64::EPSILON), 2));
+    assert!(!f64::NAN.abs_diff_eq(&f64::NAN, 1.0));
+    assert!(0.0.ulps_eq(&-0.0, 0));
+}
+
+#[test]
+fn test_approx_eq_matrice() {
+    let m = Matrice::build_matrice(3, 3, vec![1.0, 0.0, -1.0, -8.0, 4.0, 1.0, -2.0, 1.0, 0.0]).unwrap();
+    let inverse = m.get_inverse_matrice().unwrap();
+    let expected = Matrice::build_matrice(3, 3, vec![1.0, 1.0, -4.0, 2.0, 2.0, -7.0, 0.0, 1.0, -4.0]).unwrap();
+    assert!(inverse.abs_diff_eq(&expected, 1e-12));
+    let other = Matrice::build_matrice(1, 9, expected.data.clone()).unwrap();
+    assert!(!other.abs_diff_eq(&expected, 1e-12));
+}
+
+#[test]
+fn test_approx_eq_vectors() {
+    let polar = Polar {r: 2.0, phi: 30.0};
+    let vec = polar.transform_to_vector2d();
+    assert!(vec.abs_diff_eq(&Vector2D {x: 3.0_f64.sqrt(), y: 1.0}, 1e-12));
+    assert!(vec.transform_to_polar().abs_diff_eq(&polar, 1e-12));
+
+    let cart = Vector3D {x: 3.0, y: 4.0, z: 5.0};
+    assert!(cart.transform_to_cylindrical().transform_to_vector3d().relative_eq(&cart, 1e-12));
+    assert!(cart.transform_to_spherical().transform_to_vector3d().relative_eq(&cart, 1e-12));
+    assert!(cart.transform_to_spherical().abs_diff_eq(&Spherical {r: 50.0_f64.sqrt(), theta: 45.0, phi: 53.13010235415598}, 1e-12));
+}
+
+#[test]
+fn test_approx_eq_geometrics() {
+    let t1 = Triangle::build_triangle_with_points(Vector2D {x: 0.0, y: 0.0}, Vector2D {x: 3.0, y: 0.0}, Vector2D {x: 0.0, y: 4.0});
+    let t2 = Triangle::build_triangle_with_edges(5, 4, 3).unwrap();
+    assert!(t1.abs_diff_eq(&t2, 1e-12));
+    let c1 = Cuboid::build_cuboid(1.0, 2.0, 3.0).unwrap();
+    let c2 = Cuboid::build_cuboid(1.0, 2.0, 3.0 + 1e-9).unwrap();
+    assert!(c1.relative_eq(&c2, 1e-6));
+    assert!(!c1.ulps_eq(&c2, 4));
+}
+
+#[test]
+fn test_vector_n_operations() {
+    let a = VectorN::build_vector(vec![1, 2, 3, 4, 5]);
+    let b = VectorN::build_vector(vec![5, 4, 3, 2, 1]);
+    assert_eq!(vec![6, 6, 6, 6, 6], a.add_vector(&b).unwrap().data);
+    assert_eq!(vec![-4, -2, 0, 2, 4], a.sub_vector(&b).unwrap().data);
+    assert_eq!(vec![2, 4, 6, 8, 10], a.multiply_with_scalar(2).data);
+    assert_eq!(Ok(35), a.get_scalar_product(&b));
+    assert_eq!(55.0_f64.sqrt(), a.get_length());
+    assert_eq!(Ok(40.0_f64.sqrt()), a.get_distance(&b));
+}
+
+#[test]
+#[should_panic(expected="The vectors do not have the same dimension")]
+fn test_vector_n_panic() {
+    let a = VectorN::build_vector(vec![1, 2, 3, 4]);
+    let b = VectorN::build_vector(vec![1, 2, 3]);
+    a.add_vector(&b).expect("error");
+}
+
+#[test]
+fn test_vector_n_conversions() {
+    let v2 = Vector2D::build_vector(3, 4);
+    let n: VectorN<i32> = v2.into();
+    assert_eq!(vec![3, 4], n.data);
+    assert_eq!(5.0, n.get_length());
+    assert_eq!(4, n.to_vector2d().unwrap().y);
+    assert!(n.to_vector3d().is_err());
+
+    let v3 = Vector3D::build_vector(1, 2, 3);
+    let n = VectorN::from(v3);
+    assert_eq!(3, n.to_vector3d().unwrap().z);
+    assert_eq!(v3.get_length(), n.get_length());
+
+    let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    let row = VectorN::build_from_row(&m, 0).unwrap();
+    let column = VectorN::build_from_column(&m, 1).unwrap();
+    assert_eq!(vec![1, 2, 3], row.data);
+    assert_eq!(vec![2, 5], column.data);
+    assert_eq!(3, row.to_row_matrice().columns);
+    assert_eq!(3, row.to_column_matrice().rows);
+}
+
+#[test]
+fn test_fixed_vector() {
+    let a = FixedVector::build_vector([1.0, 0.0, 0.0, 0.0]);
+    let b = FixedVector::build_vector([0.0, 2.0, 0.0, 0.0]);
+    assert_eq!([1.0, 2.0, 0.0, 0.0], a.add_vector(b).data);
+    assert_eq!([1.0, -2.0, 0.0, 0.0], a.sub_vector(b).data);
+    assert_eq!(0.0, a.get_scalar_product(b));
+    assert_eq!(std::f64::consts::FRAC_PI_2, a.get_cut_angle(b));
+    assert_eq!(5.0_f64.sqrt(), a.get_distance(b));
+    assert_eq!(vec![1.0, 0.0, 0.0, 0.0], a.to_vector_n().data);
+
+    let v: FixedVector<i32, 3> = Vector3D::build_vector(1, 2, 3).into();
+    assert_eq!(14, v.get_scalar_product(v));
+    let back: Vector3D<i32> = v.multiply_with_scalar(2).into();
+    assert_eq!(6, back.z);
+}
+
+#[test]
+fn test_fixed_vector_matrice() {
+    let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    let row: FixedVector<i32, 3> = FixedVector::build_from_row(&m, 1).unwrap();
+    assert_eq!([4, 5, 6], row.data);
+    let column: FixedVector<i32, 2> = FixedVector::build_from_column(&m, 0).unwrap();
+    assert_eq!([1, 4], column.data);
+    assert!(FixedVector::<i32, 2>::build_from_row(&m, 0).is_err());
+    assert_eq!(m.get_column(2).unwrap(), FixedVector::<i32, 2>::build_from_column(&m, 2).unwrap().to_column_matrice().data);
+}
+
+#[test]
+#[should_panic(expected="Vector must have 2 dimensions")]
+fn test_fixed_vector_panic() {
+    let m = Matrice::build_matrice(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    FixedVector::<i32, 2>::build_from_row(&m, 0).expect("error");
+}
+
+#[test]
+fn test_vector2d_operators() {
+    let p = Vector2D::build_vector(1, 2);
+    let q = Vector2D::build_vector(3, -1);
+    let r = Vector2D::build_vector(2, 2);
+    assert_eq!(p.add_vector(q.multiply_with_scalar(2)).sub_vector(r), p + q * 2 - r);
+    assert_eq!(Vector2D::build_vector(5, -2), p + q * 2 - r);
+    assert_eq!(Vector2D::build_vector(-1, -2), -p);
+    assert_eq!(Vector2D::build_vector(1, 1), r / 2);
+    let mut s = p;
+    s += q;
+    assert_eq!(Vector2D::build_vector(4, 1), s);
+    s -= r;
+    assert_eq!(Vector2D::build_vector(2, -1), s);
+    assert_eq!(Vector2D::build_vector(6, 3), vec![p, q, r].into_iter().sum());
+    assert_eq!(Vector2D::build_vector(4, 1), [p, q].iter().sum());
+    assert_eq!(1, p[0]);
+    assert_eq!(2, p[1]);
+}
+
+#[test]
+fn test_vector3d_operators() {
+    let p = Vector3D::build_vector(1.0, 2.0, 3.0);
+    let q = Vector3D::build_vector(0.5, -1.0, 2.0);
+    assert_eq!(Vector3D::build_vector(1.5, 1.0, 5.0), p + q);
+    assert_eq!(Vector3D::build_vector(0.5, 3.0, 1.0), p - q);
+    assert_eq!(Vector3D::build_vector(-1.0, -2.0, -3.0), -p);
+    assert_eq!(p.multiply_with_scalar(3.0), p * 3.0);
+    assert_eq!(Vector3D::build_vector(0.5, 1.0, 1.5), p / 2.0);
+    let mut s = p;
+    s += q;
+    s -= p;
+    assert_eq!(q, s);
+    assert_eq!(Vector3D::build_vector(1.5, 1.0, 5.0), [p, q].iter().sum());
+    assert_eq!(3.0, p[2]);
+    assert!(p != q);
+}
+
+#[test]
+#[should_panic(expected="Index is out of bounds")]
+fn test_vector3d_index_panic() {
+    let p = Vector3D::build_vector(1, 2, 3);
+    let _ = p[3];
+}
+
+#[test]
+fn test_vector2d_normalize_project() {
+    let v = Vector2D::build_vector(3, 4);
+    assert_eq!(Vector2D::build_vector(0.6, 0.8), v.normalize().unwrap());
+    let w = Vector2D::build_vector(1, 1);
+    let projection = v.project_onto(w).unwrap();
+    let rejection = v.reject_from(w).unwrap();
+    assert_eq!(Vector2D::build_vector(3.5, 3.5), projection);
+    assert_eq!(Vector2D::build_vector(-0.5, 0.5), rejection);
+    assert_eq!(v.convert_to_f64(), projection + rejection);
+    assert_eq!(Vector2D::build_vector(4.0, 3.0), v.reflect_about(Vector2D::build_vector(1, -1)).unwrap());
+    assert!(v.project_onto(Vector2D::build_vector(0, 0)).is_err());
+}
+
+#[test]
+#[should_panic(expected="The zero vector cannot be normalized")]
+fn test_vector2d_normalize_error() {
+    Vector2D::build_vector(0, 0).normalize().expect("error");
+}
+
+#[test]
+fn test_vector2d_interpolation() {
+    let p = Vector2D::build_vector(2.0, 0.0);
+    let q = Vector2D::build_vector(0.0, 4.0);
+    assert_eq!(Vector2D::build_vector(1.0, 2.0), p.lerp(q, 0.5));
+    assert_eq!(p, p.slerp(q, 0.0).unwrap());
+    let r = p.slerp(q, 1.0 / 3.0).unwrap();
+    assert!((r.get_length() - 8.0 / 3.0).abs() < 1e-12);
+    assert!((p.angle_between(r) - std::f64::consts::FRAC_PI_6).abs() < 1e-12);
+    assert_eq!(Vector2D::build_vector(3.0, 0.0), p.slerp(Vector2D::build_vector(4.0, 0.0), 0.5).unwrap());
+    assert!(p.slerp(Vector2D::build_vector(-1.0, 0.0), 0.5).is_err());
+    assert_eq!(std::f64::consts::PI, p.angle_between(-p));
+}
+
+#[test]
+fn test_vector3d_normalize_project() {
+    let v = Vector3D::build_vector(2, 3, 6);
+    let unit = v.normalize().unwrap();
+    assert!(unit.abs_diff_eq(&Vector3D::build_vector(2.0 / 7.0, 3.0 / 7.0, 6.0 / 7.0), f64::EPSILON));
+    let w = Vector3D::build_vector(1, 0, 1);
+    let projection = v.project_onto(w).unwrap();
+    let rejection = v.reject_from(w).unwrap();
+    assert_eq!(Vector3D::build_vector(4.0, 0.0, 4.0), projection);
+    assert_eq!(Vector3D::build_vector(-2.0, 3.0, 2.0), rejection);
+    assert_eq!(0.0, rejection.get_scalar_product(w.convert_to_f64()));
+    assert!(v.reflect_about(w).unwrap().abs_diff_eq(&Vector3D::build_vector(-6.0, 3.0, -2.0), 1e-12));
+    assert!(v.reflect_about(Vector3D::build_vector(0, 0, 0)).is_err());
+}
+
+#[test]
+#[should_panic(expected="Cannot project onto the zero vector")]
+fn test_vector3d_project_error() {
+    Vector3D::build_vector(1, 2, 3).project_onto(Vector3D::build_vector(0, 0, 0)).expect("error");
+}
+
+#[test]
+fn test_vector3d_interpolation() {
+    let p = Vector3D::build_vector(1.0, 0.0, 0.0);
+    let q = Vector3D::build_vector(0.0, 1.0, 1.0);
+    assert_eq!(Vector3D::build_vector(0.5, 0.5, 0.5), p.lerp(q, 0.5));
+    let r = p.slerp(q, 0.5).unwrap();
+    assert!((p.angle_between(r) - r.angle_between(q)).abs() < 1e-12);
+    assert!((r.get_vector_product(p.get_vector_product(q)).get_scalar_product(r)).abs() < 1e-12);
+    assert!(q.slerp(q * 3.0, 0.5).unwrap().abs_diff_eq(&(q * 2.0), 1e-12));
+    assert!(p.slerp(-p, 0.5).is_err());
+    assert!((p.angle_between(q) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert!((Vector3D::build_vector(1.0, 1.0, 0.0).angle_between(p) - p.get_cut_angle(Vector3D::build_vector(1.0, 1.0, 0.0)).acos()).abs() < 1e-12);
+}
+
+#[test]
+fn test_quaternion_algebra() {
+    let p = Quaternion::build_quaternion(1.0, 2.0, 3.0, 4.0);
+    let q = Quaternion::build_quaternion(-2.0, 1.0, 0.5, 3.0);
+    assert_eq!(Quaternion::build_quaternion(-17.5, 4.0, -7.5, -7.0), p * q);
+    assert_eq!(Quaternion::build_quaternion(1.0, -2.0, -3.0, -4.0), p.get_conjugate());
+    assert_eq!(30.0_f64.sqrt(), p.get_norm());
+    assert!((p.normalize().unwrap().get_norm() - 1.0).abs() < 1e-15);
+    assert!((p * p.get_inverse().unwrap()).abs_diff_eq(&Quaternion::build_identity_quaternion(), 1e-15));
+    assert_eq!((p * q).get_conjugate(), q.get_conjugate() * p.get_conjugate());
+}
+
+#[test]
+#[should_panic(expected="The zero quaternion has no inverse")]
+fn test_quaternion_inverse_error() {
+    Quaternion::build_quaternion(0.0, 0.0, 0.0, 0.0).get_inverse().expect("error");
+}
+
+#[test]
+fn test_quaternion_rotation() {
+    let axis = Vector3D::build_vector(1, 1, 1);
+    let q = Quaternion::build_from_axis_angle(axis, 2.0 * std::f64::consts::FRAC_PI_3).unwrap();
+    let v = Vector3D::build_vector(1, 0, 0);
+    assert!(q.rotate(v).abs_diff_eq(&Vector3D::build_vector(0.0, 1.0, 0.0), 1e-15));
+    let m = q.to_rotation_matrice();
+    let expected = Matrice::build_matrice(3, 3, vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]).unwrap();
+    assert!(m.abs_diff_eq(&expected, 1e-15));
+    let w = Vector3D::build_vector(2.0, -1.0, 0.5);
+    let rotated = q.rotate(w);
+    let product = m.multiply_with_matrice(&Matrice::build_matrice(3, 1, vec![2.0, -1.0, 0.5]).unwrap()).unwrap();
+    assert!(rotated.abs_diff_eq(&Vector3D::build_vector(product.data[0], product.data[1], product.data[2]), 1e-15));
+    assert!(Quaternion::build_from_axis_angle(Vector3D::build_vector(0, 0, 0), 1.0).is_err());
+}
+
+#[test]
+fn test_quaternion_euler_angles() {
+    let (a, b, c) = (0.3, -0.7, 1.1);
+    let x = Vector3D::build_vector(1, 0, 0);
+    let y = Vector3D::build_vector(0, 1, 0);
+    let z = Vector3D::build_vector(0, 0, 1);
+    let qx = |angle| Quaternion::build_from_axis_angle(x, angle).unwrap();
+    let qy = |angle| Quaternion::build_from_axis_angle(y, angle).unwrap();
+    let qz = |angle| Quaternion::build_from_axis_angle(z, angle).unwrap();
+    assert!(Quaternion::build_from_euler_angles(a, b, c, EulerOrder::ZYX).abs_diff_eq(&(qz(a) * qy(b) * qx(c)), 1e-15));
+    assert!(Quaternion::build_from_euler_angles(a, b, c, EulerOrder::XYZ).abs_diff_eq(&(qx(a) * qy(b) * qz(c)), 1e-15));
+    assert!(Quaternion::build_from_euler_angles(a, b, c, EulerOrder::ZXZ).abs_diff_eq(&(qz(a) * qx(b) * qz(c)), 1e-15));
+    let q = Quaternion::build_from_euler_angles(std::f64::consts::FRAC_PI_2, std::f64::consts::FRAC_PI_2, 0.0, EulerOrder::ZYX);
+    assert!(q.rotate(x).abs_diff_eq(&Vector3D::build_vector(0.0, 0.0, -1.0), 1e-15));
+}
+
+#[test]
+fn test_quaternion_slerp() {
+    let axis = Vector3D::build_vector(0, 1, 0);
+    let p = Quaternion::build_from_axis_angle(axis, 0.2).unwrap();
+    let q = Quaternion::build_from_axis_angle(axis, 1.4).unwrap();
+    assert!(p.slerp(q, 0.0).abs_diff_eq(&p, 1e-15));
+    assert!(p.slerp(q, 1.0).abs_diff_eq(&q, 1e-15));
+    assert!(p.slerp(q, 0.25).abs_diff_eq(&Quaternion::build_from_axis_angle(axis, 0.5).unwrap(), 1e-15));
+    let negated = Quaternion::build_quaternion(-q.w, -q.x, -q.y, -q.z);
+    assert!(p.slerp(negated, 0.25).abs_diff_eq(&Quaternion::build_from_axis_angle(axis, 0.5).unwrap(), 1e-15));
+    assert!(p.slerp(p, 0.5).abs_diff_eq(&p, 1e-15));
+}
+
+#[test]
+fn test_transform2d() {
+    let p = Vector2D::build_vector(1, 2);
+    let rotation = Transform2D::build_rotation(std::f64::consts::FRAC_PI_2);
+    assert!(rotation.apply_to_point(p).abs_diff_eq(&Vector2D::build_vector(-2.0, 1.0), 1e-15));
+    assert_eq!(Vector2D::build_vector(3.0, -2.0), Transform2D::build_scaling(3.0, -1.0).apply_to_point(p));
+    assert_eq!(Vector2D::build_vector(5.0, 2.0), Transform2D::build_shearing(2.0, 0.0).apply_to_point(p));
+    assert_eq!(Vector2D::build_vector(-1.0, 2.0), Transform2D::build_reflection(Vector2D::build_vector(1, 0)).unwrap().apply_to_point(p));
+    let t = Transform2D::build_translation(1.0, 1.0).compose_with(&rotation);
+    assert!(t.apply_to_point(p).abs_diff_eq(&Vector2D::build_vector(-3.0, 2.0), 1e-15));
+    let inverse = t.get_inverse().unwrap();
+    assert!(t.compose_with(&inverse).abs_diff_eq(&Transform2D::build_identity_transform(), 1e-15));
+    assert!(inverse.apply_to_point(Vector2D::build_vector(-3.0, 2.0)).abs_diff_eq(&p.convert_to_f64(), 1e-15));
+    assert!(Transform2D::build_scaling(1.0, 0.0).get_inverse().is_err());
+    let m = Matrice::build_matrice(3, 3, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0]).unwrap();
+    assert!(Transform2D::build_from_matrice(&m).is_err());
+    assert!(Transform2D::build_from_matrice(&Transform2D::build_rotation(0.3).matrice).is_ok());
+}
+
+#[test]
+fn test_transform3d_points() {
+    let p = Vector3D::build_vector(1, 2, 3);
+    let half_pi = std::f64::consts::FRAC_PI_2;
+    assert!(Transform3D::build_rotation_x(half_pi).apply_to_point(p).abs_diff_eq(&Vector3D::build_vector(1.0, -3.0, 2.0), 1e-15));
+    assert!(Transform3D::build_rotation_y(half_pi).apply_to_point(p).abs_diff_eq(&Vector3D::build_vector(3.0, 2.0, -1.0), 1e-15));
+    let axis = Transform3D::build_rotation(Vector3D::build_vector(0, 0, 5), half_pi).unwrap();
+    assert!(axis.abs_diff_eq(&Transform3D::build_rotation_z(half_pi), 1e-15));
+    assert_eq!(Vector3D::build_vector(2.0, 2.0, 0.0), Transform3D::build_scaling(2.0, 1.0, 0.0).apply_to_point(p));
+    assert_eq!(Vector3D::build_vector(7.0, 2.0, 3.0), Transform3D::build_shearing(0.0, 2.0, 0.0, 0.0, 0.0, 0.0).apply_to_point(p));
+    let t = Transform3D::build_translation(1.0, 0.0, -1.0).compose_with(&Transform3D::build_rotation_z(half_pi));
+    assert!(t.apply_to_point(p).abs_diff_eq(&Vector3D::build_vector(-2.0, 2.0, 2.0), 1e-15));
+    assert!(t.apply_to_vector(p).abs_diff_eq(&Vector3D::build_vector(-2.0, 1.0, 3.0), 1e-15));
+    let inverse = t.get_inverse().unwrap();
+    assert!(inverse.apply_to_point(t.apply_to_point(p)).abs_diff_eq(&p.convert_to_f64(), 1e-14));
+    assert!(Transform3D::build_scaling(1.0, 1.0, 0.0).get_inverse().is_err());
+}
+
+#[test]
+fn test_transform3d_lines_planes() {
+    let plane = Plane::build_plane_with_vectors(Vector3D::build_vector(1, 1, 1), Vector3D::build_vector(1, 1, 1));
+    let reflection = Transform3D::build_reflection(plane).unwrap();
+    assert!(reflection.apply_to_point(Vector3D::build_vector(0, 0, 0)).abs_diff_eq(&Vector3D::build_vector(2.0, 2.0, 2.0), 1e-14));
+    assert!(reflection.compose_with(&reflection).abs_diff_eq(&Transform3D::build_identity_transform(), 1e-14));
+    let line = Line3D {r: Vector3D::build_vector(1, 0, 0), a: Vector3D::build_vector(0, 1, 0)};
+    let moved = Transform3D::build_translation(0.0, 0.0, 2.0).apply_to_line(line);
+    assert_eq!(Vector3D::build_vector(1.0, 0.0, 2.0), moved.r);
+    assert_eq!(Vector3D::build_vector(0.0, 1.0, 0.0), moved.a);
+    let shear = Transform3D::build_shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let sheared = shear.apply_to_plane(plane).unwrap();
+    let q = Vector3D::build_vector(0, 2, 1);
+    let s = Vector3D::build_vector(-1, 1, 3);
+    assert!(sheared.n.get_scalar_product(shear.apply_to_point(q) - sheared.r).abs() < 1e-14);
+    assert!(sheared.n.get_scalar_product(shear.apply_to_point(s) - sheared.r).abs() < 1e-14);
+    assert!(Transform3D::build_scaling(0.0, 1.0, 1.0).apply_to_plane(plane).is_err());
+}
+
+#[test]
+fn test_line2d_forms() {
+    let p = Vector2D::build_vector(1, 2);
+    let q = Vector2D::build_vector(3, 6);
+    let line = Line2D::build_line_from_two_points(p, q);
+    assert_eq!((4, -2, 0), line.get_general_form());
+    assert_eq!(Ok((2.0, 0.0)), line.get_slope_intercept());
+    let general = Line2D::build_line_from_general_form(4, -2, 0).unwrap();
+    assert_eq!(Ok((2.0, 0.0)), general.get_slope_intercept());
+    let vertical = Line2D::build_line_from_general_form(1, 0, -3).unwrap();
+    assert_eq!(Vector2D::build_vector(3.0, 0.0), vertical.r);
+    assert!(vertical.get_slope_intercept().is_err());
+    let slope = Line2D::build_line_from_slope_intercept(0.5, 1.0);
+    assert_eq!(Ok((0.5, 1.0)), slope.get_slope_intercept());
+    assert_eq!((0.5, -1.0, 1.0), slope.get_general_form());
+}
+
+#[test]
+#[should_panic(expected="The coefficients a and b must not both be zero")]
+fn test_line2d_general_form_error() {
+    Line2D::build_line_from_general_form(0, 0, 1).expect("error");
+}
+
+#[test]
+fn test_line2d_relations() {
+    let l1 = Line2D::build_line_from_slope_intercept(2, 1);
+    let l2 = Line2D::build_line_from_slope_intercept(2, -3);
+    let l3 = Line2D {r: Vector2D::build_vector(0, 0), a: Vector2D::build_vector(-2, 1)};
+    assert!(l1.are_parallel(l2));
+    assert!(!l1.are_parallel(l3));
+    assert!(l1.are_perpendicular(l3));
+    assert!(l1.intersection(l2).is_err());
+    assert!(l1.intersection(l3).unwrap().abs_diff_eq(&Vector2D::build_vector(-0.4, 0.2), 1e-15));
+    assert!((l1.get_cut_angle(l3) - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+    assert_eq!(0.0, l1.get_cut_angle(l2));
+    assert!((l1.distance_from_point(Vector2D::build_vector(0, -3)) - 4.0 / 5.0_f64.sqrt()).abs() < 1e-15);
+    assert_eq!(0.0, l1.distance_from_point(Vector2D::build_vector(1, 3)));
+}
+
+#[test]
+fn test_line3d_closest_points() {
+    let l1 = Line3D {r: Vector3D::build_vector(5, 2, 1), a: Vector3D::build_vector(1, 1, 3)};
+    let l2 = Line3D {r: Vector3D::build_vector(2, -1, 0), a: Vector3D::build_vector(3, 2, 1)};
+    let ClosestPoints {p, q, s, t} = l1.closest_points(l2).unwrap();
+    let connection = q - p;
+    assert!(connection.get_scalar_product(l1.a.convert_to_f64()).abs() < 1e-12);
+    assert!(connection.get_scalar_product(l2.a.convert_to_f64()).abs() < 1e-12);
+    assert!((connection.get_length() - l1.distance_from_line(l2).unwrap()).abs() < 1e-12);
+    assert!(p.abs_diff_eq(&(l1.r.convert_to_f64() + l1.a.convert_to_f64() * s), 1e-12));
+    assert!(q.abs_diff_eq(&(l2.r.convert_to_f64() + l2.a.convert_to_f64() * t), 1e-12));
+    assert!(l1.intersection(l2).is_err());
+}
+
+#[test]
+#[should_panic(expected="Lines are parallel")]
+fn test_line3d_closest_points_parallel() {
+    let l1 = Line3D {r: Vector3D::build_vector(1, 0, 5), a: Vector3D::build_vector(2, 1, 1)};
+    let l2 = Line3D {r: Vector3D::build_vector(0, 2, 1), a: Vector3D::build_vector(-4, -2, -2)};
+    l1.closest_points(l2).expect("error");
+}
+
+#[test]
+fn test_line3d_intersection_foot() {
+    let l1 = Line3D {r: Vector3D::build_vector(0.0, 0.0, 0.0), a: Vector3D::build_vector(1.0, 1.0, 1.0)};
+    let l2 = Line3D {r: Vector3D::build_vector(4.0, 0.0, 2.0), a: Vector3D::build_vector(-1.0, 1.0, 0.0)};
+    assert_eq!(Ok(Vector3D::build_vector(2.0, 2.0, 2.0)), l1.intersection(l2));
+    let closest = l1.closest_points(l2).unwrap();
+    assert_eq!((2.0, 2.0), (closest.s, closest.t));
+    assert_eq!(closest.p, closest.q);
+    let foot = l1.foot_of_perpendicular(Vector3D::build_vector(3.0, 0.0, 0.0));
+    assert_eq!(Vector3D::build_vector(1.0, 1.0, 1.0), foot);
+    assert!((foot.get_distance(Vector3D::build_vector(3.0, 0.0, 0.0)) - l1.distance_from_point(Vector3D::build_vector(3.0, 0.0, 0.0))).abs() < 1e-12);
+}
+
+#[test]
+fn test_line3d_intersection_non_integer() {
+    let crossing = Vector3D::build_vector(0.1, 0.2, 0.3) + Vector3D::build_vector(0.7, 0.3, 0.11) * 0.37;
+    let l1 = Line3D {r: Vector3D::build_vector(0.1, 0.2, 0.3), a: Vector3D::build_vector(0.7, 0.3, 0.11)};
+    let l2 = Line3D {r: crossing - Vector3D::build_vector(0.2, -0.9, 0.45) * 1.3, a: Vector3D::build_vector(0.2, -0.9, 0.45)};
+    assert!(l1.intersection(l2).expect("error").abs_diff_eq(&crossing, 1e-12));
+    assert!(l2.intersection(l1).expect("error").abs_diff_eq(&crossing, 1e-12));
+    let l3 = Line3D {r: l2.r + Vector3D::build_vector(0.0, 0.0, 1e-3), a: l2.a};
+    assert!(l1.intersection(l3).is_err());
+}
+
+#[test]
+fn test_plane_forms() {
+    let plane = Plane::build_plane_from_three_points(Vector3D::build_vector(1, 1, 2), Vector3D::build_vector(0, 4, -5), Vector3D::build_vector(-3, 4, 9));
+    let (a, b, c, d) = plane.get_coordinate_form();
+    let coordinate = Plane::build_plane_from_coordinate_form(a, b, c, d).unwrap();
+    assert!(coordinate.get_distance_from_point(Vector3D::build_vector(1.0, 1.0, 2.0)) < 1e-12);
+    let (r, u, v) = plane.get_parametric_form();
+    let parametric = Plane::build_plane_from_parametric_form(r, u, v);
+    assert!(plane.is_parallel_to_plane(parametric));
+    assert_eq!(0, parametric.n.get_scalar_product(Vector3D::build_vector(0, 4, -5).sub_vector(r)));
+    let (n, distance) = plane.get_hesse_normal_form().unwrap();
+    assert!((n.get_length() - 1.0).abs() < 1e-15);
+    assert!((distance - plane.get_distance_from_point(Vector3D::build_vector(0, 0, 0))).abs() < 1e-12);
+    assert!((n.get_scalar_product(Vector3D::build_vector(-3.0, 4.0, 9.0)) - distance).abs() < 1e-12);
+    assert!(Plane::build_plane_from_coordinate_form(0, 0, 0, 1).is_err());
+}
+
+#[test]
+fn test_plane_intersections() {
+    let p = Plane::build_plane_from_coordinate_form(1.0, 1.0, 1.0, 6.0).unwrap();
+    let q = Plane::build_plane_from_coordinate_form(1.0, -1.0, 0.0, 0.0).unwrap();
+    let s = Plane::build_plane_from_coordinate_form(0.0, 0.0, 1.0, 0.0).unwrap();
+    let l = p.intersection_with_plane(q).unwrap();
+    for t in [0.0, 1.0, -2.5].iter() {
+        let x = l.r + l.a * *t;
+        assert!(p.get_distance_from_point(x) < 1e-12);
+        assert!(q.get_distance_from_point(x) < 1e-12);
+    }
+    assert!(p.intersection_of_three_planes(q, s).unwrap().abs_diff_eq(&Vector3D::build_vector(3.0, 3.0, 0.0), 1e-12));
+    let parallel = Plane::build_plane_from_coordinate_form(2.0, 2.0, 2.0, 1.0).unwrap();
+    assert!(p.intersection_with_plane(parallel).is_err());
+    let r = Plane::build_plane_from_coordinate_form(1.0, -1.0, 0.0, 5.0).unwrap();
+    assert!(p.intersection_of_three_planes(q, r).is_err());
+}
+
+#[test]
+#[should_panic(expected="The planes are parallel")]
+fn test_plane_intersection_parallel() {
+    let p = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 1), Vector3D::build_vector(0, 0, 1));
+    let q = Plane::build_plane_with_vectors(Vector3D::build_vector(0, 0, 2), Vector3D::build_vector(0, 0, -3));
+    p.intersection_with_plane(q).expect("error");
+}
+
+#[test]
+fn test_plane_angles() {
+    let p = Plane::build_plane_with_vectors(Vector3D::build_vector(0.0, 0.0, 0.0), Vector3D::build_vector(0.0, 0.0, 1.0));
+    let q = Plane::build_plane_with_vectors(Vector3D::build_vector(0.0, 0.0, 0.0), Vector3D::build_vector(0.0, 3.0_f64.sqrt(), -1.0));
+    assert!((p.get_angle_with_plane(q) - std::f64::consts::FRAC_PI_3).abs() < 1e-15);
+    assert_eq!(0.0, p.get_angle_with_plane(p));
+    let l = Line3D {r: Vector3D::build_vector(0.0, 0.0, 5.0), a: Vector3D::build_vector(1.0, 0.0, 1.0)};
+    assert!((p.get_angle_with_line(l) - std::f64::consts::FRAC_PI_4).abs() < 1e-15);
+    let parallel = Line3D {r: Vector3D::build_vector(0.0, 0.0, 5.0), a: Vector3D::build_vector(1.0, 2.0, 0.0)};
+    assert_eq!(0.0, p.get_angle_with_line(parallel));
+}
+
+#[test]
+fn test_segment2d_intersection() {
+    let s1 = Segment2D::build_segment(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0));
+    let crossing = Segment2D::build_segment(Vector2D::build_vector(1, -1), Vector2D::build_vector(1, 3));
+    assert_eq!(SegmentIntersection::Point {point: Vector2D::build_vector(1.0, 0.0), s: 0.25, t: 0.25}, s1.intersection(crossing));
+    let touching = Segment2D::build_segment(Vector2D::build_vector(4, 0), Vector2D::build_vector(6, 2));
+    assert_eq!(SegmentIntersection::Point {point: Vector2D::build_vector(4.0, 0.0), s: 1.0, t: 0.0}, s1.intersection(touching));
+    let missing = Segment2D::build_segment(Vector2D::build_vector(5, -1), Vector2D::build_vector(5, 1));
+    assert_eq!(SegmentIntersection::Disjoint, s1.intersection(missing));
+    let parallel = Segment2D::build_segment(Vector2D::build_vector(0, 1), Vector2D::build_vector(4, 1));
+    assert_eq!(SegmentIntersection::Disjoint, s1.intersection(parallel));
+}
+
+#[test]
+fn test_segment2d_collinear() {
+    let s1 = Segment2D::build_segment(Vector2D::build_vector(0.0, 0.0), Vector2D::build_vector(4.0, 2.0));
+    let reversed = Segment2D::build_segment(Vector2D::build_vector(6.0, 3.0), Vector2D::build_vector(2.0, 1.0));
+    assert_eq!(SegmentIntersection::Overlap {start: Vector2D::build_vector(2.0, 1.0), end: Vector2D::build_vector(4.0, 2.0)}, s1.intersection(reversed));
+    let end_to_end = Segment2D::build_segment(Vector2D::build_vector(4.0, 2.0), Vector2D::build_vector(8.0, 4.0));
+    assert_eq!(SegmentIntersection::Point {point: Vector2D::build_vector(4.0, 2.0), s: 1.0, t: 0.0}, s1.intersection(end_to_end));
+    let apart = Segment2D::build_segment(Vector2D::build_vector(5.0, 2.5), Vector2D::build_vector(8.0, 4.0));
+    assert_eq!(SegmentIntersection::Disjoint, s1.intersection(apart));
+    let point = Segment2D::build_segment(Vector2D::build_vector(2.0, 1.0), Vector2D::build_vector(2.0, 1.0));
+    assert_eq!(SegmentIntersection::Point {point: Vector2D::build_vector(2.0, 1.0), s: 0.5, t: 0.0}, s1.intersection(point));
+    assert_eq!(SegmentIntersection::Point {point: Vector2D::build_vector(2.0, 1.0), s: 0.0, t: 0.5}, point.intersection(s1));
+}
+
+#[test]
+fn test_segment3d_intersection() {
+    let s1 = Segment3D::build_segment(Vector3D::build_vector(0.0, 0.0, 0.0), Vector3D::build_vector(2.0, 2.0, 2.0));
+    let skew = Segment3D::build_segment(Vector3D::build_vector(0.0, 2.0, 0.0), Vector3D::build_vector(2.0, 0.0, 0.0));
+    assert_eq!(SegmentIntersection::Disjoint, s1.intersection(skew));
+    let crossing = Segment3D::build_segment(Vector3D::build_vector(0.0, 2.0, 1.0), Vector3D::build_vector(2.0, 0.0, 1.0));
+    match s1.intersection(crossing) {
+        SegmentIntersection::Point {point, s, t} => {
+            assert!(point.abs_diff_eq(&Vector3D::build_vector(1.0, 1.0, 1.0), 1e-12));
+            assert!((s - 0.5).abs() < 1e-12 && (t - 0.5).abs() < 1e-12);
+        },
+        _ => panic!("The segments cross"),
+    }
+    let collinear = Segment3D::build_segment(Vector3D::build_vector(1.0, 1.0, 1.0), Vector3D::build_vector(3.0, 3.0, 3.0));
+    assert_eq!(SegmentIntersection::Overlap {start: Vector3D::build_vector(1.0, 1.0, 1.0), end: Vector3D::build_vector(2.0, 2.0, 2.0)}, s1.intersection(collinear));
+    assert!((s1.distance_from_point(Vector3D::build_vector(3.0, 3.0, 3.0)) - 3.0_f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_ray3d_plane_triangle() {
+    let ray = Ray3D::build_ray(Vector3D::build_vector(0.0, 0.0, 0.0), Vector3D::build_vector(1.0, 1.0, 1.0));
+    let plane = Plane::build_plane_from_coordinate_form(1.0, 0.0, 0.0, 2.0).unwrap();
+    assert_eq!(Some(RayHit {point: Vector3D::build_vector(2.0, 2.0, 2.0), t: 2.0}), ray.intersect_plane(plane));
+    let behind = Plane::build_plane_from_coordinate_form(1.0, 0.0, 0.0, -2.0).unwrap();
+    assert_eq!(None, ray.intersect_plane(behind));
+    let parallel = Plane::build_plane_from_coordinate_form(1.0, -1.0, 0.0, 1.0).unwrap();
+    assert_eq!(None, ray.intersect_plane(parallel));
+    let (p, q, s) = (Vector3D::build_vector(3.0, 0.0, 0.0), Vector3D::build_vector(0.0, 3.0, 0.0), Vector3D::build_vector(0.0, 0.0, 3.0));
+    let hit = ray.intersect_triangle(p, q, s).unwrap();
+    assert!(hit.abs_diff_eq(&RayHit {point: Vector3D::build_vector(1.0, 1.0, 1.0), t: 1.0}, 1e-12));
+    assert!(ray.intersect_triangle(q, p, s).is_some());
+    let missing = Ray3D::build_ray(Vector3D::build_vector(0.0, 0.0, 0.0), Vector3D::build_vector(1.0, 1.0, -1.0));
+    assert_eq!(None, missing.intersect_triangle(p, q, s));
+    let edge = Ray3D::build_ray(Vector3D::build_vector(1.5, 1.5, -1.0), Vector3D::build_vector(0.0, 0.0, 1.0));
+    assert!(edge.intersect_triangle(p, q, s).is_some());
+}
+
+#[test]
+fn test_ray3d_sphere_box() {
+    let ray = Ray3D::build_ray(Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(0, 0, 1));
+    assert_eq!(Some(RayHit {point: Vector3D::build_vector(0.0, 0.0, 4.0), t: 4.0}), ray.intersect_sphere(Vector3D::build_vector(0, 0, 5), 1.0));
+    assert_eq!(Some(RayHit {point: Vector3D::build_vector(0.0, 0.0, 1.0), t: 1.0}), ray.intersect_sphere(Vector3D::build_vector(0, 0, 0), 1.0));
+    assert_eq!(None, ray.intersect_sphere(Vector3D::build_vector(0, 0, -5), 1.0));
+    assert_eq!(None, ray.intersect_sphere(Vector3D::build_vector(3, 0, 5), 1.0));
+    let hit = ray.intersect_box(Vector3D::build_vector(-1, -1, 2), Vector3D::build_vector(1, 1, 3)).unwrap();
+    assert_eq!(RayHit {point: Vector3D::build_vector(0.0, 0.0, 2.0), t: 2.0}, hit);
+    let inside = ray.intersect_box(Vector3D::build_vector(-1, -1, -1), Vector3D::build_vector(1, 1, 3)).unwrap();
+    assert_eq!(3.0, inside.t);
+    assert_eq!(None, ray.intersect_box(Vector3D::build_vector(2, 2, 2), Vector3D::build_vector(3, 3, 3)));
+    assert_eq!(None, ray.intersect_box(Vector3D::build_vector(-1, -1, -3), Vector3D::build_vector(1, 1, -2)));
+    let oblique = Ray3D::build_ray(Vector3D::build_vector(-2.0, 0.5, 0.5), Vector3D::build_vector(1.0, 0.25, 0.0));
+    assert_eq!(Some(2.0), oblique.intersect_box(Vector3D::build_vector(0.0, 0.0, 0.0), Vector3D::build_vector(1.0, 1.0, 1.0)).map(|hit| hit.t));
+}
+
+#[test]
+fn test_get_symmetric_eigen() {
+    let a = Matrice::build_matrice(3, 3, vec![4.0, 1.0, 2.0, 1.0, 3.0, 0.0, 2.0, 0.0, 5.0]).unwrap();
+    let (values, vectors) = a.get_symmetric_eigen().unwrap();
+    assert!(values[0] >= values[1] && values[1] >= values[2]);
+    assert!((values.iter().sum::<f64>() - 12.0).abs() < 1e-10);
+    for (k, value) in values.iter().enumerate() {
+        for i in 0..3 {
+            let av: f64 = (0..3).map(|j| a.data[i * 3 + j] * vectors.data[j * 3 + k]).sum();
+            assert!((av - value * vectors.data[i * 3 + k]).abs() < 1e-10);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected="The matrice is not symmetric")]
+fn test_get_symmetric_eigen_error() {
+    Matrice::build_matrice(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap().get_symmetric_eigen().expect("error");
+}
+
+#[test]
+fn test_aabb2d() {
+    let a = Aabb2D::build_aabb(Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 2)).unwrap();
+    let b = Aabb2D::build_aabb(Vector2D::build_vector(3, 1), Vector2D::build_vector(6, 5)).unwrap();
+    assert_eq!(Aabb2D::build_aabb(Vector2D::build_vector(0, 0), Vector2D::build_vector(6, 5)).unwrap(), a.union(b));
+    assert!(a.intersects(b));
+    assert!(!a.intersects(b.expand(-1.5)));
+    assert_eq!(Vector2D::build_vector(2.0, 1.0), a.get_centre());
+    assert!(a.contains_point(Vector2D::build_vector(4, 2)));
+    assert!(!a.contains_point(Vector2D::build_vector(5, 1)));
+    assert!(a.expand(1.0).contains_aabb(a));
+    assert_eq!(8.0, a.get_area());
+    assert_eq!(12.0, a.get_perimeter());
+    assert_eq!(8.0, a.to_rectangle().unwrap().get_area());
+    assert_eq!(Vector2D::build_vector(-1.0, 0.0), a.expand_to_point(Vector2D::build_vector(-1, 1)).min);
+}
+
+#[test]
+#[should_panic(expected="The minimum corner must not be larger than the maximum corner")]
+fn test_aabb2d_error() {
+    Aabb2D::build_aabb(Vector2D::build_vector(1, 0), Vector2D::build_vector(0, 1)).expect("error");
+}
+
+#[test]
+fn test_aabb3d() {
+    let points = [Vector3D::build_vector(1, 0, 2), Vector3D::build_vector(-1, 3, 0), Vector3D::build_vector(0, 1, 1)];
+    let a = Aabb3D::build_from_points(&points).unwrap();
+    assert_eq!(12.0, a.get_volume());
+    assert_eq!(32.0, a.get_surface());
+    assert_eq!(12.0, a.to_cuboid().unwrap().get_volume());
+    let b = Aabb3D::build_aabb(Vector3D::build_vector(0, 2, 1), Vector3D::build_vector(5, 5, 5)).unwrap();
+    let c = a.intersection(b).unwrap();
+    assert_eq!(Vector3D::build_vector(0.0, 2.0, 1.0), c.min);
+    assert_eq!(Vector3D::build_vector(1.0, 3.0, 2.0), c.max);
+    assert_eq!(None, a.intersection(Aabb3D::build_aabb(Vector3D::build_vector(2, 0, 0), Vector3D::build_vector(3, 1, 1)).unwrap()));
+    assert_eq!(3.0, a.distance_from_point(Vector3D::build_vector(1, 6, 2)));
+    assert!(a.contains_aabb(c));
+}
+
+#[test]
+#[should_panic(expected="Vector or Array is empty")]
+fn test_aabb3d_error() {
+    let points: [Vector3D<f64>; 0] = [];
+    Aabb3D::build_from_points(&points).expect("error");
+}
+
+#[test]
+fn test_obb2d() {
+    let points = [Vector2D::build_vector(0.0, 0.0), Vector2D::build_vector(3.0, 3.0), Vector2D::build_vector(1.0, -1.0), Vector2D::build_vector(4.0, 2.0)];
+    let obb = Obb2D::build_from_points(&points).unwrap();
+    assert!((obb.get_area() - 6.0).abs() < 1e-9);
+    assert!(obb.centre.abs_diff_eq(&Vector2D::build_vector(2.0, 1.0), 1e-9));
+    for p in points.iter() {
+        assert!(obb.contains_point(*p));
+    }
+    assert!(!obb.contains_point(Vector2D::build_vector(0.0, 2.0)));
+    assert!((obb.get_perimeter() - 8.0 * 2.0_f64.sqrt()).abs() < 1e-9);
+    assert_eq!(4, obb.get_corners().len());
+}
+
+#[test]
+fn test_obb3d() {
+    let points = [
+        Vector3D::build_vector(0, 0, 0), Vector3D::build_vector(4, 0, 0), Vector3D::build_vector(0, 2, 0), Vector3D::build_vector(4, 2, 0),
+        Vector3D::build_vector(0, 0, 1), Vector3D::build_vector(4, 0, 1), Vector3D::build_vector(0, 2, 1), Vector3D::build_vector(4, 2, 1),
+    ];
+    let obb = Obb3D::build_from_points(&points).unwrap();
+    assert!((obb.get_volume() - 8.0).abs() < 1e-9);
+    assert!((obb.get_surface() - 28.0).abs() < 1e-9);
+    assert!((obb.half_extents[0] - 2.0).abs() < 1e-9);
+    assert!((obb.half_extents[2] - 0.5).abs() < 1e-9);
+    assert!(obb.centre.abs_diff_eq(&Vector3D::build_vector(2.0, 1.0, 0.5), 1e-9));
+    assert!(obb.get_corners().iter().all(|c| points.iter().any(|p| p.convert_to_f64().abs_diff_eq(c, 1e-9))));
+}
+
+#[test]
+fn test_convex_hull_2d() {
+    let points = [
+        Vector2D::build_vector(1, 1), Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(2, 0),
+        Vector2D::build_vector(4, 3), Vector2D::build_vector(0, 3), Vector2D::build_vector(4, 3), Vector2D::build_vector(0, 1),
+    ];
+    let hull = get_convex_hull_2d(&points);
+    assert_eq!(vec![Vector2D::build_vector(0.0, 0.0), Vector2D::build_vector(4.0, 0.0), Vector2D::build_vector(4.0, 3.0), Vector2D::build_vector(0.0, 3.0)], hull);
+    let polygon = SimplePolygon::build_convex_hull(&points).unwrap();
+    assert_eq!(12.0, polygon.get_area());
+    assert_eq!(14.0, polygon.get_perimeter());
+    let collinear = [Vector2D::build_vector(2, 2), Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 1)];
+    assert_eq!(vec![Vector2D::build_vector(0.0, 0.0), Vector2D::build_vector(2.0, 2.0)], get_convex_hull_2d(&collinear));
+    assert_eq!(vec![Vector2D::build_vector(1.0, 1.0)], get_convex_hull_2d(&[Vector2D::build_vector(1, 1), Vector2D::build_vector(1, 1)]));
+}
+
+#[test]
+#[should_panic(expected="A polygon needs at least three vertices")]
+fn test_convex_hull_2d_error() {
+    SimplePolygon::build_convex_hull(&[Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 1), Vector2D::build_vector(3, 3)]).expect("error");
+}
+
+#[test]
+fn test_simple_polygon_area_perimeter() {
+    let polygon = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(4, 4), Vector2D::build_vector(2, 1), Vector2D::build_vector(0, 4)]).unwrap();
+    assert_eq!(10.0, polygon.get_area());
+    assert!((polygon.get_perimeter() - (12.0 + 2.0 * 13.0_f64.sqrt())).abs() < 1e-12);
+}
+
+#[test]
+fn test_convex_hull_3d_cube() {
+    let mut points = Vec::new();
+    for x in 0..3 {
+        for y in 0..3 {
+            for z in 0..3 {
+                points.push(Vector3D::build_vector(x, y, z));
+            }
+        }
+    }
+    points.push(Vector3D::build_vector(2, 2, 2));
+    let hull = ConvexHull3D::build_convex_hull(&points).unwrap();
+    assert_eq!(8, hull.vertices.len());
+    assert_eq!(12, hull.faces.len());
+    assert!((hull.get_volume() - 8.0).abs() < 1e-12);
+    assert!((hull.get_surface() - 24.0).abs() < 1e-12);
+    for f in 0..hull.faces.len() {
+        let [a, b, c] = hull.get_face_vertices(f);
+        let normal = (b - a).get_vector_product(c - a);
+        assert!(normal.get_scalar_product(Vector3D::build_vector(1.0, 1.0, 1.0) - a) < 0.0);
+    }
+}
+
+#[test]
+fn test_convex_hull_3d_sphere() {
+    let mut points = Vec::new();
+    for i in 0..20 {
+        for k in 0..40 {
+            let theta = std::f64::consts::PI * (i as f64 + 0.5) / 20.0;
+            let phi = 2.0 * std::f64::consts::PI * k as f64 / 40.0;
+            points.push(Vector3D::build_vector(theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()));
+            points.push(Vector3D::build_vector(0.5 * theta.sin() * phi.cos(), 0.5 * theta.sin() * phi.sin(), 0.5 * theta.cos()));
+        }
+    }
+    let hull = ConvexHull3D::build_convex_hull(&points).unwrap();
+    assert_eq!(800, hull.vertices.len());
+    assert_eq!(2 * hull.vertices.len() - 4, hull.faces.len());
+    let sphere = Sphere::build_sphere(1).unwrap();
+    assert!(hull.get_volume() < sphere.get_volume() && hull.get_volume() > 0.97 * sphere.get_volume());
+    assert!(hull.get_surface() < sphere.get_surface() && hull.get_surface() > 0.97 * sphere.get_surface());
+}
+
+#[test]
+#[should_panic(expected="The points must not all lie in one plane")]
+fn test_convex_hull_3d_error() {
+    let points = [Vector3D::build_vector(0, 0, 1), Vector3D::build_vector(1, 0, 1), Vector3D::build_vector(0, 1, 1), Vector3D::build_vector(1, 1, 1)];
+    ConvexHull3D::build_convex_hull(&points).expect("error");
+}
+
+#[test]
+fn test_simple_polygon_orientation_centroid() {
+    let square = SimplePolygon::build_polygon(vec![Vector2D::build_vector(1, 1), Vector2D::build_vector(3, 1), Vector2D::build_vector(3, 3), Vector2D::build_vector(1, 3)]).unwrap();
+    assert_eq!(Orientation::CounterClockwise, square.get_orientation());
+    assert_eq!(Orientation::Clockwise, square.reverse().get_orientation());
+    assert_eq!(Ok(Vector2D::build_vector(2.0, 2.0)), square.get_centroid());
+    assert_eq!(Ok(Vector2D::build_vector(2.0, 2.0)), square.reverse().get_centroid());
+    let l_shape = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(2, 0), Vector2D::build_vector(2, 1),
+        Vector2D::build_vector(1, 1), Vector2D::build_vector(1, 2), Vector2D::build_vector(0, 2)]).unwrap();
+    assert!(l_shape.get_centroid().unwrap().abs_diff_eq(&Vector2D::build_vector(5.0 / 6.0, 5.0 / 6.0), 1e-12));
+    let flat = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 1), Vector2D::build_vector(2, 2)]).unwrap();
+    assert_eq!(Orientation::Degenerate, flat.get_orientation());
+}
+
+#[test]
+#[should_panic(expected="The polygon has no area")]
+fn test_simple_polygon_centroid_error() {
+    let flat = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 1), Vector2D::build_vector(2, 2)]).unwrap();
+    flat.get_centroid().expect("error");
+}
+
+#[test]
+fn test_simple_polygon_convex_self_intersecting() {
+    let square = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 0), Vector2D::build_vector(2, 0),
+        Vector2D::build_vector(2, 2), Vector2D::build_vector(0, 2)]).unwrap();
+    assert!(square.is_convex());
+    assert!(square.reverse().is_convex());
+    assert!(!square.is_self_intersecting());
+    let arrow = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(4, 4), Vector2D::build_vector(2, 1), Vector2D::build_vector(0, 4)]).unwrap();
+    assert!(!arrow.is_convex());
+    assert!(!arrow.is_self_intersecting());
+    let star: Vec<Vector2D<f64>> = (0..5).map(|i| {
+        let angle = 4.0 * std::f64::consts::PI * i as f64 / 5.0;
+        Vector2D::build_vector(angle.cos(), angle.sin())
+    }).collect();
+    let star = SimplePolygon::build_polygon(star).unwrap();
+    assert!(star.is_self_intersecting());
+    assert!(!star.is_convex());
+    assert_eq!(2, star.get_winding_number(Vector2D::build_vector(0.0, 0.0)));
+    assert!(star.contains_point(Vector2D::build_vector(0.0, 0.0)));
+    let touching = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(2, 0), Vector2D::build_vector(1, 1),
+        Vector2D::build_vector(2, 2), Vector2D::build_vector(0, 2), Vector2D::build_vector(1, 1)]).unwrap();
+    assert!(touching.is_self_intersecting());
+}
+
+#[test]
+fn test_simple_polygon_contains_point() {
+    let polygon = SimplePolygon::build_polygon(vec![Vector2D::build_vector(0, 0), Vector2D::build_vector(4, 0), Vector2D::build_vector(4, 4), Vector2D::build_vector(2, 1), Vector2D::build_vector(0, 4)]).unwrap();
+    assert_eq!(1, polygon.get_winding_number(Vector2D::build_vector(3, 1)));
+    assert_eq!(-1, polygon.reverse().get_winding_number(Vector2D::build_vector(3, 1)));
+    assert_eq!(0, polygon.get_winding_number(Vector2D::build_vector(2, 2)));
+    assert!(polygon.contains_point(Vector2D::build_vector(0, 0)));
+    assert!(polygon.contains_point(Vector2D::build_vector(2, 1)));
+    assert!(!polygon.contains_point(Vector2D::build_vector(5, 1)));
+    assert!(!polygon.contains_point(Vector2D::build_vector(2, 4)));
+}
+
+#[test]
+fn test_simple_polygon_contains_point_border() {
+    let (a, b, c) = (Vector2D::build_vector(0.1, 0.2), Vector2D::build_vector(0.7, 0.3), Vector2D::build_vector(0.2, 0.9));
+    let polygon = SimplePolygon::build_polygon(vec![a, b, c]).unwrap();
+    for i in 0..10 {
+        let t = i as f64 / 10.0;
+        assert!(polygon.contains_point(a + (b - a) * t));
+        assert!(polygon.contains_point(b + (c - b) * t));
+        assert!(polygon.contains_point(c + (a - c) * t));
+    }
+    assert!(!polygon.contains_point(Vector2D::build_vector(0.4, 0.25 - 1e-6)));
+}
+
+fn build_test_polygon(points: &[(i32, i32)]) -> SimplePolygon {
+    SimplePolygon::build_polygon(points.iter().map(|&(x, y)| Vector2D::build_vector(x, y)).collect()).unwrap()
+}
+
+fn get_covered_area(polygons: &[SimplePolygon]) -> f64 {
+    polygons.iter().map(|p| p.get_signed_area()).sum()
+}
+
+#[test]
+fn test_clip_with_convex_polygon() {
+    let arrow = build_test_polygon(&[(0, 0), (4, 0), (4, 4), (2, 1), (0, 4)]);
+    let window = build_test_polygon(&[(1, -1), (3, -1), (3, 5), (1, 5)]);
+    let clipped = arrow.clip_with_convex_polygon(&window.reverse()).unwrap();
+    assert!((clipped[0].get_area() - get_covered_area(&arrow.get_intersection(&window).expect("error"))).abs() < 1e-9);
+    assert!(arrow.clip_with_convex_polygon(&build_test_polygon(&[(5, 5), (6, 5), (6, 6)])).unwrap().is_empty());
+}
+
+#[test]
+#[should_panic(expected="The clip polygon must be convex")]
+fn test_clip_with_convex_polygon_error() {
+    let arrow = build_test_polygon(&[(0, 0), (4, 0), (4, 4), (2, 1), (0, 4)]);
+    arrow.clip_with_convex_polygon(&arrow).expect("error");
+}
+
+#[test]
+fn test_polygon_boolean_operations() {
+    let a = build_test_polygon(&[(0, 0), (4, 0), (4, 4), (2, 1), (0, 4)]);
+    let b = build_test_polygon(&[(-1, 2), (5, 2), (5, 3), (-1, 3)]);
+    let intersection = a.get_intersection(&b).expect("error");
+    assert_eq!(2, intersection.len());
+    let common = get_covered_area(&intersection);
+    assert!((common - 2.0).abs() < 1e-9);
+    let union = a.get_union(&b).expect("error");
+    assert_eq!(2, union.len());
+    assert_eq!(1, union.iter().filter(|p| p.get_orientation() == Orientation::Clockwise).count());
+    assert!((get_covered_area(&union) - (a.get_area() + b.get_area() - common)).abs() < 1e-9);
+    let difference = a.get_difference(&b).expect("error");
+    assert!((get_covered_area(&difference) - (a.get_area() - common)).abs() < 1e-9);
+    assert!((get_covered_area(&b.get_difference(&a).expect("error")) - (b.get_area() - common)).abs() < 1e-9);
+    assert!(intersection.iter().chain(difference.iter()).all(|p| p.get_orientation() == Orientation::CounterClockwise));
+
+    let u_shape = build_test_polygon(&[(0, 0), (6, 0), (6, 6), (4, 6), (4, 2), (2, 2), (2, 6), (0, 6)]);
+    let bar = build_test_polygon(&[(-1, 4), (7, 4), (7, 5), (-1, 5)]);
+    let union = u_shape.get_union(&bar).expect("error");
+    assert_eq!(2, union.len());
+    assert_eq!(1, union.iter().filter(|p| p.get_orientation() == Orientation::Clockwise).count());
+    assert!((get_covered_area(&union) - 32.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_polygon_boolean_operations_special_cases() {
+    let outer = build_test_polygon(&[(0, 0), (6, 0), (6, 6), (0, 6)]);
+    let inner = build_test_polygon(&[(2, 2), (2, 4), (4, 4), (4, 2)]);
+    let far = build_test_polygon(&[(10, 10), (11, 10), (11, 11)]);
+    assert_eq!(vec![inner.to_counterclockwise()], outer.get_intersection(&inner).expect("error"));
+    assert_eq!(vec![outer.clone()], outer.get_union(&inner).expect("error"));
+    let ring = outer.get_difference(&inner).expect("error");
+    assert_eq!(2, ring.len());
+    assert_eq!(32.0, get_covered_area(&ring));
+    assert!(inner.get_difference(&outer).expect("error").is_empty());
+    assert!(outer.get_intersection(&far).expect("error").is_empty());
+    assert_eq!(2, outer.get_union(&far).expect("error").len());
+
+    let neighbour = build_test_polygon(&[(6, 0), (9, 0), (9, 6), (6, 6)]);
+    let union = outer.get_union(&neighbour).expect("error");
+    assert_eq!(1, union.len());
+    assert!((union[0].get_area() - 54.0).abs() < 1e-5);
+    assert!(outer.get_intersection(&neighbour).expect("error").is_empty());
+    assert_eq!(vec![outer.clone()], outer.get_difference(&neighbour).expect("error"));
+    let shifted = build_test_polygon(&[(3, 0), (9, 0), (9, 6), (3, 6)]);
+    assert!((get_covered_area(&outer.get_intersection(&shifted).expect("error")) - 18.0).abs() < 1e-5);
+    assert!((get_covered_area(&outer.get_difference(&shifted).expect("error")) - 18.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_polygon_boolean_operations_degenerate_vertices() {
+    let strip = build_test_polygon(&[(1, -1), (3, -1), (3, -1), (3, 5), (1, 5)]);
+    let spiked = build_test_polygon(&[(1, -1), (3, -1), (3, 5), (3, 7), (3, 5), (1, 5)]);
+    let square = build_test_polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+    let arrow = build_test_polygon(&[(0, 0), (4, 0), (4, 4), (2, 1), (0, 4)]);
+    for subject in [strip, spiked].iter() {
+        assert!((get_covered_area(&subject.get_intersection(&square).expect("error")) - 8.0).abs() < 1e-5);
+        assert!((get_covered_area(&subject.get_union(&square).expect("error")) - 20.0).abs() < 1e-5);
+        assert!((get_covered_area(&subject.get_difference(&square).expect("error")) - 4.0).abs() < 1e-5);
+        assert!((get_covered_area(&square.get_difference(subject).expect("error")) - 8.0).abs() < 1e-5);
+        assert!((get_covered_area(&subject.get_intersection(&arrow).expect("error")) - 3.5).abs() < 1e-5);
+        assert!((get_covered_area(&arrow.get_intersection(subject).expect("error")) - 3.5).abs() < 1e-5);
+    }
+
+    let flat = build_test_polygon(&[(0, 0), (2, 0), (4, 0)]);
+    assert!(flat.get_intersection(&square).expect("error").is_empty());
+    assert_eq!(vec![square.clone()], flat.get_union(&square).expect("error"));
+    assert_eq!(vec![square.clone()], square.get_difference(&flat).expect("error"));
+}
+
+#[test]
+#[should_panic(expected="The polygons must have finite coordinates")]
+fn test_polygon_boolean_operations_error() {
+    let square = build_test_polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+    let broken = SimplePolygon::build_polygon(vec![Vector2D::build_vector(1.0, 1.0), Vector2D::build_vector(f64::NAN, 1.0),
+        Vector2D::build_vector(2.0, 3.0)]).unwrap();
+    square.get_union(&broken).expect("error");
+}
+
+fn build_scattered_points() -> Vec<Vector2D<f64>> {
+    (0..60).map(|i| {
+        let i = i as f64;
+        Vector2D::build_vector((i * 0.618034).fract() * 10.0, (i * 0.414214 + (i * 1.7).sin() * 0.3).fract().abs() * 8.0)
+    }).collect()
+}
+
+#[test]
+fn test_delaunay_triangulation() {
+    let points = build_scattered_points();
+    let triangulation = DelaunayTriangulation::build_delaunay_triangulation(&points).unwrap();
+    let hull = SimplePolygon::build_convex_hull(&points).unwrap();
+    let n = triangulation.points.len();
+    assert_eq!(2 * n - 2 - hull.vertices.len(), triangulation.triangles.len());
+    let area: f64 = triangulation.to_triangles().unwrap().iter().map(|t| t.get_area()).sum();
+    assert!(triangulation.to_triangles().unwrap().iter().all(|t| t.get_centroid().is_ok()));
+    assert!((area - hull.get_area()).abs() < 1e-9);
+    for i in 0..triangulation.triangles.len() {
+        let [a, b, c] = triangulation.get_triangle_vertices(i);
+        assert!((b - a).get_vector_product(c - a) > 0.0);
+        let d = (b - a) * 2.0;
+        let e = (c - a) * 2.0;
+        let (bb, cc) = ((b - a).get_scalar_product(b - a), (c - a).get_scalar_product(c - a));
+        let centre = a + Vector2D::build_vector(bb * e.y - cc * d.y, cc * d.x - bb * e.x) / d.get_vector_product(e);
+        let r = centre.get_distance(a);
+        assert!(triangulation.points.iter().all(|p| p.get_distance(centre) > r - 1e-9));
+    }
+}
+
+#[test]
+fn test_delaunay_triangulation_grid() {
+    let mut points = Vec::new();
+    for x in 0..4 {
+        for y in 0..3 {
+            points.push(Vector2D::build_vector(x, y));
+        }
+    }
+    points.push(Vector2D::build_vector(0, 0));
+    let triangulation = DelaunayTriangulation::build_delaunay_triangulation(&points).unwrap();
+    assert_eq!(12, triangulation.points.len());
+    assert_eq!(12, triangulation.triangles.len());
+    let neighbours = triangulation.get_neighbours(0);
+    assert!(neighbours == vec![1, 3] || neighbours == vec![1, 3, 4]);
+}
+
+#[test]
+fn test_delaunay_triangulation_cocircular() {
+    let points: Vec<Vector2D<f64>> = (0..9).map(|i| {
+        let angle = 2.0 * std::f64::consts::PI * i as f64 / 9.0;
+        Vector2D::build_vector(3.0 + 2.0 * angle.cos(), 1.0 + 2.0 * angle.sin())
+    }).collect();
+    let triangulation = DelaunayTriangulation::build_delaunay_triangulation(&points).unwrap();
+    assert_eq!(7, triangulation.triangles.len());
+    for i in 0..triangulation.triangles.len() {
+        let [a, b, c] = triangulation.get_triangle_vertices(i);
+        assert!((b - a).get_vector_product(c - a) > 0.0);
+    }
+    let area: f64 = triangulation.to_triangles().unwrap().iter().map(|t| t.get_area()).sum();
+    assert!((area - SimplePolygon::build_polygon(points).unwrap().get_area()).abs() < 1e-9);
+}
+
+#[test]
+fn test_delaunay_triangulation_cocircular_grid() {
+    for &(columns, rows, step) in [(5, 5, 1.0), (7, 4, 0.1), (6, 6, 0.3)].iter() {
+        let mut points = Vec::new();
+        for x in 0..columns {
+            for y in 0..rows {
+                points.push(Vector2D::build_vector(0.7 + x as f64 * step, -0.2 + y as f64 * step));
+            }
+        }
+        let triangulation = DelaunayTriangulation::build_delaunay_triangulation(&points).unwrap();
+        assert_eq!(2 * (columns - 1) * (rows - 1), triangulation.triangles.len());
+        for i in 0..triangulation.triangles.len() {
+            let [a, b, c] = triangulation.get_triangle_vertices(i);
+            assert!((b - a).get_vector_product(c - a) > 0.0);
+        }
+
+        let bounds = Aabb2D::build_aabb(Vector2D::build_vector(0.0, -1.0), Vector2D::build_vector(0.7 + columns as f64 * step, rows as f64 * step)).unwrap();
+        let diagram = triangulation.get_voronoi_diagram(bounds).unwrap();
+        let area: f64 = diagram.cells.iter().map(|cell| cell.get_area()).sum();
+        assert!((area - bounds.get_area()).abs() < 1e-9);
+    }
+}
+
+fn check_delaunay_hull(points: &[Vector2D<f64>]) {
+    let triangulation = DelaunayTriangulation::build_delaunay_triangulation(points).unwrap();
+    let hull = SimplePolygon::build_convex_hull(points).unwrap();
+    let n = triangulation.points.len();
+    assert_eq!(2 * n - 2 - hull.vertices.len(), triangulation.triangles.len());
+    let area: f64 = (0..triangulation.triangles.len()).map(|i| {
+        let [a, b, c] = triangulation.get_triangle_vertices(i);
+        0.5 * (b - a).get_vector_product(c - a)
+    }).sum();
+    assert!((area - hull.get_area()).abs() < 1e-9 * hull.get_area());
+}
+
+#[test]
+fn test_delaunay_triangulation_hull() {
+    let mut generator = MatriceGenerator::build_generator(45);
+    for &count in [3, 4, 10, 50, 200].iter() {
+        let points: Vec<Vector2D<f64>> = (0..count).map(|_| Vector2D::build_vector(generator.next_f64() * 20.0 - 5.0, generator.next_f64() * 3.0)).collect();
+        check_delaunay_hull(&points);
+    }
+
+    for &(radius, span) in [(100.0, 0.1), (1000.0, 0.02), (10.0, 1.0)].iter() {
+        let mut points: Vec<Vector2D<f64>> = (0..25).map(|i| {
+            let angle = std::f64::consts::FRAC_PI_2 + span * (i as f64 / 24.0 - 0.5);
+            Vector2D::build_vector(radius * angle.cos(), radius * angle.sin() - radius)
+        }).collect();
+        check_delaunay_hull(&points);
+        points.push(Vector2D::build_vector(0.0, -0.1 * radius * span * span));
+        check_delaunay_hull(&points);
+    }
+}
+
+#[test]
+#[should_panic(expected="The points must not all lie on a line")]
+fn test_delaunay_triangulation_error() {
+    DelaunayTriangulation::build_delaunay_triangulation(&[Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 1), Vector2D::build_vector(3, 3)]).expect("error");
+}
+
+#[test]
+fn test_voronoi_diagram() {
+    let points = build_scattered_points();
+    let bounds = Aabb2D::build_aabb(Vector2D::build_vector(-1, -1), Vector2D::build_vector(11, 9)).unwrap();
+    let diagram = VoronoiDiagram::build_voronoi_diagram(&points, bounds).unwrap();
+    let area: f64 = diagram.cells.iter().map(|cell| cell.get_area()).sum();
+    assert!((area - bounds.get_area()).abs() < 1e-9);
+    for (i, cell) in diagram.cells.iter().enumerate() {
+        assert!(cell.is_convex());
+        assert!(cell.contains_point(diagram.sites[i]));
+        assert_eq!(i, diagram.get_nearest_site(cell.get_centroid().unwrap()));
+    }
+}
+
+#[test]
+#[should_panic(expected="The bounding box must contain all sites")]
+fn test_voronoi_diagram_error() {
+    let bounds = Aabb2D::build_aabb(Vector2D::build_vector(0, 0), Vector2D::build_vector(2, 2)).unwrap();
+    VoronoiDiagram::build_voronoi_diagram(&[Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 2), Vector2D::build_vector(3, 1)], bounds).expect("error");
+}
+
+#[test]
+fn test_triangle_centres() {
+    let triangle = Triangle::build_triangle_with_points(Vector2D::build_vector(1, 1), Vector2D::build_vector(7, 2), Vector2D::build_vector(3, 6));
+    let [a, b, c] = triangle.vertices.unwrap();
+    let incenter = triangle.get_incenter().unwrap();
+    let r = triangle.get_inner_circle().r;
+    for &(p, q) in [(a, b), (b, c), (c, a)].iter() {
+        assert!((Segment2D::build_segment(p, q).distance_from_point(incenter) - r).abs() < 1e-12);
+    }
+    let circumcenter = triangle.get_circumcenter().unwrap();
+    let big_r = triangle.get_outer_circle().r;
+    assert!([a, b, c].iter().all(|v| (v.get_distance(circumcenter) - big_r).abs() < 1e-12));
+    let orthocenter = triangle.get_orthocenter().unwrap();
+    assert!(((orthocenter - a).get_scalar_product(c - b)).abs() < 1e-12);
+    assert!(((orthocenter - b).get_scalar_product(c - a)).abs() < 1e-12);
+    let euler = triangle.get_euler_line().unwrap();
+    assert!(euler.distance_from_point(triangle.get_centroid().unwrap()) < 1e-12);
+    let circle = triangle.get_nine_point_circle().unwrap();
+    let centre = circle.centre;
+    assert!(euler.distance_from_point(centre) < 1e-12);
+    assert!([a.lerp(b, 0.5), b.lerp(c, 0.5), c.lerp(a, 0.5), orthocenter.lerp(a, 0.5)].iter().all(|p| (p.get_distance(centre) - circle.r).abs() < 1e-12));
+}
+
+#[test]
+#[should_panic(expected="An equilateral triangle has no Euler line")]
+fn test_triangle_euler_line_error() {
+    let triangle = Triangle::build_triangle_with_points(Vector2D::build_vector(0.0, 0.0), Vector2D::build_vector(2.0, 0.0), Vector2D::build_vector(1.0, 3.0_f64.sqrt()));
+    triangle.get_euler_line().expect("error");
+}
+
+#[test]
+#[should_panic(expected="The triangle has no vertices")]
+fn test_triangle_centres_error() {
+    Triangle::build_triangle_with_edges(3, 4, 5).unwrap().get_centroid().expect("error");
+}
+
+#[test]
+fn test_triangle_solver() {
+    let reference = Triangle::build_triangle_with_edges(7.0, 8.0, 5.0).unwrap();
+    let (alpha, beta, gamma) = reference.get_angles();
+    assert!(Triangle::build_triangle_with_sas(8.0, 5.0, alpha).unwrap().abs_diff_eq(&reference, 1e-12));
+    assert!(Triangle::build_triangle_with_asa(beta, 7.0, gamma).unwrap().abs_diff_eq(&reference, 1e-12));
+    assert!(Triangle::build_triangle_with_aas(alpha, beta, 7.0).unwrap().abs_diff_eq(&reference, 1e-12));
+    let (alpha, _, _) = Triangle::build_triangle_with_edges(3.0, 4.0, 5.0).unwrap().get_angles();
+    let right = Triangle::build_triangles_with_ssa(3.0, 5.0, alpha).unwrap();
+    assert_eq!(1, right.len());
+    assert!((right[0].c - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_triangle_solver_ssa() {
+    let two = Triangle::build_triangles_with_ssa(3, 4, 30).unwrap();
+    assert_eq!(2, two.len());
+    for triangle in two.iter() {
+        let (alpha, _, _) = triangle.get_angles();
+        assert!((alpha - 30.0).abs() < 1e-9);
+        assert!((triangle.a - 3.0).abs() < 1e-12 && (triangle.b - 4.0).abs() < 1e-12);
+    }
+    assert!(two[0].get_angles().1 < 90.0 && two[1].get_angles().1 > 90.0);
+    assert_eq!(1, Triangle::build_triangles_with_ssa(5, 4, 30).unwrap().len());
+    assert_eq!(0, Triangle::build_triangles_with_ssa(3, 4, 120).unwrap().len());
+    assert_eq!(1, Triangle::build_triangles_with_ssa(5, 4, 120).unwrap().len());
+}
+
+#[test]
+fn test_triangle_solver_ssa_isosceles() {
+    for &alpha in [30.0_f64, 60.0, 81.0, 82.0, 87.5, 89.9].iter() {
+        let triangles = Triangle::build_triangles_with_ssa(1.0, 1.0, alpha).unwrap();
+        assert_eq!(1, triangles.len());
+        assert!((triangles[0].c - 2.0 * alpha.to_radians().cos()).abs() < 1e-12);
+    }
+    assert_eq!(0, Triangle::build_triangles_with_ssa(1.0, 1.0, 90.0).unwrap().len());
+    assert_eq!(0, Triangle::build_triangles_with_ssa(2.5, 2.5, 120.0).unwrap().len());
+}
+
+#[test]
+fn test_triangle_solver_sas_small_angle() {
+    let triangle = Triangle::build_triangle_with_sas(1.0, 1.0, 1e-7).unwrap();
+    assert!((triangle.a - 2.0 * (1e-7_f64.to_radians() / 2.0).sin()).abs() < 1e-22);
+    let triangle = Triangle::build_triangle_with_sas(5, 5, 1).unwrap();
+    assert!((triangle.a - 10.0 * 0.5_f64.to_radians().sin()).abs() < 1e-15);
+}
+
+#[test]
+#[should_panic(expected="Length of triangle edges must be positive")]
+fn test_triangle_solver_length_error() {
+    Triangle::build_triangle_with_sas(0, 3, 60).expect("error");
+}
+
+#[test]
+#[should_panic(expected="Triangle angles must lie between 0 and 180 degrees")]
+fn test_triangle_solver_angle_error() {
+    Triangle::build_triangles_with_ssa(3, 4, 180).expect("error");
+}
+
+#[test]
+#[should_panic(expected="The sum of the triangle angles must be less than 180 degrees")]
+fn test_triangle_solver_angle_sum_error() {
+    Triangle::build_triangle_with_asa(100, 3, 80).expect("error");
+}
+
+#[test]
+fn test_circle2d_intersections() {
+    let c1 = Circle2D::build_circle(Vector2D::build_vector(1.0, -2.0), 3.0).unwrap();
+    let c2 = Circle2D::build_circle(Vector2D::build_vector(3.0, 1.0), 2.0).unwrap();
+    let points = c1.intersection_with_circle(c2).unwrap();
+    assert_eq!(2, points.len());
+    assert!(points.iter().all(|p| (p.get_distance(c1.centre) - 3.0).abs() < 1e-12 && (p.get_distance(c2.centre) - 2.0).abs() < 1e-12));
+    let touching = Circle2D::build_circle(Vector2D::build_vector(6, -2), 2).unwrap();
+    assert_eq!(vec![Vector2D::build_vector(4.0, -2.0)], c1.intersection_with_circle(touching).unwrap());
+    let inside = Circle2D::build_circle(Vector2D::build_vector(1.5, -2.0), 1.0).unwrap();
+    assert!(c1.intersection_with_circle(inside).unwrap().is_empty());
+    let line = Line2D::build_line_from_two_points(Vector2D::build_vector(-5.0, 1.0), Vector2D::build_vector(5.0, 1.0));
+    assert_eq!(vec![Vector2D::build_vector(1.0, 1.0)], c1.intersection_with_line(line));
+    let diagonal = Line2D::build_line_from_slope_intercept(1.0, -3.0);
+    let points = c1.intersection_with_line(diagonal);
+    assert_eq!(2, points.len());
+    assert!(points[0].x < points[1].x);
+    assert!(points.iter().all(|p| (p.get_distance(c1.centre) - 3.0).abs() < 1e-12 && diagonal.distance_from_point(*p) < 1e-12));
+    assert!(c1.intersection_with_line(Line2D::build_line_from_slope_intercept(0.0, 2.0)).is_empty());
+    assert!(c1.get_area() == c1.get_circle().get_area() && c1.get_perimeter() == 6.0 * std::f64::consts::PI);
+}
+
+#[test]
+#[should_panic(expected="The circles are identical")]
+fn test_circle2d_intersection_error() {
+    let c = Circle2D::build_circle(Vector2D::build_vector(1, 1), 2).unwrap();
+    c.intersection_with_circle(c).expect("error");
+}
+
+#[test]
+fn test_circle2d_tangents() {
+    let circle = Circle2D::build_circle(Vector2D::build_vector(1.0, 1.0), 2.0).unwrap();
+    let p = Vector2D::build_vector(4.0, 5.0);
+    let tangents = circle.get_tangents_from_point(p);
+    assert_eq!(2, tangents.len());
+    for tangent in tangents.iter() {
+        assert!((tangent.distance_from_point(circle.centre) - 2.0).abs() < 1e-12);
+        assert!(tangent.distance_from_point(p) < 1e-12);
+    }
+    assert_eq!(1, circle.get_tangents_from_point(Vector2D::build_vector(3.0, 1.0)).len());
+    assert!(circle.get_tangents_from_point(Vector2D::build_vector(2.0, 1.0)).is_empty());
+
+    let other = Circle2D::build_circle(Vector2D::build_vector(7.0, 3.0), 1.0).unwrap();
+    let common = circle.get_common_tangents(other).unwrap();
+    assert_eq!(4, common.len());
+    for tangent in common.iter() {
+        assert!((tangent.distance_from_point(circle.centre) - 2.0).abs() < 1e-12);
+        assert!((tangent.distance_from_point(other.centre) - 1.0).abs() < 1e-12);
+    }
+    let touching = Circle2D::build_circle(Vector2D::build_vector(4.0, 1.0), 1.0).unwrap();
+    assert_eq!(3, circle.get_common_tangents(touching).unwrap().len());
+    let overlapping = Circle2D::build_circle(Vector2D::build_vector(2.0, 1.0), 2.0).unwrap();
+    assert_eq!(2, circle.get_common_tangents(overlapping).unwrap().len());
+    let inside = Circle2D::build_circle(Vector2D::build_vector(1.5, 1.0), 1.0).unwrap();
+    assert!(circle.get_common_tangents(inside).unwrap().is_empty());
+}
+
+#[test]
+#[should_panic(expected="The points lie on a line")]
+fn test_circle2d_through_points_error() {
+    Circle2D::build_circle_through_points(Vector2D::build_vector(0, 0), Vector2D::build_vector(1, 1), Vector2D::build_vector(2, 2)).expect("error");
+}